@@ -0,0 +1,180 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+use std::ops::Range;
+
+use crate::automata::DFA;
+use crate::automata::dfa::ERR_UNDEFINED_SYMBOL;
+use crate::model::{State, Σ};
+
+pub const ERR_EMPTY_PATTERN: &str = "Pattern must contain at least one symbol";
+pub const ERR_NO_PATTERNS: &str = "Aho-Corasick automaton must have at least one pattern";
+
+/// Occurrence of a pattern in a text searched by an [`AhoCorasick`] automaton
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Occurrence {
+    /// Index of the pattern, in the order the patterns were given
+    pub pattern: usize,
+
+    /// Positions of the symbols of the occurrence in the text
+    pub span: Range<usize>,
+}
+
+/// Aho-Corasick automaton finding all occurrences of a set of patterns in a
+/// single pass over a text
+///
+/// The trie of the patterns with its failure links resolved into full transitions
+/// is a [`DFA`] over Σ accepting the words ending with a pattern, its states are
+/// tagged with the number of their trie node, the root being 0, in breadth first
+/// order, each knowing the patterns ending there
+pub struct AhoCorasick<A> {
+    dfa: DFA<A, usize>,
+    // pattern indices and lengths ending at each node, longest first
+    outputs: Vec<Vec<(usize, usize)>>,
+    symbols: HashMap<A, usize>,
+    delta: Vec<Vec<usize>>,
+}
+
+impl<A: Clone + Eq + Hash> AhoCorasick<A> {
+    /// Automaton of the patterns over Σ, patterns may repeat, each occurrence
+    /// being reported for every index of the pattern
+    ///
+    /// # Errors
+    #[allow(non_snake_case)]
+    pub fn new(Σ: &Σ<A>, patterns: &[Vec<A>]) -> Result<Self, &'static str> {
+        if patterns.is_empty() {
+            return Err(ERR_NO_PATTERNS);
+        } else if patterns.iter().any(Vec::is_empty) {
+            return Err(ERR_EMPTY_PATTERN);
+        }
+
+        let symbols = Σ.iter().enumerate().map(|(idx, sym)| (sym.clone(), idx)).collect::<HashMap<_, _>>();
+
+        let mut trie: Vec<Vec<Option<usize>>> = vec![vec![None; Σ.len()]];
+        let mut ends = vec![Vec::new()];
+
+        for (pattern, word) in patterns.iter().enumerate() {
+            let mut node = 0;
+
+            for sym in word {
+                let sym = *symbols.get(sym).ok_or(ERR_UNDEFINED_SYMBOL)?;
+
+                let fresh = trie.len();
+
+                node = *trie[node][sym].get_or_insert(fresh);
+
+                if node == fresh {
+                    trie.push(vec![None; Σ.len()]);
+                    ends.push(Vec::new());
+                }
+            }
+
+            ends[node].push((pattern, word.len()));
+        }
+
+        let (order, delta, outputs) = resolve(&trie, ends);
+
+        // nodes numbered in breadth first order, the order the dfa keeps its states in
+        let mut number = vec![0; trie.len()];
+
+        for (idx, node) in order.iter().enumerate() {
+            number[*node] = idx;
+        }
+
+        let delta = order.iter()
+            .map(|node| delta[*node].iter().map(|next| number[*next]).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let outputs = order.iter().map(|node| outputs[*node].clone()).collect::<Vec<_>>();
+
+        let states = outputs.iter().enumerate().map(|(idx, output)| (vec![idx], !output.is_empty())).collect();
+
+        Ok(Self {
+            dfa: DFA::from_table(Σ.clone(), states, 0, &delta),
+            outputs,
+            symbols,
+            delta,
+        })
+    }
+
+    /// [`DFA`] of this automaton, accepting the words over Σ ending with a pattern
+    #[must_use]
+    pub const fn dfa(&self) -> &DFA<A, usize> {
+        &self.dfa
+    }
+
+    /// Indices of the patterns ending in a state of the [`DFA`], longest first
+    pub fn patterns<'a>(&'a self, state: &State<usize>) -> impl Iterator<Item=usize> + 'a {
+        state.first()
+            .and_then(|node| self.outputs.get(*node))
+            .into_iter()
+            .flatten()
+            .map(|(pattern, _)| *pattern)
+    }
+
+    /// Occurrences of the patterns in the text, overlapping ones included, in
+    /// order of their end, longest first, symbols not in Σ are in no pattern
+    #[must_use]
+    pub fn find(&self, text: &[A]) -> Vec<Occurrence> {
+        let mut occurrences = Vec::new();
+        let mut node = 0;
+
+        for (position, sym) in text.iter().enumerate() {
+            node = self.symbols.get(sym).map_or(0, |sym| self.delta[node][*sym]);
+
+            occurrences.extend(self.outputs[node].iter().map(|(pattern, length)| Occurrence {
+                pattern: *pattern,
+                span: position + 1 - length..position + 1,
+            }));
+        }
+
+        occurrences
+    }
+}
+
+impl<A: Debug> Debug for AhoCorasick<A> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("AhoCorasick")
+            .field("dfa", &self.dfa)
+            .field("outputs", &self.outputs)
+            .finish_non_exhaustive()
+    }
+}
+
+type Resolved = (Vec<usize>, Vec<Vec<usize>>, Vec<Vec<(usize, usize)>>);
+
+// breadth first order of the trie nodes, their full transitions, following the
+// failure links where the trie has none, and the patterns ending at each node,
+// along its failure links, longest first
+fn resolve(trie: &[Vec<Option<usize>>], mut outputs: Vec<Vec<(usize, usize)>>) -> Resolved {
+    let mut order = vec![0];
+    let mut delta = vec![Vec::new(); trie.len()];
+    let mut fail = vec![0; trie.len()];
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(node) = queue.pop_front() {
+        let transitions = trie[node].iter()
+            .enumerate()
+            .map(|(sym, next)| match next {
+                Some(next) => {
+                    // the root fails to itself, its children to the root
+                    fail[*next] = if node == 0 { 0 } else { delta[fail[node]][sym] };
+
+                    let inherited = outputs[fail[*next]].clone();
+
+                    outputs[*next].extend(inherited);
+                    order.push(*next);
+                    queue.push_back(*next);
+
+                    *next
+                }
+                None if node == 0 => 0,
+                None => delta[fail[node]][sym],
+            })
+            .collect();
+
+        delta[node] = transitions;
+    }
+
+    (order, delta, outputs)
+}
@@ -0,0 +1,270 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display, Formatter, Write};
+use std::ops::RangeInclusive;
+
+use crate::UNREACHABLE_ERR;
+
+/// Effective Boolean algebra of the predicates labelling the transitions of a
+/// [`SymbolicAutomaton`](crate::automata::SymbolicAutomaton)
+///
+/// Predicates are sets of symbols closed under ∧, ∨ and ¬ whose satisfiability
+/// can be decided
+pub trait BooleanAlgebra: Clone + Debug + Eq {
+    /// Symbols the predicates are evaluated on
+    type Symbol;
+
+    /// Predicate holding for every symbol, ⊤
+    fn top() -> Self;
+
+    /// Predicate holding for no symbol, ⊥
+    fn bottom() -> Self;
+
+    /// Predicate holding for the symbols both predicates hold for, ∧
+    #[must_use]
+    fn and(&self, other: &Self) -> Self;
+
+    /// Predicate holding for the symbols either predicate holds for, ∨
+    #[must_use]
+    fn or(&self, other: &Self) -> Self;
+
+    /// Predicate holding for the symbols this predicate does not hold for, ¬
+    #[must_use]
+    fn not(&self) -> Self;
+
+    /// Does this predicate hold for at least one symbol
+    fn is_satisfiable(&self) -> bool;
+
+    /// Does this predicate hold for the symbol
+    fn holds(&self, symbol: &Self::Symbol) -> bool;
+
+    /// A symbol this predicate holds for, `None` if it is not satisfiable
+    fn witness(&self) -> Option<Self::Symbol>;
+}
+
+/// Totally ordered symbols with a least and greatest value, each symbol but the
+/// greatest having a successor, over which [`Ranges`] can be complemented
+pub trait Discrete: Copy + Debug + Ord {
+    /// Least symbol
+    const MIN: Self;
+
+    /// Greatest symbol
+    const MAX: Self;
+
+    /// Least symbol greater than this one, `None` for the greatest
+    fn successor(self) -> Option<Self>;
+
+    /// Greatest symbol less than this one, `None` for the least
+    fn predecessor(self) -> Option<Self>;
+
+    /// Number of symbols less than this one
+    fn ordinal(self) -> u128;
+
+    /// Symbol with the number of symbols less than it, `None` beyond the greatest
+    fn from_ordinal(ordinal: u128) -> Option<Self>;
+}
+
+impl Discrete for char {
+    const MIN: Self = '\0';
+    const MAX: Self = Self::MAX;
+
+    // skips the surrogates, which are not chars
+    fn successor(self) -> Option<Self> {
+        match self {
+            '\u{D7FF}' => Some('\u{E000}'),
+            _ => Self::from_u32(self as u32 + 1),
+        }
+    }
+
+    fn predecessor(self) -> Option<Self> {
+        match self {
+            '\0' => None,
+            '\u{E000}' => Some('\u{D7FF}'),
+            _ => Self::from_u32(self as u32 - 1),
+        }
+    }
+
+    // skips the surrogates, 0x800 of them before '\u{E000}'
+    fn ordinal(self) -> u128 {
+        let code = self as u32;
+
+        u128::from(if code < 0xD800 { code } else { code - 0x800 })
+    }
+
+    fn from_ordinal(ordinal: u128) -> Option<Self> {
+        let ordinal = u32::try_from(ordinal).ok()?;
+
+        Self::from_u32(if ordinal < 0xD800 { ordinal } else { ordinal.checked_add(0x800)? })
+    }
+}
+
+macro_rules! discrete {
+    ($($int:ty),*) => {
+        $(
+            impl Discrete for $int {
+                const MIN: Self = Self::MIN;
+                const MAX: Self = Self::MAX;
+
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                #[allow(clippy::cast_lossless)] // usize is not From into u128
+                fn ordinal(self) -> u128 {
+                    self as u128
+                }
+
+                fn from_ordinal(ordinal: u128) -> Option<Self> {
+                    Self::try_from(ordinal).ok()
+                }
+            }
+        )*
+    };
+}
+
+discrete!(u8, u16, u32, u64, usize);
+
+/// Predicate given by a set of inclusive ranges of symbols, kept sorted, disjoint
+/// and not adjacent, so that equal sets have equal ranges
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct Ranges<T>(Vec<(T, T)>);
+
+impl<T: Discrete> Ranges<T> {
+    /// Predicate holding for the symbols in any of the ranges, which may be
+    /// empty, overlapping or in any order
+    pub fn new(ranges: impl IntoIterator<Item=RangeInclusive<T>>) -> Self {
+        Self::normalize(ranges.into_iter().map(RangeInclusive::into_inner).collect())
+    }
+
+    /// Predicate holding for the symbol only
+    #[must_use]
+    pub fn symbol(symbol: T) -> Self {
+        Self(vec![(symbol, symbol)])
+    }
+
+    /// Ranges of this predicate, in ascending order
+    pub fn ranges(&self) -> impl Iterator<Item=RangeInclusive<T>> + '_ {
+        self.0.iter().map(|(start, end)| *start..=*end)
+    }
+
+    fn normalize(mut ranges: Vec<(T, T)>) -> Self {
+        ranges.retain(|(start, end)| start <= end);
+        ranges.sort_unstable();
+
+        let mut merged: Vec<(T, T)> = Vec::with_capacity(ranges.len());
+
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some((_, last)) if last.successor().is_none_or(|next| start <= next) => *last = end.max(*last),
+                _ => merged.push((start, end)),
+            }
+        }
+
+        Self(merged)
+    }
+}
+
+impl<T: Discrete> BooleanAlgebra for Ranges<T> {
+    type Symbol = T;
+
+    fn top() -> Self {
+        Self(vec![(T::MIN, T::MAX)])
+    }
+
+    fn bottom() -> Self {
+        Self(Vec::new())
+    }
+
+    fn and(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut left, mut right) = (0, 0);
+
+        while left < self.0.len() && right < other.0.len() {
+            let (start, end) = (self.0[left].0.max(other.0[right].0), self.0[left].1.min(other.0[right].1));
+
+            if start <= end {
+                ranges.push((start, end));
+            }
+
+            if self.0[left].1 < other.0[right].1 {
+                left += 1;
+            } else {
+                right += 1;
+            }
+        }
+
+        Self(ranges)
+    }
+
+    fn or(&self, other: &Self) -> Self {
+        Self::normalize(self.0.iter().chain(&other.0).copied().collect())
+    }
+
+    fn not(&self) -> Self {
+        let mut ranges = Vec::new();
+        let mut start = Some(T::MIN);
+
+        for (first, last) in &self.0 {
+            if let Some(gap) = start.filter(|gap| gap < first) {
+                ranges.push((gap, first.predecessor().expect(UNREACHABLE_ERR)));
+            }
+
+            start = last.successor();
+        }
+
+        if let Some(gap) = start {
+            ranges.push((gap, T::MAX));
+        }
+
+        Self(ranges)
+    }
+
+    fn is_satisfiable(&self) -> bool {
+        !self.0.is_empty()
+    }
+
+    fn holds(&self, symbol: &T) -> bool {
+        self.0.binary_search_by(|(start, end)| {
+            if end < symbol {
+                Ordering::Less
+            } else if start > symbol {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        }).is_ok()
+    }
+
+    fn witness(&self) -> Option<T> {
+        self.0.first().map(|(start, _)| *start)
+    }
+}
+
+impl<T: Debug + Eq> Debug for Ranges<T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_char('[')?;
+
+        for (idx, (start, end)) in self.0.iter().enumerate() {
+            if idx > 0 {
+                fmt.write_str(", ")?;
+            }
+
+            if start == end {
+                fmt.write_fmt(format_args!("{start:?}"))?;
+            } else {
+                fmt.write_fmt(format_args!("{start:?}..={end:?}"))?;
+            }
+        }
+
+        fmt.write_char(']')
+    }
+}
+
+impl<T: Debug + Eq> Display for Ranges<T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self, fmt)
+    }
+}
@@ -0,0 +1,349 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+
+use crate::automata::{
+    ERR_INVALID_INPUT, ERR_UNDEFINED_FINAL_STATE, ERR_UNDEFINED_INITIAL_STATE, ERR_UNREFERENCED_STATE_Q,
+};
+use crate::automata::dfa::ERR_UNDEFINED_SYMBOL;
+use crate::automata::mealy::ERR_UNDEFINED_STATE;
+use crate::model::{F, Phase, Q, State, δ, Σ};
+use crate::UNREACHABLE_ERR;
+
+pub const ERR_EMPTY_CYCLE: &str = "Cycle of a lasso shaped input must not be empty";
+
+/// Nondeterministic Büchi automaton, (Q, Σ, δ, q0, F), accepting the infinite
+/// words with a run visiting a final state infinitely often
+#[allow(non_snake_case)]
+pub struct Buchi<A, S> {
+    Σ: Σ<A>,
+    states: Vec<State<S>>,
+    delta: Vec<Vec<Vec<usize>>>,
+    initial: usize,
+}
+
+impl<A: Eq, S: Eq + Hash> Buchi<A, S> {
+    /// F may be empty, see [`F::empty`], for an automaton accepting no input
+    ///
+    /// # Errors
+    #[allow(non_snake_case)]
+    #[allow(clippy::missing_panics_doc)] // states are validated before indexing
+    pub fn new(Q: Q<S>, Σ: Σ<A>, δ: δ<A, S>, q0: S, F: &F<S>) -> Result<Self, &'static str> {
+        if Q.iter().any(|q| δ.iter().all(|(state, _)| state != q)) {
+            return Err(ERR_UNREFERENCED_STATE_Q);
+        } else if δ.iter().all(|(state, _)| *state != q0) {
+            return Err(ERR_UNDEFINED_INITIAL_STATE);
+        } else if F.iter().any(|f| f.iter().any(|f| !Q.contains(f))) {
+            return Err(ERR_UNDEFINED_FINAL_STATE);
+        } else if δ.iter().any(|(state, _)| !Q.contains(state)) {
+            return Err(ERR_UNDEFINED_STATE);
+        } else if δ.iter().any(|(_, transitions)| transitions.iter().any(|(sym, _)| !Σ.contains(sym))) {
+            return Err(ERR_UNDEFINED_SYMBOL);
+        }
+
+        let q0 = vec![q0];
+
+        let states = Vec::from(Q).into_iter()
+            .map(|tag| {
+                let tag = vec![tag];
+                let phase = Phase::of(tag == q0, F.contains(&tag));
+
+                State::new(tag, phase)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let index = |tag: &S| states.iter().position(|state| state[0] == *tag).expect(UNREACHABLE_ERR);
+        let mut delta = vec![vec![Vec::new(); Σ.len()]; states.len()];
+
+        let (δ, defaults) = δ.into_parts();
+
+        for (state, transitions) in δ {
+            for (sym, next) in transitions {
+                let sym = Σ.iter().position(|symbol| *symbol == sym).expect(UNREACHABLE_ERR);
+
+                delta[index(&state)][sym].push(index(&next));
+            }
+        }
+
+        // the default transition is taken on the symbols without input transitions
+        for (state, next) in defaults {
+            let next = index(&next);
+
+            for transitions in delta[index(&state)].iter_mut().filter(|transitions| transitions.is_empty()) {
+                transitions.push(next);
+            }
+        }
+
+        let initial = index(&q0[0]);
+
+        Ok(Self { Σ, states, delta, initial })
+    }
+
+    // builds an automaton from an index based table, δ[state][symbol] -> {states},
+    // of unique states
+    #[allow(non_snake_case)]
+    pub(crate) fn from_table(Σ: Σ<A>, states: Vec<(S, bool)>, initial: usize, delta: Vec<Vec<Vec<usize>>>) -> Self {
+        let states = states.into_iter()
+            .enumerate()
+            .map(|(idx, (tag, r#final))| State::from_tags(vec![tag], Phase::of(idx == initial, r#final)))
+            .collect();
+
+        Self { Σ, states, delta, initial }
+    }
+
+    /// Alphabet Σ of this automaton, in declared order
+    #[must_use]
+    pub const fn alphabet(&self) -> &Σ<A> {
+        &self.Σ
+    }
+
+    /// Initial state q0 of this automaton
+    #[must_use]
+    pub fn initial(&self) -> &State<S> {
+        &self.states[self.initial]
+    }
+
+    /// States Q of this automaton, in declared order
+    pub fn states(&self) -> impl Iterator<Item=&State<S>> {
+        self.states.iter()
+    }
+
+    /// Transitions δ(state, input), empty if either is not defined in this automaton
+    pub fn transitions<'a>(&'a self, state: &State<S>, input: &A) -> impl Iterator<Item=&'a State<S>> {
+        let state = self.states.iter().position(|candidate| candidate == state);
+        let sym = self.symbol(input);
+
+        state.zip(sym)
+            .into_iter()
+            .flat_map(|(state, sym)| self.delta[state][sym].iter().map(|next| &self.states[*next]))
+    }
+
+    /// Is the lasso shaped infinite word prefix · cycle^ω accepted, i.e. does a
+    /// run on it visit a final state infinitely often
+    ///
+    /// # Errors
+    /// an empty cycle, or an input symbol that is not defined in Σ
+    pub fn accepts(&self, prefix: &[A], cycle: &[A]) -> Result<bool, &'static str> {
+        if cycle.is_empty() {
+            return Err(ERR_EMPTY_CYCLE);
+        }
+
+        let symbols = cycle.iter().map(|sym| self.symbol(sym).ok_or(ERR_INVALID_INPUT)).collect::<Result<Vec<_>, _>>()?;
+        let mut current = vec![self.initial];
+
+        for input in prefix {
+            let sym = self.symbol(input).ok_or(ERR_INVALID_INPUT)?;
+            let mut next = current.iter().flat_map(|state| self.delta[*state][sym].iter().copied()).collect::<Vec<_>>();
+
+            next.sort_unstable();
+            next.dedup();
+
+            current = next;
+        }
+
+        // runs on the cycle are paths in the product of the states and the positions in the cycle
+        let size = symbols.len();
+        let successors = |node: usize| {
+            let (state, position) = (node / size, node % size);
+
+            self.delta[state][symbols[position]].iter().map(move |next| next * size + (position + 1) % size)
+        };
+
+        let count = self.states.len() * size;
+        let reached = reach(count, current.iter().map(|state| state * size), successors);
+
+        Ok((0..count).any(|node|
+            reached[node] && self.states[node / size].is_final() && reach(count, successors(node), successors)[node]
+        ))
+    }
+
+    fn symbol(&self, input: &A) -> Option<usize> {
+        self.Σ.iter().position(|sym| sym == input)
+    }
+}
+
+impl<A: Clone + Eq, S: Eq + Hash> Buchi<A, S> {
+    /// Accepted lasso shaped infinite word prefix · cycle^ω, `None` if the language
+    /// of this automaton is empty
+    ///
+    /// The cycle returns to the first final state, in breadth first order from
+    /// the initial state, lying on a cycle, the prefix being the shortest word
+    /// reaching it and the cycle the shortest non empty word returning to it
+    #[must_use]
+    pub fn accepting_lasso(&self) -> Option<(Vec<A>, Vec<A>)> {
+        self.breadth_first()
+            .into_iter()
+            .filter(|state| self.states[*state].is_final())
+            .find_map(|state| {
+                let cycle = self.shortest_word(self.successors(state).map(|(sym, next)| (next, Some((state, sym)))), state)?;
+                let prefix = self.shortest_word([(self.initial, None)], state)?;
+
+                Some((prefix, cycle))
+            })
+    }
+
+    /// Is the language of this automaton empty, i.e. does it accept no infinite word
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.accepting_lasso().is_none()
+    }
+
+    // states reachable from the initial state, in breadth first order
+    fn breadth_first(&self) -> Vec<usize> {
+        let mut order = vec![self.initial];
+        let mut current = 0;
+
+        while current < order.len() {
+            for (_, next) in self.successors(order[current]) {
+                if !order.contains(&next) {
+                    order.push(next);
+                }
+            }
+
+            current += 1;
+        }
+
+        order
+    }
+
+    // shortest word leading from the start states, given with the state and symbol
+    // they were reached by, to the target state
+    fn shortest_word(
+        &self, start: impl IntoIterator<Item=(usize, Option<(usize, usize)>)>, target: usize,
+    ) -> Option<Vec<A>> {
+        let mut parents: Vec<Option<Option<(usize, usize)>>> = vec![None; self.states.len()];
+        let mut queue = VecDeque::new();
+
+        for (state, parent) in start {
+            if parents[state].is_none() {
+                parents[state] = Some(parent);
+                queue.push_back(state);
+            }
+        }
+
+        while let Some(state) = queue.pop_front() {
+            if state == target {
+                let mut word = Vec::new();
+                let mut current = state;
+
+                while let Some(Some((previous, sym))) = parents[current] {
+                    word.push(self.Σ[sym].clone());
+
+                    // the cycle's first step leaves the target, stop once back at it
+                    if previous == target {
+                        break;
+                    }
+
+                    current = previous;
+                }
+
+                word.reverse();
+
+                return Some(word);
+            }
+
+            for (sym, next) in self.successors(state) {
+                if parents[next].is_none() {
+                    parents[next] = Some(Some((state, sym)));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        None
+    }
+
+    fn successors(&self, state: usize) -> impl Iterator<Item=(usize, usize)> + '_ {
+        self.delta[state].iter().enumerate().flat_map(|(sym, next)| next.iter().map(move |next| (sym, *next)))
+    }
+}
+
+impl<A: Clone + Eq, S: Clone + Eq + Hash> Buchi<A, S> {
+    /// Büchi automaton accepting the infinite words accepted by both automata,
+    /// over the alphabet of this automaton
+    ///
+    /// States are tagged (state, other state, copy), copy 1 waiting for a final
+    /// state of this automaton and copy 2 for a final state of the other, the final
+    /// states being those of copy 1 in a final state of this automaton
+    #[must_use]
+    pub fn intersection<T: Clone + Eq + Hash>(&self, other: &Buchi<A, T>) -> Buchi<A, (S, T, u8)> {
+        let symbols = self.Σ.iter().map(|sym| other.symbol(sym)).collect::<Vec<_>>();
+        let mut nodes = vec![(self.initial, other.initial, 1)];
+        let mut index = HashMap::from([(nodes[0], 0)]);
+        let mut delta = Vec::new();
+        let mut current = 0;
+
+        while current < nodes.len() {
+            let (state, other_state, copy) = nodes[current];
+
+            let copy = match copy {
+                1 if self.states[state].is_final() => 2,
+                2 if other.states[other_state].is_final() => 1,
+                copy => copy,
+            };
+
+            let mut transitions = vec![Vec::new(); symbols.len()];
+
+            for (sym, other_sym) in symbols.iter().enumerate() {
+                let Some(other_sym) = other_sym else { continue };
+
+                for next in &self.delta[state][sym] {
+                    for other_next in &other.delta[other_state][*other_sym] {
+                        let idx = *index.entry((*next, *other_next, copy)).or_insert_with_key(|node| {
+                            nodes.push(*node);
+                            nodes.len() - 1
+                        });
+
+                        transitions[sym].push(idx);
+                    }
+                }
+            }
+
+            delta.push(transitions);
+            current += 1;
+        }
+
+        let states = nodes.iter()
+            .enumerate()
+            .map(|(idx, (state, other_state, copy))| State::from_tags(
+                vec![(self.states[*state][0].clone(), other.states[*other_state][0].clone(), *copy)],
+                Phase::of(idx == 0, *copy == 1 && self.states[*state].is_final()),
+            ))
+            .collect();
+
+        Buchi { Σ: self.Σ.clone(), states, delta, initial: 0 }
+    }
+}
+
+impl<A: Debug, S: Debug> Debug for Buchi<A, S> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Buchi")
+            .field("Σ", &self.Σ)
+            .field("states", &self.states)
+            .field("δ", &self.delta)
+            .finish_non_exhaustive()
+    }
+}
+
+// nodes reachable in one or more steps from the start nodes, including the start nodes
+fn reach<I: Iterator<Item=usize>>(
+    count: usize, start: impl IntoIterator<Item=usize>, successors: impl Fn(usize) -> I,
+) -> Vec<bool> {
+    let mut reached = vec![false; count];
+    let mut stack = start.into_iter().collect::<Vec<_>>();
+
+    for node in &stack {
+        reached[*node] = true;
+    }
+
+    while let Some(node) = stack.pop() {
+        for next in successors(node) {
+            if !reached[next] {
+                reached[next] = true;
+                stack.push(next);
+            }
+        }
+    }
+
+    reached
+}
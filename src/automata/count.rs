@@ -0,0 +1,363 @@
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+
+use crate::automata::DFA;
+use crate::automata::explain::live_states;
+use crate::automata::table::{reachable, DfaTable};
+use crate::UNREACHABLE_ERR;
+
+pub const ERR_COUNT_OVERFLOW: &str = "Count of words overflows a 128 bit integer";
+
+/// Generating function `Σ c(n) zⁿ` of the numbers `c(n)` of words of each length
+/// accepted by a [`DFA`], a rational function `P(z) / Q(z)`
+///
+/// The denominator is `det(I - zM)`, `M` counting the transitions between the
+/// states reachable and reaching a final state, so the fraction need not be in
+/// lowest terms, and the counts satisfy the linear recurrence it defines
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GeneratingFunction {
+    /// Coefficients of the numerator `P`, of `z⁰` first, without trailing zeros
+    pub numerator: Vec<i128>,
+
+    /// Coefficients of the denominator `Q`, of `z⁰` first, without trailing
+    /// zeros, its constant term being 1
+    pub denominator: Vec<i128>,
+}
+
+impl GeneratingFunction {
+    /// Coefficients of the power series of this function up to `zˡᵉⁿ`, the
+    /// numbers of words of each length up to `len`
+    ///
+    /// # Errors
+    pub fn expand(&self, len: usize) -> Result<Vec<i128>, &'static str> {
+        let mut series: Vec<i128> = Vec::with_capacity(len + 1);
+
+        for n in 0..=len {
+            let mut coefficient = self.numerator.get(n).copied().unwrap_or(0);
+
+            // Q(z) · F(z) = P(z), with Q(0) = 1
+            for (k, q) in self.denominator.iter().enumerate().skip(1).take(n) {
+                coefficient = q.checked_mul(series[n - k])
+                    .and_then(|term| coefficient.checked_sub(term))
+                    .ok_or(ERR_COUNT_OVERFLOW)?;
+            }
+
+            series.push(coefficient);
+        }
+
+        Ok(series)
+    }
+}
+
+impl Display for GeneratingFunction {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        let terms = |coefficients: &[i128]| coefficients.iter().filter(|coefficient| **coefficient != 0).count();
+
+        if self.denominator == [1] {
+            return write_polynomial(fmt, &self.numerator);
+        } else if terms(&self.numerator) > 1 {
+            fmt.write_str("(")?;
+            write_polynomial(fmt, &self.numerator)?;
+            fmt.write_str(")")?;
+        } else {
+            write_polynomial(fmt, &self.numerator)?;
+        }
+
+        fmt.write_str(" / (")?;
+        write_polynomial(fmt, &self.denominator)?;
+        fmt.write_str(")")
+    }
+}
+
+impl<A: Clone + Eq + Hash, S: Eq + Hash> DFA<A, S> {
+    /// Number of words of `len` symbols this automaton accepts, by dynamic
+    /// programming over its transitions, counting the words of each length from
+    /// each state to a final state, a transition on a class of an interval
+    /// alphabet for each of its symbols
+    ///
+    /// Counting fails once the words from a state overflow, so for a length whose
+    /// count is close to `u128::MAX` it may fail although the count would fit
+    ///
+    /// # Errors
+    pub fn count_words(&self, len: usize) -> Result<u128, &'static str> {
+        let table = self.table();
+        let mut count = 0;
+
+        completions(&table, len, |words| count = words[table.initial])?;
+
+        Ok(count)
+    }
+
+    /// Numbers of words of each length up to `len` this automaton accepts, see
+    /// [`DFA::count_words`]
+    ///
+    /// # Errors
+    pub fn word_counts(&self, len: usize) -> Result<Vec<u128>, &'static str> {
+        let table = self.table();
+        let mut counts = Vec::with_capacity(len + 1);
+
+        completions(&table, len, |words| counts.push(words[table.initial]))?;
+
+        Ok(counts)
+    }
+
+    /// Generating function of the numbers of words of each length this automaton
+    /// accepts, see [`GeneratingFunction`]
+    ///
+    /// # Errors
+    pub fn generating_function(&self) -> Result<GeneratingFunction, &'static str> {
+        let table = self.table();
+        let matrix = transition_counts(&table)?;
+        let size = matrix.len();
+
+        let mut denominator = characteristic(&matrix)?;
+        let counts = self.word_counts(size.saturating_sub(1))?
+            .into_iter()
+            .map(|count| i128::try_from(count).map_err(|_| ERR_COUNT_OVERFLOW))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // P(z) = Q(z) · F(z), its degree below the number of states
+        let mut numerator = (0..size)
+            .map(|n| (0..=n).try_fold(0_i128, |coefficient, k| {
+                denominator[k].checked_mul(counts[n - k]).and_then(|term| coefficient.checked_add(term))
+            }))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(ERR_COUNT_OVERFLOW)?;
+
+        trim(&mut numerator);
+        trim(&mut denominator);
+
+        Ok(GeneratingFunction { numerator, denominator })
+    }
+
+    /// Asymptotic growth rate of the numbers of words of each length this
+    /// automaton accepts, the spectral radius `λ` of its transition counts
+    ///
+    /// The counts grow as `λⁿ` up to polynomial factors, it is 0 for a finite
+    /// language and 1 for one growing polynomially
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)] // a state has transitions on at most 2⁶⁴ symbols
+    pub fn growth_rate(&self) -> f64 {
+        let table = self.table();
+        let matrix = transition_counts(&table).expect(UNREACHABLE_ERR);
+
+        components(&matrix).iter()
+            .map(|component| spectral_radius(&matrix, component))
+            .fold(0.0, f64::max)
+    }
+
+    /// Topological entropy of the language of this automaton, `log₂ λ` of its
+    /// growth rate, the bits of information per symbol of its long words, 0 for
+    /// a finite language
+    #[must_use]
+    pub fn entropy(&self) -> f64 {
+        let growth = self.growth_rate();
+
+        if growth > 0.0 { growth.log2() } else { 0.0 }
+    }
+}
+
+// words of each length up to `len` leading from each state to a final state,
+// visited by length keeping only the last two, a transition on a class counting
+// for each of its symbols, and the states not reachable from the initial state
+// having none
+pub fn completions<A, S>(
+    table: &DfaTable<'_, A, S>, len: usize, mut visit: impl FnMut(&[u128]),
+) -> Result<(), &'static str> {
+    let useful = useful_states(table);
+    let mut words = (0..table.states.len()).map(|state| u128::from(useful[state] && table.is_final(state))).collect::<Vec<_>>();
+
+    visit(&words);
+
+    for _ in 0..len {
+        words = table.delta.iter()
+            .enumerate()
+            .map(|(state, transitions)| transitions.iter()
+                .enumerate()
+                .filter(|_| useful[state])
+                .try_fold(0_u128, |count, (sym, next)| words[*next].checked_mul(table.size(sym))
+                    .and_then(|words| count.checked_add(words)))
+                .ok_or(ERR_COUNT_OVERFLOW))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        visit(&words);
+    }
+
+    Ok(())
+}
+
+fn useful_states<A, S>(table: &DfaTable<'_, A, S>) -> Vec<bool> {
+    let mut useful = live_states(table);
+    let mut reached = vec![false; useful.len()];
+
+    for state in reachable(table.initial, &table.delta) {
+        reached[state] = true;
+    }
+
+    for (useful, reached) in useful.iter_mut().zip(reached) {
+        *useful &= reached;
+    }
+
+    useful
+}
+
+// numbers of transitions between the useful states, the initial state first, a
+// transition on a class counting for each of its symbols
+fn transition_counts<A, S>(table: &DfaTable<'_, A, S>) -> Result<Vec<Vec<i128>>, &'static str> {
+    let useful = useful_states(table);
+    let states = reachable(table.initial, &table.delta).into_iter().filter(|state| useful[*state]).collect::<Vec<_>>();
+    let mut matrix = vec![vec![0_i128; states.len()]; states.len()];
+
+    for (row, state) in states.iter().enumerate() {
+        for (sym, target) in table.delta[*state].iter().enumerate() {
+            if let Some(column) = states.iter().position(|other| other == target) {
+                matrix[row][column] = i128::try_from(table.size(sym)).ok()
+                    .and_then(|size| matrix[row][column].checked_add(size))
+                    .ok_or(ERR_COUNT_OVERFLOW)?;
+            }
+        }
+    }
+
+    Ok(matrix)
+}
+
+// coefficients of det(I - zM), the reversed characteristic polynomial of M, by
+// the Faddeev-LeVerrier algorithm, whose divisions are exact over the integers
+fn characteristic(matrix: &[Vec<i128>]) -> Result<Vec<i128>, &'static str> {
+    let size = matrix.len();
+    let mut coefficients = vec![1_i128];
+    let mut power = vec![vec![0_i128; size]; size];
+
+    for k in 1..=size {
+        // M (M_{k-1} + c_{k-1} I) from M M_{k-1}, c_k being minus its trace over k
+        for (idx, row) in power.iter_mut().enumerate() {
+            row[idx] = row[idx].checked_add(coefficients[k - 1]).ok_or(ERR_COUNT_OVERFLOW)?;
+        }
+
+        power = multiply(matrix, &power)?;
+
+        let trace = (0..size).try_fold(0_i128, |trace, idx| trace.checked_add(power[idx][idx])).ok_or(ERR_COUNT_OVERFLOW)?;
+
+        coefficients.push(-trace / i128::try_from(k).map_err(|_| ERR_COUNT_OVERFLOW)?);
+    }
+
+    Ok(coefficients)
+}
+
+fn multiply(lhs: &[Vec<i128>], rhs: &[Vec<i128>]) -> Result<Vec<Vec<i128>>, &'static str> {
+    lhs.iter()
+        .map(|row| (0..rhs.len())
+            .map(|column| row.iter()
+                .zip(rhs)
+                .try_fold(0_i128, |sum, (lhs, rhs)| lhs.checked_mul(rhs[column]).and_then(|term| sum.checked_add(term)))
+                .ok_or(ERR_COUNT_OVERFLOW))
+            .collect())
+        .collect()
+}
+
+// strongly connected components with at least one transition inside them
+fn components(matrix: &[Vec<i128>]) -> Vec<Vec<usize>> {
+    let size = matrix.len();
+    let mut reach = matrix.iter().map(|row| row.iter().map(|count| *count > 0).collect::<Vec<_>>()).collect::<Vec<_>>();
+
+    // transitive closure, paths of at least one transition
+    for via in 0..size {
+        let through = reach[via].clone();
+
+        for row in reach.iter_mut().filter(|row| row[via]) {
+            for (to, reached) in row.iter_mut().enumerate() {
+                *reached |= through[to];
+            }
+        }
+    }
+
+    let mut assigned = vec![false; size];
+    let mut components = Vec::new();
+
+    for state in (0..size).filter(|state| reach[*state][*state]) {
+        if !assigned[state] {
+            let component = (0..size).filter(|other| reach[state][*other] && reach[*other][state]).collect::<Vec<_>>();
+
+            for other in &component {
+                assigned[*other] = true;
+            }
+
+            components.push(component);
+        }
+    }
+
+    components
+}
+
+// Perron root of the irreducible component, by power iteration on M + I, which
+// is primitive, until the Collatz-Wielandt bounds on its root meet
+#[allow(clippy::cast_precision_loss)] // counts beyond 2⁵³ are approximated anyway
+fn spectral_radius(matrix: &[Vec<i128>], component: &[usize]) -> f64 {
+    let mut vector = vec![1.0; component.len()];
+    let mut bounds = (0.0, f64::INFINITY);
+
+    for _ in 0..10_000 {
+        let next = component.iter()
+            .zip(&vector)
+            .map(|(row, own)| own + component.iter()
+                .zip(&vector)
+                .map(|(column, value)| matrix[*row][*column] as f64 * value)
+                .sum::<f64>())
+            .collect::<Vec<_>>();
+
+        let ratios = next.iter().zip(&vector).map(|(next, value)| next / value);
+
+        bounds = ratios.fold((f64::INFINITY, 0.0), |(lower, upper), ratio| (f64::min(lower, ratio), f64::max(upper, ratio)));
+
+        let scale = next.iter().copied().fold(0.0, f64::max);
+
+        vector = next.into_iter().map(|value| value / scale).collect();
+
+        if bounds.1 - bounds.0 <= 1e-12 * bounds.1 {
+            break;
+        }
+    }
+
+    f64::midpoint(bounds.0, bounds.1) - 1.0
+}
+
+fn trim(coefficients: &mut Vec<i128>) {
+    while coefficients.last() == Some(&0) {
+        coefficients.pop();
+    }
+}
+
+fn write_polynomial(fmt: &mut Formatter<'_>, coefficients: &[i128]) -> fmt::Result {
+    let terms = coefficients.iter().enumerate().filter(|(_, coefficient)| **coefficient != 0).collect::<Vec<_>>();
+
+    if terms.is_empty() {
+        return fmt.write_str("0");
+    }
+
+    for (idx, (power, coefficient)) in terms.into_iter().enumerate() {
+        let sign = match (idx, coefficient.is_negative()) {
+            (0, false) => "",
+            (0, true) => "-",
+            (_, false) => " + ",
+            (_, true) => " - ",
+        };
+
+        let magnitude = coefficient.unsigned_abs();
+
+        fmt.write_str(sign)?;
+
+        match (power, magnitude) {
+            (0, _) => write!(fmt, "{magnitude}")?,
+            (_, 1) => {}
+            _ => write!(fmt, "{magnitude}")?,
+        }
+
+        match power {
+            0 => {}
+            1 => fmt.write_str("z")?,
+            _ => write!(fmt, "z^{power}")?,
+        }
+    }
+
+    Ok(())
+}
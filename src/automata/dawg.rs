@@ -0,0 +1,379 @@
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+
+use crate::automata::DFA;
+use crate::model::Σ;
+use crate::UNREACHABLE_ERR;
+
+pub const ERR_INVALID_BYTES: &str = "Bytes do not encode a dawg";
+pub const ERR_UNSORTED_WORDS: &str = "Words must be in strictly increasing lexicographic order";
+
+// finality and transitions of a node, sorted by symbol
+type Node<A> = (bool, Vec<(A, usize)>);
+
+/// Minimal acyclic automaton, directed acyclic word graph, of a finite set of
+/// words, sharing their common prefixes and suffixes
+///
+/// Built incrementally from the words in lexicographic order, as described by
+/// Daciuk et al., minimizing the part of the previous word not shared with the
+/// next one, its nodes are numbered in topological order, the root being 0
+pub struct Dawg<A> {
+    nodes: Vec<Node<A>>,
+    len: usize,
+}
+
+impl<A: Clone + Eq + Hash + Ord> Dawg<A> {
+    /// Dawg of the words, given in strictly increasing lexicographic order
+    ///
+    /// # Errors
+    #[allow(clippy::missing_panics_doc)] // the path always holds the root
+    pub fn new(words: &[Vec<A>]) -> Result<Self, &'static str> {
+        if words.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(ERR_UNSORTED_WORDS);
+        }
+
+        let mut nodes: Vec<Node<A>> = vec![(false, Vec::new())];
+        let mut register = HashMap::new();
+        // nodes along the previous word, not yet registered but the root
+        let mut path = vec![0];
+        let mut previous: &[A] = &[];
+
+        for word in words {
+            let common = previous.iter().zip(word).take_while(|(lhs, rhs)| lhs == rhs).count();
+
+            replace_or_register(&mut nodes, &mut register, &mut path, common);
+
+            for sym in &word[common..] {
+                let (parent, child) = (*path.last().expect(UNREACHABLE_ERR), nodes.len());
+
+                nodes.push((false, Vec::new()));
+                nodes[parent].1.push((sym.clone(), child));
+                path.push(child);
+            }
+
+            nodes[*path.last().expect(UNREACHABLE_ERR)].0 = true;
+            previous = word;
+        }
+
+        replace_or_register(&mut nodes, &mut register, &mut path, 0);
+
+        Ok(Self { nodes: topological(&nodes), len: words.len() })
+    }
+
+    /// Does this dawg contain the word
+    #[must_use]
+    pub fn contains(&self, word: &[A]) -> bool {
+        self.walk(word).is_some_and(|node| self.nodes[node].0)
+    }
+
+    /// Words of this dawg starting with the prefix, in lexicographic order
+    pub fn words_with_prefix<'a>(&'a self, prefix: &[A]) -> impl Iterator<Item=Vec<A>> + 'a {
+        let start = self.walk(prefix);
+
+        Words {
+            nodes: &self.nodes,
+            stack: start.map(|node| vec![(node, 0)]).unwrap_or_default(),
+            word: prefix.to_vec(),
+            entering: start,
+        }
+    }
+
+    /// Words of this dawg, in lexicographic order
+    pub fn words(&self) -> impl Iterator<Item=Vec<A>> + '_ {
+        self.words_with_prefix(&[])
+    }
+
+    /// [`DFA`] accepting the words of this dawg, over the symbols they use in
+    /// ascending order, its states are tagged with the number of their node
+    /// and a rejecting dead state, numbered after them, completes its transitions
+    ///
+    /// # Errors
+    pub fn to_dfa(&self) -> Result<DFA<A, usize>, &'static str> {
+        let mut symbols = self.nodes.iter().flat_map(|(_, edges)| edges.iter().map(|(sym, _)| sym.clone())).collect::<Vec<_>>();
+
+        symbols.sort_unstable();
+        symbols.dedup();
+
+        let dead = self.nodes.len();
+
+        let delta = self.nodes.iter()
+            .map(|(_, edges)| symbols.iter()
+                .map(|sym| edges.binary_search_by(|(other, _)| other.cmp(sym)).map_or(dead, |edge| edges[edge].1))
+                .collect())
+            .chain([vec![dead; symbols.len()]])
+            .collect::<Vec<_>>();
+
+        let states = self.nodes.iter()
+            .map(|(r#final, _)| *r#final)
+            .chain([false])
+            .enumerate()
+            .map(|(idx, r#final)| (vec![idx], r#final))
+            .collect();
+
+        Ok(DFA::from_table(Σ::new(symbols)?, states, 0, &delta))
+    }
+
+    fn walk(&self, word: &[A]) -> Option<usize> {
+        word.iter().try_fold(0, |node, sym| {
+            let edges = &self.nodes[node].1;
+
+            edges.binary_search_by(|(other, _)| other.cmp(sym)).ok().map(|edge| edges[edge].1)
+        })
+    }
+}
+
+impl<A> Dawg<A> {
+    /// Number of words of this dawg
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Does this dawg contain no words
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of nodes of this dawg, the states of its minimal automaton without
+    /// a dead state
+    #[must_use]
+    pub const fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+}
+
+impl Dawg<char> {
+    /// Compact form of this dawg, its symbols followed by its nodes in topological
+    /// order, each with its finality and transitions, as variable length integers
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)] // the symbols are those of the transitions
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut symbols = self.nodes.iter().flat_map(|(_, edges)| edges.iter().map(|(sym, _)| *sym)).collect::<Vec<_>>();
+
+        symbols.sort_unstable();
+        symbols.dedup();
+
+        let mut bytes = Vec::new();
+
+        write(&mut bytes, symbols.len());
+
+        for sym in &symbols {
+            write(&mut bytes, *sym as usize);
+        }
+
+        write(&mut bytes, self.nodes.len());
+
+        for (r#final, edges) in &self.nodes {
+            write(&mut bytes, edges.len() << 1 | usize::from(*r#final));
+
+            for (sym, next) in edges {
+                write(&mut bytes, symbols.binary_search(sym).expect(UNREACHABLE_ERR));
+                write(&mut bytes, *next);
+            }
+        }
+
+        bytes
+    }
+
+    /// Dawg read from its compact form, see [`Dawg::to_bytes`]
+    ///
+    /// # Errors
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, &'static str> {
+        let mut reader = Reader { bytes, position: 0 };
+
+        let symbols = (0..reader.read()?)
+            .map(|_| u32::try_from(reader.read()?).ok().and_then(char::from_u32).ok_or(ERR_INVALID_BYTES))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if symbols.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(ERR_INVALID_BYTES);
+        }
+
+        let count = reader.read()?;
+        let mut nodes = Vec::new();
+
+        for node in 0..count {
+            let header = reader.read()?;
+
+            let edges = (0..header >> 1)
+                .map(|_| {
+                    let sym = symbols.get(reader.read()?).copied().ok_or(ERR_INVALID_BYTES)?;
+                    let next = reader.read()?;
+
+                    // transitions lead forward, so the nodes are acyclic
+                    if node < next && next < count { Ok((sym, next)) } else { Err(ERR_INVALID_BYTES) }
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if edges.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+                return Err(ERR_INVALID_BYTES);
+            }
+
+            nodes.push((header & 1 == 1, edges));
+        }
+
+        if nodes.is_empty() || reader.position < bytes.len() {
+            return Err(ERR_INVALID_BYTES);
+        }
+
+        // words ending below each node, counted from the last node backwards
+        let mut words = vec![0; nodes.len()];
+
+        for (node, (r#final, edges)) in nodes.iter().enumerate().rev() {
+            words[node] = edges.iter()
+                .try_fold(usize::from(*r#final), |count, (_, next)| count.checked_add(words[*next]))
+                .ok_or(ERR_INVALID_BYTES)?;
+        }
+
+        Ok(Self { len: words[0], nodes })
+    }
+}
+
+impl<A: Debug> Debug for Dawg<A> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Dawg")
+            .field("len", &self.len)
+            .field("nodes", &self.nodes)
+            .finish()
+    }
+}
+
+// depth first iteration of the words below a node, in lexicographic order
+struct Words<'a, A> {
+    nodes: &'a [Node<A>],
+    // nodes along the current word, with their next transition
+    stack: Vec<(usize, usize)>,
+    word: Vec<A>,
+    // node just entered, whose word is yet to be reported
+    entering: Option<usize>,
+}
+
+impl<A: Clone> Iterator for Words<'_, A> {
+    type Item = Vec<A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.entering.take().is_some_and(|node| self.nodes[node].0) {
+            return Some(self.word.clone());
+        }
+
+        loop {
+            let (node, edge) = self.stack.last_mut()?;
+
+            if let Some((sym, next)) = self.nodes[*node].1.get(*edge) {
+                *edge += 1;
+                self.word.push(sym.clone());
+                self.stack.push((*next, 0));
+
+                if self.nodes[*next].0 {
+                    return Some(self.word.clone());
+                }
+            } else {
+                self.stack.pop();
+
+                if !self.stack.is_empty() {
+                    self.word.pop();
+                }
+            }
+        }
+    }
+}
+
+// minimizes the nodes of the previous word after its first `keep` symbols, from
+// the deepest, each replaced by an equivalent registered node or registered itself
+fn replace_or_register<A: Clone + Eq + Hash>(
+    nodes: &mut [Node<A>], register: &mut HashMap<Node<A>, usize>, path: &mut Vec<usize>, keep: usize,
+) {
+    while path.len() > keep + 1 {
+        let node = path.pop().expect(UNREACHABLE_ERR);
+        let parent = *path.last().expect(UNREACHABLE_ERR);
+
+        match register.get(&nodes[node]) {
+            Some(equivalent) => {
+                let equivalent = *equivalent;
+
+                if let Some(edge) = nodes[parent].1.last_mut() {
+                    edge.1 = equivalent;
+                }
+            }
+            None => {
+                register.insert(nodes[node].clone(), node);
+            }
+        }
+    }
+}
+
+// nodes reachable from the root renumbered in topological order, the root first
+fn topological<A: Clone>(nodes: &[Node<A>]) -> Vec<Node<A>> {
+    let mut order = Vec::new();
+    let mut visited = vec![false; nodes.len()];
+    let mut stack = vec![(0, 0)];
+
+    visited[0] = true;
+
+    // depth first, nodes are added once all the nodes they lead to are
+    while let Some((node, edge)) = stack.pop() {
+        if let Some((_, next)) = nodes[node].1.get(edge) {
+            stack.push((node, edge + 1));
+
+            if !visited[*next] {
+                visited[*next] = true;
+                stack.push((*next, 0));
+            }
+        } else {
+            order.push(node);
+        }
+    }
+
+    order.reverse();
+
+    let mut number = vec![0; nodes.len()];
+
+    for (idx, node) in order.iter().enumerate() {
+        number[*node] = idx;
+    }
+
+    order.iter()
+        .map(|node| {
+            let (r#final, edges) = &nodes[*node];
+
+            (*r#final, edges.iter().map(|(sym, next)| (sym.clone(), number[*next])).collect())
+        })
+        .collect()
+}
+
+fn write(bytes: &mut Vec<u8>, value: usize) {
+    let mut value = value;
+
+    while value >= 0x80 {
+        bytes.push(u8::try_from(value & 0x7F).expect(UNREACHABLE_ERR) | 0x80);
+        value >>= 7;
+    }
+
+    bytes.push(u8::try_from(value).expect(UNREACHABLE_ERR));
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Reader<'_> {
+    fn read(&mut self) -> Result<usize, &'static str> {
+        let mut value = 0_usize;
+
+        for shift in (0..usize::BITS).step_by(7) {
+            let byte = *self.bytes.get(self.position).ok_or(ERR_INVALID_BYTES)?;
+
+            self.position += 1;
+            value |= usize::from(byte & 0x7F).checked_shl(shift).ok_or(ERR_INVALID_BYTES)?;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(ERR_INVALID_BYTES)
+    }
+}
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::automata::{DFA, NFA};
+
+impl<A: Clone + Eq + Hash, S: Clone + Eq + Hash> NFA<A, S> {
+    /// Equivalent [`DFA`] using the subset construction, each state of the
+    /// resulting automaton is tagged with the states of the subset it represents,
+    /// in declared order, the empty subset, if reachable, being a dead state
+    #[must_use]
+    pub fn determinize(&self) -> DFA<A, S> {
+        let table = self.table();
+        let mut subsets = vec![vec![table.initial]];
+        let mut index = HashMap::from([(vec![table.initial], 0)]);
+        let mut delta = Vec::new();
+        let mut current = 0;
+
+        while current < subsets.len() {
+            let transitions = (0..table.symbols.len())
+                .map(|sym| {
+                    let mut next = subsets[current].iter()
+                        .flat_map(|state| table.delta[*state][sym].iter().copied())
+                        .collect::<Vec<_>>();
+
+                    next.sort_unstable();
+                    next.dedup();
+
+                    *index.entry(next).or_insert_with_key(|next| {
+                        subsets.push(next.clone());
+                        subsets.len() - 1
+                    })
+                })
+                .collect();
+
+            delta.push(transitions);
+            current += 1;
+        }
+
+        let states = subsets.into_iter()
+            .map(|subset| (
+                subset.iter().flat_map(|state| table.states[*state].iter().cloned()).collect(),
+                subset.iter().any(|state| table.is_final(*state)),
+            ))
+            .collect();
+
+        DFA::from_table(self.alphabet().clone(), states, 0, &delta)
+    }
+}
@@ -1,25 +1,37 @@
 use std::collections::HashMap;
 use std::fmt::{self, Debug, Formatter};
-use std::hash::Hash;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::ops::RangeInclusive;
 
 use crate::automata::{
-    convert_to_transitions, ERR_DANGLING_STATE, ERR_INVALID_INPUT,
-    get_initial_state, Transitions, validate_fa_configuration,
+    convert_to_defaults, convert_to_transitions, Defaults, Discrete, ERR_DANGLING_STATE,
+    ERR_INVALID_INPUT, get_initial_state, Transitions, validate_fa_configuration,
 };
-use crate::model::{F, δ};
+use crate::automata::table::{build_transitions, DfaTable, reachable};
+use crate::model::{F, Intervals, Phase, δ};
 use crate::model::state::{Q, State};
 use crate::model::Σ;
 use crate::UNREACHABLE_ERR;
+use crate::utils::duped::Duped;
 
 pub const ERR_DUPLICATE_INPUT_TRANSITION: &str = "Each state transition must define unique input transitions";
 pub const ERR_INCOMPLETE_INPUT_TRANSITIONS: &str = "Each transition function must define a transition state for all inputs";
 pub const ERR_UNDEFINED_SYMBOL: &str = "Symbol is not defined in input transitions";
 
-///
+/// Deterministic finite automaton, (Q, Σ, δ, q0, F)
 #[allow(clippy::upper_case_acronyms)]
+#[allow(non_snake_case)]
 pub struct DFA<A, S: Hash> {
+    Σ: Σ<A>,
+    // positions of the symbols of Σ by their hash, finding a symbol without
+    // searching Σ or owning another copy of it
+    symbols: HashMap<u64, Vec<usize>>,
+    // classes of an interval alphabet, Σ holding the first symbol of each class
+    intervals: Option<Intervals<A>>,
+    states: Vec<State<S>>,
     current: State<S>,
     transitions: Transitions<A, S>,
+    defaults: Defaults<S>,
 }
 
 impl<A: Eq + Hash, S: Eq + Hash> DFA<A, S> {
@@ -28,39 +40,72 @@ impl<A: Eq + Hash, S: Eq + Hash> DFA<A, S> {
     pub fn new(Q: Q<S>, Σ: &Σ<A>, δ: δ<A, S>, q0: S, F: &F<S>) -> Result<Self, &'static str> {
         validate_fa_configuration(&Q, &δ, &q0, F)?;
 
-        let mut transitions = convert_to_transitions(Q, q0, F, &δ)?;
+        let (states, mut transitions) = convert_to_transitions(Q, q0, F, &δ)?;
 
-        Self::add_input_transitions(&mut transitions, Σ, δ)?;
+        let defaults = Self::add_input_transitions(&mut transitions, Σ, δ)?;
 
-        Self::validate_dfa(&transitions)?;
+        Self::validate_dfa(&transitions, &defaults)?;
 
         Ok(Self {
+            Σ: Σ.clone(),
+            symbols: index_symbols(Σ),
+            intervals: None,
+            states,
             current: get_initial_state(&transitions),
             transitions,
+            defaults,
         })
     }
 
-    ///
+    /// Alphabet Σ of this automaton, in declared order
+    #[must_use]
+    pub const fn alphabet(&self) -> &Σ<A> {
+        &self.Σ
+    }
+
+    /// Classes of the interval alphabet of this automaton, `None` if it was
+    /// defined over an alphabet Σ of symbols
+    #[must_use]
+    pub const fn intervals(&self) -> Option<&Intervals<A>> {
+        self.intervals.as_ref()
+    }
+
+    /// Current state of this automaton
+    #[must_use]
+    pub const fn current(&self) -> &State<S> {
+        &self.current
+    }
+
+    /// Initial state q0 of this automaton
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)] // validated on construction
+    pub fn initial(&self) -> &State<S> {
+        self.states.iter().find(|state| state.is_initial()).expect(UNREACHABLE_ERR)
+    }
+
+    /// Does the current state belong to the final states F
     #[must_use]
     pub const fn matches(&self) -> bool {
         self.current.is_final()
     }
 
-    /// # Errors
+    /// Sets the current state back to the initial state q0
     pub fn reset(&mut self) {
         self.current = get_initial_state(&self.transitions);
     }
 
+    /// States Q of this automaton, in declared order
+    pub fn states(&self) -> impl Iterator<Item=&State<S>> {
+        self.states.iter()
+    }
+
+    /// Moves to the state δ(current, input), following the default transition of
+    /// the current state if it defines none for the input, with an interval
+    /// alphabet the input may be any symbol of its classes
+    ///
     /// # Errors
-    #[allow(clippy::missing_panics_doc)] // see comments in method
     pub fn step(&mut self, input: &A) -> Result<&State<S>, &'static str> {
-        let (_, transitions) = self.transitions.get_key_value(&self.current).expect(UNREACHABLE_ERR);
-
-        let next = transitions.get(input).ok_or(ERR_INVALID_INPUT)?;
-
-        let (current, _) = self.transitions.get_key_value(next).expect(UNREACHABLE_ERR);
-
-        self.current = current.clone();
+        self.current = self.transition(&self.current, input).ok_or(ERR_INVALID_INPUT)?.clone();
 
         Ok(&self.current)
     }
@@ -74,20 +119,52 @@ impl<A: Eq + Hash, S: Eq + Hash> DFA<A, S> {
         Ok(&self.current)
     }
 
+    /// Transition δ(state, input), the default transition of the state if it
+    /// defines none for the input, `None` if either is not defined in this automaton
+    #[must_use]
+    pub fn transition(&self, state: &State<S>, input: &A) -> Option<&State<S>> {
+        let input = self.symbol(input)?;
+
+        let next = match self.transitions.get(state)?.get(input) {
+            Some(next) => next,
+            None => self.defaults.get(state).filter(|_| self.contains(input))?,
+        };
+
+        self.transitions.get_key_value(next).map(|(state, _)| state)
+    }
+
+    pub(crate) fn table(&self) -> DfaTable<'_, A, S> {
+        DfaTable::new(&self.Σ, self.intervals.as_ref(), &self.states, &self.transitions, &self.defaults)
+    }
+
+    // is the symbol in Σ
+    fn contains(&self, sym: &A) -> bool {
+        self.symbols.get(&hash(sym)).is_some_and(|positions| positions.iter().any(|idx| self.Σ[*idx] == *sym))
+    }
+
+    // symbol of Σ standing for the input, the first symbol of its class with an
+    // interval alphabet, found by binary search
+    fn symbol<'a>(&'a self, input: &'a A) -> Option<&'a A> {
+        self.intervals.as_ref().map_or(Some(input), |intervals| intervals.class(input).map(|class| &self.Σ[class]))
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn add_input_transitions(
         state_transitions: &mut Transitions<A, S>, Σ: &Σ<A>, δ: δ<A, S>,
-    ) -> Result<(), &'static str> {
+    ) -> Result<Defaults<S>, &'static str> {
+        let (δ, defaults) = δ.into_parts();
+        let defaults = convert_to_defaults(state_transitions, defaults)?;
+
         for (state, input_transitions) in δ {
             let state_key = vec![state];
 
-            if Σ.iter()
+            if !defaults.contains_key(&state_key) && Σ.iter()
                 .any(|sym1| !input_transitions.iter().any(|(sym2, _)| sym2 == sym1)) {
                 return Err(ERR_INCOMPLETE_INPUT_TRANSITIONS);
             } else if input_transitions.iter().any(|(sym, _)| !Σ.contains(sym)) {
                 return Err(ERR_UNDEFINED_SYMBOL);
-            } else if Σ.iter().count() != input_transitions.len() {
+            } else if input_transitions.iter().map(|(sym, _)| sym).has_dupes() {
                 return Err(ERR_DUPLICATE_INPUT_TRANSITION);
             }
 
@@ -95,16 +172,17 @@ impl<A: Eq + Hash, S: Eq + Hash> DFA<A, S> {
                 .map(|(sym, state)| (sym, vec![state]))
                 .collect::<HashMap<A, Vec<S>>>();
 
-            let entry = state_transitions.get_mut(&state_key).expect(UNREACHABLE_ERR);
+            // states without transitions to them are not in the transitions table
+            let entry = state_transitions.get_mut(&state_key).ok_or(ERR_DANGLING_STATE)?;
 
             entry.extend(inputs);
         }
 
-        Ok(())
+        Ok(defaults)
     }
 
     #[inline]
-    fn validate_dfa(state_transitions: &Transitions<A, S>) -> Result<(), &'static str> {
+    fn validate_dfa(state_transitions: &Transitions<A, S>, defaults: &Defaults<S>) -> Result<(), &'static str> {
         let transition_states = |transition_state| state_transitions.iter()
             .filter_map(
                 move |(state, input_transitions)|
@@ -117,7 +195,8 @@ impl<A: Eq + Hash, S: Eq + Hash> DFA<A, S> {
 
         if state_transitions.keys().any(|state| {
             !state.is_initial() &&
-                transition_states(state).all(|input_transition| input_transition != state.as_ref())
+                transition_states(state).all(|input_transition| input_transition != state.as_ref()) &&
+                defaults.iter().all(|(from, next)| from == state || next != state.as_ref())
         }) {
             Err(ERR_DANGLING_STATE)
         } else {
@@ -126,11 +205,128 @@ impl<A: Eq + Hash, S: Eq + Hash> DFA<A, S> {
     }
 }
 
+impl<A: Discrete + Hash, S: Clone + Eq + Hash> DFA<A, S> {
+    /// Automaton over an interval alphabet, whose transitions are on ranges of
+    /// symbols, the classes of the alphabet being split further at the boundaries
+    /// of the ranges, the ranges of each state must be disjoint and cover the
+    /// alphabet, unless it has a default transition
+    ///
+    /// # Errors
+    #[allow(non_snake_case)]
+    pub fn from_intervals(
+        Q: Q<S>, Σ: &Intervals<A>, δ: δ<RangeInclusive<A>, S>, q0: S, F: &F<S>,
+    ) -> Result<Self, &'static str> {
+        if δ.iter().any(|(_, transitions)| transitions.iter().any(|(range, _)| !Σ.contains(range))) {
+            return Err(ERR_UNDEFINED_SYMBOL);
+        } else if δ.iter().any(|(_, transitions)| transitions.iter().enumerate().any(|(idx, (range, _))|
+            transitions[..idx].iter().any(|(other, _)| range.start() <= other.end() && other.start() <= range.end())
+        )) {
+            return Err(ERR_DUPLICATE_INPUT_TRANSITION);
+        } else if δ.iter().any(|(state, transitions)| δ.default_of(state).is_none() &&
+            !Σ.is_covered_by(&transitions.iter().map(|(range, _)| range.clone()).collect::<Vec<_>>())
+        ) {
+            return Err(ERR_INCOMPLETE_INPUT_TRANSITIONS);
+        }
+
+        let intervals = Σ.refine(δ.iter().flat_map(|(_, transitions)| transitions.iter().map(|(range, _)| range)));
+        let symbols = Σ::new(intervals.iter().map(|class| *class.start()).collect())?;
+
+        let (δ, defaults) = δ.into_parts();
+
+        let δ = δ::with_defaults(δ.into_iter()
+            .map(|(state, transitions)| {
+                let transitions = symbols.iter()
+                    .filter_map(|sym| transitions.iter()
+                        .find(|(range, _)| range.contains(sym))
+                        .map(|(_, next)| (*sym, next.clone())))
+                    .collect();
+
+                (state, transitions)
+            })
+            .collect(), defaults)?;
+
+        let mut dfa = Self::new(Q, &symbols, δ, q0, F)?;
+
+        dfa.intervals = Some(intervals);
+
+        Ok(dfa)
+    }
+}
+
+impl<A: Clone + Eq + Hash, S: Clone + Eq + Hash> DFA<A, S> {
+    // builds a dfa from an index based table, δ[state][symbol], of unique states,
+    // states not reachable from the initial state are dropped
+    #[allow(non_snake_case)]
+    pub(crate) fn from_table(
+        Σ: Σ<A>, states: Vec<(Vec<S>, bool)>, initial: usize, delta: &[Vec<usize>],
+    ) -> Self {
+        let order = reachable(initial, delta);
+        let mut index = vec![usize::MAX; delta.len()];
+
+        for (new, old) in order.iter().enumerate() {
+            index[*old] = new;
+        }
+
+        let mut states = states.into_iter().map(Some).collect::<Vec<_>>();
+
+        let states = order.iter()
+            .map(|old| {
+                let (tags, r#final) = states[*old].take().expect(UNREACHABLE_ERR);
+
+                State::from_tags(tags, Phase::of(*old == initial, r#final))
+            })
+            .collect::<Vec<_>>();
+
+        let delta = order.iter()
+            .map(|old| delta[*old].iter().map(|next| vec![index[*next]]).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let transitions = build_transitions(&Σ, &states, &delta);
+
+        Self {
+            symbols: index_symbols(&Σ),
+            Σ,
+            intervals: None,
+            current: states[0].clone(),
+            states,
+            transitions,
+            defaults: HashMap::new(),
+        }
+    }
+
+    // keeps the interval alphabet of the dfa this one is derived from, over the same Σ
+    pub(crate) fn with_intervals_of(mut self, other: &Self) -> Self {
+        self.intervals.clone_from(&other.intervals);
+        self
+    }
+}
+
 impl<A: Debug, S: Debug + Hash> Debug for DFA<A, S> {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
         fmt.debug_struct("DFA")
+            .field("Σ", &self.Σ)
+            .field("intervals", &self.intervals)
             .field("δ", &self.transitions)
+            .field("defaults", &self.defaults)
             .field("current", &self.current)
-            .finish()
+            .finish_non_exhaustive()
     }
 }
+
+#[allow(non_snake_case)]
+fn index_symbols<A: Hash>(Σ: &Σ<A>) -> HashMap<u64, Vec<usize>> {
+    let mut symbols = HashMap::<_, Vec<_>>::new();
+
+    for (idx, sym) in Σ.iter().enumerate() {
+        symbols.entry(hash(sym)).or_default().push(idx);
+    }
+
+    symbols
+}
+
+fn hash<A: Hash>(sym: &A) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    sym.hash(&mut hasher);
+    hasher.finish()
+}
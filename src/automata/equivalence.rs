@@ -0,0 +1,93 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::automata::DFA;
+use crate::automata::table::DfaTable;
+
+impl<A: Clone + Eq + Hash, S: Eq + Hash> DFA<A, S> {
+    /// Do both automata accept the same language, symbols not defined in
+    /// either alphabet lead to a rejecting dead state
+    #[must_use]
+    pub fn equivalent<T: Eq + Hash>(&self, other: &DFA<A, T>) -> bool {
+        self.distinguishing_word(other).is_none()
+    }
+
+    /// Shortest word, in shortlex order of the combined alphabets, accepted by
+    /// exactly one of both automata, `None` if they are equivalent
+    ///
+    /// With interval alphabets the product is over the common classes of both,
+    /// each standing for its first symbol
+    #[must_use]
+    pub fn distinguishing_word<T: Eq + Hash>(&self, other: &DFA<A, T>) -> Option<Vec<A>> {
+        let lhs = self.table();
+        let rhs = other.table();
+
+        let symbols = common_symbols(&lhs, &rhs);
+
+        let lhs_symbols = symbols.iter().map(|sym| lhs.symbol(sym)).collect::<Vec<_>>();
+        let rhs_symbols = symbols.iter().map(|sym| rhs.symbol(sym)).collect::<Vec<_>>();
+
+        let start = (Some(lhs.initial), Some(rhs.initial));
+        let mut parents: HashMap<_, Option<(_, usize)>> = HashMap::from([(start, None)]);
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(pair @ (left, right)) = queue.pop_front() {
+            let accepted = |state: Option<usize>, r#final: &dyn Fn(usize) -> bool| state.is_some_and(r#final);
+
+            if accepted(left, &|state| lhs.is_final(state)) != accepted(right, &|state| rhs.is_final(state)) {
+                let mut word = Vec::new();
+                let mut current = pair;
+
+                while let Some(&Some((previous, sym))) = parents.get(&current) {
+                    word.push(symbols[sym].clone());
+                    current = previous;
+                }
+
+                word.reverse();
+
+                return Some(word);
+            }
+
+            for sym in 0..symbols.len() {
+                let next = (
+                    left.zip(lhs_symbols[sym]).map(|(state, sym)| lhs.delta[state][sym]),
+                    right.zip(rhs_symbols[sym]).map(|(state, sym)| rhs.delta[state][sym]),
+                );
+
+                if let std::collections::hash_map::Entry::Vacant(entry) = parents.entry(next) {
+                    entry.insert(Some((pair, sym)));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+// a symbol of each class of inputs neither automaton tells apart, the first
+// symbols of the common classes of interval alphabets, otherwise the symbols of
+// Σ followed by the first symbol of each class not among them
+fn common_symbols<A: Clone + Eq + Hash, S, T>(lhs: &DfaTable<'_, A, S>, rhs: &DfaTable<'_, A, T>) -> Vec<A> {
+    if let (Some(left), Some(right)) = (lhs.intervals, rhs.intervals) {
+        return left.common(right).iter().map(|class| class.start().clone()).collect();
+    }
+
+    let mut symbols: Vec<A> = Vec::new();
+
+    for sym in [lhs.intervals.map_or(lhs.symbols, |_| &[]), rhs.intervals.map_or(rhs.symbols, |_| &[])].concat() {
+        if !symbols.contains(&sym) {
+            symbols.push(sym);
+        }
+    }
+
+    for intervals in [lhs.intervals, rhs.intervals].into_iter().flatten() {
+        for class in 0..intervals.len() {
+            let first = (0..).map_while(|n| intervals.nth(class, n)).find(|sym| !symbols.contains(sym));
+
+            symbols.extend(first);
+        }
+    }
+
+    symbols
+}
@@ -0,0 +1,157 @@
+use std::collections::VecDeque;
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+
+use crate::automata::DFA;
+use crate::automata::table::DfaTable;
+use crate::model::State;
+
+/// Reason a [`DFA`] accepts or rejects an input, see [`DFA::explain`]
+#[derive(Debug)]
+pub enum Explanation<A, S> {
+    /// Input is accepted
+    Accepted,
+
+    /// Symbol at the position is not defined in Σ, read in the given state
+    UndefinedSymbol {
+        /// Position of the undefined symbol in the input
+        position: usize,
+
+        /// State the symbol was read in
+        state: State<S>,
+    },
+
+    /// Input entered a state from which no final state is reachable
+    DeadState {
+        /// Position of the symbol leading into the state, `None` if it is the initial state
+        position: Option<usize>,
+
+        /// State without a path to a final state
+        state: State<S>,
+    },
+
+    /// Input ended in a state which is not final
+    NotFinal {
+        /// State the input ended in
+        state: State<S>,
+
+        /// Shortest suffix, in shortlex order of Σ, leading to a final state
+        suffix: Vec<A>,
+    },
+}
+
+impl<A: Display, S: Display> Display for Explanation<A, S> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Accepted => fmt.write_str("accepted"),
+            Self::UndefinedSymbol { position, state } =>
+                write!(fmt, "symbol at position {position} is not defined, read in state {state}"),
+            Self::DeadState { position: Some(position), state } =>
+                write!(fmt, "symbol at position {position} leads to state {state}, from which no final state is reachable"),
+            Self::DeadState { position: None, state } =>
+                write!(fmt, "no final state is reachable from initial state {state}"),
+            Self::NotFinal { state, suffix } => {
+                write!(fmt, "ended in state {state}, which is not final, accepted if followed by [")?;
+
+                for (idx, sym) in suffix.iter().enumerate() {
+                    write!(fmt, "{}{sym}", if idx == 0 { "" } else { ", " })?;
+                }
+
+                fmt.write_str("]")
+            }
+        }
+    }
+}
+
+impl<A: Clone + Eq + Hash, S: Eq + Hash> DFA<A, S> {
+    /// Explains why an input, read from the initial state, is accepted or rejected,
+    /// without changing the current state of this automaton
+    #[must_use]
+    pub fn explain(&self, inputs: &[A]) -> Explanation<A, S> {
+        let table = self.table();
+        let live = live_states(&table);
+        let mut current = table.initial;
+
+        if !live[current] {
+            return Explanation::DeadState { position: None, state: table.states[current].clone() };
+        }
+
+        for (position, input) in inputs.iter().enumerate() {
+            let Some(sym) = table.symbol(input) else {
+                return Explanation::UndefinedSymbol { position, state: table.states[current].clone() };
+            };
+
+            current = table.delta[current][sym];
+
+            if !live[current] {
+                return Explanation::DeadState { position: Some(position), state: table.states[current].clone() };
+            }
+        }
+
+        if table.is_final(current) {
+            Explanation::Accepted
+        } else {
+            Explanation::NotFinal { state: table.states[current].clone(), suffix: shortest_suffix(&table, current) }
+        }
+    }
+}
+
+// states from which a final state is reachable
+pub fn live_states<A, S>(table: &DfaTable<'_, A, S>) -> Vec<bool> {
+    let mut predecessors = vec![Vec::new(); table.states.len()];
+
+    for (state, transitions) in table.delta.iter().enumerate() {
+        for next in transitions {
+            predecessors[*next].push(state);
+        }
+    }
+
+    let mut live = table.states.iter().map(State::is_final).collect::<Vec<_>>();
+    let mut queue = live.iter().enumerate().filter(|(_, live)| **live).map(|(state, _)| state).collect::<VecDeque<_>>();
+
+    while let Some(state) = queue.pop_front() {
+        for previous in &predecessors[state] {
+            if !live[*previous] {
+                live[*previous] = true;
+                queue.push_back(*previous);
+            }
+        }
+    }
+
+    live
+}
+
+// shortest word, in shortlex order of Σ, leading from a live state to a final state
+fn shortest_suffix<A: Clone, S>(table: &DfaTable<'_, A, S>, start: usize) -> Vec<A> {
+    let mut parents: Vec<Option<(usize, usize)>> = vec![None; table.states.len()];
+    let mut visited = vec![false; table.states.len()];
+    let mut queue = VecDeque::from([start]);
+
+    visited[start] = true;
+
+    while let Some(state) = queue.pop_front() {
+        if table.states[state].is_final() {
+            let mut suffix = Vec::new();
+            let mut current = state;
+
+            while let Some((previous, sym)) = parents[current] {
+                suffix.push(table.symbols[sym].clone());
+                current = previous;
+            }
+
+            suffix.reverse();
+
+            return suffix;
+        }
+
+        for (sym, next) in table.delta[state].iter().enumerate() {
+            if !visited[*next] {
+                visited[*next] = true;
+                parents[*next] = Some((state, sym));
+                queue.push_back(*next);
+            }
+        }
+    }
+
+    Vec::new()
+}
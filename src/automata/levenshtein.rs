@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::automata::DFA;
+use crate::automata::table::DfaTable;
+use crate::model::Σ;
+
+/// Levenshtein automaton of a word, accepting the words within an edit distance
+/// of it
+///
+/// Edits are insertions, deletions and substitutions of symbols, and with
+/// [`Levenshtein::damerau`] transpositions of adjacent symbols, its states are
+/// rows of the edit distance table of the word, capped above the distance,
+/// computed on the fly while reading symbols, so the automaton over an alphabet
+/// is only built by [`Levenshtein::to_dfa`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Levenshtein<A> {
+    word: Vec<A>,
+    distance: usize,
+    transpositions: bool,
+}
+
+// edit distances from the symbols read to each prefix of the word, and with
+// transpositions the distances reached if the next symbol completes one
+#[derive(Clone, Eq, Hash, PartialEq)]
+struct Row {
+    costs: Vec<usize>,
+    swaps: Vec<usize>,
+}
+
+impl<A: Clone + Eq + Hash> Levenshtein<A> {
+    /// Automaton of the words within the Levenshtein distance of the word
+    #[must_use]
+    pub fn new(word: &[A], distance: usize) -> Self {
+        Self { word: word.to_vec(), distance, transpositions: false }
+    }
+
+    /// Automaton of the words within the distance of the word, counting the
+    /// transposition of two adjacent symbols as a single edit, the optimal string
+    /// alignment distance
+    #[must_use]
+    pub fn damerau(word: &[A], distance: usize) -> Self {
+        Self { word: word.to_vec(), distance, transpositions: true }
+    }
+
+    /// Edit distance between the word and the other word, `None` if it exceeds
+    /// the distance of this automaton
+    #[must_use]
+    pub fn distance(&self, other: &[A]) -> Option<usize> {
+        let row = other.iter().try_fold(self.initial(), |row, sym| self.step(&row, sym))?;
+
+        self.accepted(&row)
+    }
+
+    /// [`DFA`] over Σ accepting the words within the distance of the word, its
+    /// states tagged in breadth first order from the initial state, symbols of
+    /// the word not in Σ are never matched
+    #[must_use]
+    #[allow(non_snake_case)]
+    pub fn to_dfa(&self, Σ: &Σ<A>) -> DFA<A, usize> {
+        let dead = Row { costs: vec![self.distance + 1; self.word.len() + 1], ..self.initial() };
+
+        let mut rows = vec![self.initial()];
+        let mut index = HashMap::from([(rows[0].clone(), 0)]);
+        let mut delta = Vec::new();
+
+        while delta.len() < rows.len() {
+            let current = delta.len();
+
+            let transitions = Σ.iter()
+                .map(|sym| {
+                    let next = self.step(&rows[current], sym).unwrap_or_else(|| dead.clone());
+
+                    *index.entry(next).or_insert_with_key(|next| {
+                        rows.push(next.clone());
+                        rows.len() - 1
+                    })
+                })
+                .collect();
+
+            delta.push(transitions);
+        }
+
+        let states = rows.iter().enumerate().map(|(idx, row)| (vec![idx], self.accepted(row).is_some())).collect();
+
+        DFA::from_table(Σ.clone(), states, 0, &delta)
+    }
+
+    fn initial(&self) -> Row {
+        Row {
+            costs: (0..=self.word.len()).map(|len| len.min(self.distance + 1)).collect(),
+            swaps: if self.transpositions { vec![self.distance + 1; self.word.len() + 1] } else { Vec::new() },
+        }
+    }
+
+    // row after reading the symbol, `None` once every prefix is beyond the distance
+    fn step(&self, row: &Row, sym: &A) -> Option<Row> {
+        let cap = self.distance + 1;
+        let mut costs = vec![cap; self.word.len() + 1];
+
+        costs[0] = (row.costs[0] + 1).min(cap);
+
+        for len in 1..=self.word.len() {
+            let substitution = row.costs[len - 1] + usize::from(self.word[len - 1] != *sym);
+            let mut cost = substitution.min(row.costs[len] + 1).min(costs[len - 1] + 1);
+
+            if self.transpositions && len >= 2 && self.word[len - 2] == *sym {
+                cost = cost.min(row.swaps[len]);
+            }
+
+            costs[len] = cost.min(cap);
+        }
+
+        let swaps = if self.transpositions {
+            (0..=self.word.len())
+                .map(|len| if len >= 2 && self.word[len - 1] == *sym { (row.costs[len - 2] + 1).min(cap) } else { cap })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        costs.iter().any(|cost| *cost < cap).then_some(Row { costs, swaps })
+    }
+
+    fn accepted(&self, row: &Row) -> Option<usize> {
+        row.costs.last().copied().filter(|cost| *cost <= self.distance)
+    }
+}
+
+impl<A: Clone + Eq + Hash, S: Eq + Hash> DFA<A, S> {
+    /// Words accepted by this automaton within the distance of the word of the
+    /// Levenshtein automaton, with their distance, in lexicographic order of Σ,
+    /// each class of an interval alphabet expanded into its symbols
+    ///
+    /// Found by depth first search of the product, stepping the Levenshtein rows
+    /// along the transitions of this automaton and pruning where no word within
+    /// the distance is left, so the product is never built
+    #[must_use]
+    pub fn fuzzy_matches(&self, levenshtein: &Levenshtein<A>) -> Vec<(Vec<A>, usize)> {
+        let table = self.table();
+        let mut matches = Vec::new();
+        let mut word = Vec::new();
+
+        search(&table, levenshtein, table.initial, &levenshtein.initial(), &mut word, &mut matches);
+
+        matches
+    }
+}
+
+// words below the state of this automaton and the row, depth first
+fn search<A: Clone + Eq + Hash, S: Eq + Hash>(
+    table: &DfaTable<'_, A, S>, levenshtein: &Levenshtein<A>, state: usize, row: &Row,
+    word: &mut Vec<A>, matches: &mut Vec<(Vec<A>, usize)>,
+) {
+    if let Some(distance) = levenshtein.accepted(row).filter(|_| table.is_final(state)) {
+        matches.push((word.clone(), distance));
+    }
+
+    for (sym, next) in table.delta[state].iter().enumerate() {
+        for input in table.inputs(sym) {
+            if let Some(row) = levenshtein.step(row, &input) {
+                word.push(input);
+                search(table, levenshtein, *next, &row, word, matches);
+                word.pop();
+            }
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+
+use crate::automata::{ERR_DANGLING_STATE, ERR_INVALID_INPUT, ERR_UNDEFINED_INITIAL_STATE, ERR_UNREFERENCED_STATE_Q};
+use crate::automata::dfa::{ERR_INCOMPLETE_INPUT_TRANSITIONS, ERR_UNDEFINED_SYMBOL};
+use crate::automata::minimize::partition;
+use crate::automata::table::reachable;
+use crate::model::{Phase, Q, State, λ, Σ};
+use crate::UNREACHABLE_ERR;
+
+pub const ERR_UNDEFINED_STATE: &str = "Transition function state is not defined in Q";
+
+/// Mealy machine, a transducer emitting an output on each transition, (Q, Σ, Γ, λ, q0)
+#[allow(non_snake_case)]
+pub struct Mealy<I, O, S: Hash> {
+    Σ: Σ<I>,
+    states: Vec<State<S>>,
+    index: HashMap<State<S>, usize>,
+    delta: Vec<Vec<(usize, O)>>,
+    current: usize,
+}
+
+impl<I: Clone + Eq + Hash, O, S: Eq + Hash> Mealy<I, O, S> {
+    /// # Errors
+    #[allow(non_snake_case)]
+    #[allow(clippy::missing_panics_doc)] // states and symbols are validated before indexing
+    pub fn new(Q: Q<S>, Σ: &Σ<I>, λ: λ<I, S, O>, q0: S) -> Result<Self, &'static str> {
+        if Q.iter().any(|q| λ.iter().all(|(state, _)| state != q)) {
+            return Err(ERR_UNREFERENCED_STATE_Q);
+        } else if λ.iter().any(|(state, _)| !Q.contains(state)) {
+            return Err(ERR_UNDEFINED_STATE);
+        } else if !Q.contains(&q0) {
+            return Err(ERR_UNDEFINED_INITIAL_STATE);
+        }
+
+        let states = Vec::from(Q).into_iter()
+            .map(|tag| {
+                let phase = Phase::of(tag == q0, false);
+
+                State::new(vec![tag], phase)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let index = states.iter().enumerate().map(|(idx, state)| (state.clone(), idx)).collect::<HashMap<_, _>>();
+        let mut delta = (0..states.len()).map(|_| Vec::new()).collect::<Vec<_>>();
+
+        for (state, transitions) in λ {
+            if Σ.iter().any(|sym| transitions.iter().all(|(input, _, _)| input != sym)) {
+                return Err(ERR_INCOMPLETE_INPUT_TRANSITIONS);
+            } else if transitions.iter().any(|(sym, _, _)| !Σ.contains(sym)) {
+                return Err(ERR_UNDEFINED_SYMBOL);
+            }
+
+            let mut transitions = transitions.into_iter()
+                .map(|(sym, next, output)| (sym, (index[&vec![next]], output)))
+                .collect::<HashMap<_, _>>();
+
+            delta[index[&vec![state]]] = Σ.iter()
+                .map(|sym| transitions.remove(sym).expect(UNREACHABLE_ERR))
+                .collect();
+        }
+
+        let initial = index[&vec![q0]];
+
+        if (0..states.len()).any(|state|
+            state != initial && delta.iter().enumerate().all(|(from, transitions)|
+                from == state || transitions.iter().all(|(next, _)| *next != state)
+            )
+        ) {
+            return Err(ERR_DANGLING_STATE);
+        }
+
+        Ok(Self { Σ: Σ.clone(), states, index, delta, current: initial })
+    }
+
+    /// Alphabet Σ of this machine, in declared order
+    #[must_use]
+    pub const fn alphabet(&self) -> &Σ<I> {
+        &self.Σ
+    }
+
+    /// Current state of this machine
+    #[must_use]
+    pub fn current(&self) -> &State<S> {
+        &self.states[self.current]
+    }
+
+    /// Initial state q0 of this machine
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)] // validated on construction
+    pub fn initial(&self) -> &State<S> {
+        self.states.iter().find(|state| state.is_initial()).expect(UNREACHABLE_ERR)
+    }
+
+    /// Sets the current state back to the initial state q0
+    #[allow(clippy::missing_panics_doc)] // validated on construction
+    pub fn reset(&mut self) {
+        self.current = self.states.iter().position(State::is_initial).expect(UNREACHABLE_ERR);
+    }
+
+    /// States Q of this machine, in declared order
+    pub fn states(&self) -> impl Iterator<Item=&State<S>> {
+        self.states.iter()
+    }
+
+    /// Transitions on the input, returning the output emitted
+    ///
+    /// # Errors
+    /// an input symbol that is not defined in Σ
+    pub fn step(&mut self, input: &I) -> Result<&O, &'static str> {
+        let sym = self.symbol(input).ok_or(ERR_INVALID_INPUT)?;
+        let (next, output) = &self.delta[self.current][sym];
+
+        self.current = *next;
+
+        Ok(output)
+    }
+
+    /// Transition δ(state, input) and its output, `None` if either is not defined in this machine
+    #[must_use]
+    pub fn transition(&self, state: &State<S>, input: &I) -> Option<(&State<S>, &O)> {
+        let (next, output) = &self.delta[*self.index.get(state)?][self.symbol(input)?];
+
+        Some((&self.states[*next], output))
+    }
+
+    pub(crate) fn symbol(&self, input: &I) -> Option<usize> {
+        self.Σ.iter().position(|sym| sym == input)
+    }
+}
+
+impl<I: Clone + Eq + Hash, O: Clone, S: Eq + Hash> Mealy<I, O, S> {
+    /// Steps through the inputs, returning the output emitted by each transition
+    ///
+    /// # Errors
+    /// an input symbol that is not defined in Σ
+    pub fn transduce(&mut self, inputs: &[I]) -> Result<Vec<O>, &'static str> {
+        inputs.iter().map(|input| self.step(input).cloned()).collect()
+    }
+}
+
+impl<I: Clone + Eq + Hash, O: Clone + Eq + Hash, S: Clone + Eq + Hash> Mealy<I, O, S> {
+    /// Equivalent [`Mealy`] machine with the minimum number of states, states
+    /// producing the same outputs for all inputs are merged, each merged state
+    /// keeps the tags of its first state in breadth first order from the initial state
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)] // validated on construction
+    pub fn minimize(&self) -> Self {
+        let initial = self.states.iter().position(State::is_initial).expect(UNREACHABLE_ERR);
+        let next = self.delta.iter().map(|transitions| transitions.iter().map(|(next, _)| *next).collect()).collect::<Vec<_>>();
+        let states = reachable(initial, &next);
+
+        let (classes, count) = partition(&states, &next, |state| {
+            self.delta[state].iter().map(|(_, output)| output.clone()).collect::<Vec<_>>()
+        });
+
+        let mut representatives = vec![usize::MAX; count];
+
+        for state in states.iter().rev() {
+            representatives[classes[*state]] = *state;
+        }
+
+        let delta = representatives.iter()
+            .map(|state| self.delta[*state].iter().map(|(next, output)| (classes[*next], output.clone())).collect())
+            .collect();
+
+        let states = representatives.iter().map(|state| self.states[*state].to_vec()).collect();
+
+        Self::from_table(self.Σ.clone(), states, classes[initial], delta)
+    }
+
+    // builds a machine from an index based table, δ[state][symbol], of unique
+    // states, states not reachable from the initial state are dropped
+    #[allow(non_snake_case)]
+    pub(crate) fn from_table(Σ: Σ<I>, states: Vec<Vec<S>>, initial: usize, delta: Vec<Vec<(usize, O)>>) -> Self {
+        let next = delta.iter().map(|transitions| transitions.iter().map(|(next, _)| *next).collect()).collect::<Vec<_>>();
+        let order = reachable(initial, &next);
+        let mut index = vec![usize::MAX; delta.len()];
+
+        for (new, old) in order.iter().enumerate() {
+            index[*old] = new;
+        }
+
+        let mut states = states.into_iter().map(Some).collect::<Vec<_>>();
+        let mut delta = delta.into_iter().map(Some).collect::<Vec<_>>();
+
+        let states = order.iter()
+            .map(|old| State::from_tags(states[*old].take().expect(UNREACHABLE_ERR), Phase::of(*old == initial, false)))
+            .collect::<Vec<_>>();
+
+        let delta = order.iter()
+            .map(|old| delta[*old].take().expect(UNREACHABLE_ERR).into_iter().map(|(next, output)| (index[next], output)).collect())
+            .collect();
+
+        Self {
+            Σ,
+            index: states.iter().enumerate().map(|(idx, state)| (state.clone(), idx)).collect(),
+            states,
+            delta,
+            current: 0,
+        }
+    }
+}
+
+impl<I: Debug, O: Debug, S: Debug + Hash> Debug for Mealy<I, O, S> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Mealy")
+            .field("Σ", &self.Σ)
+            .field("states", &self.states)
+            .field("λ", &self.delta)
+            .field("current", &self.states[self.current])
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::automata::DFA;
+use crate::automata::table::reachable;
+
+impl<A: Clone + Eq + Hash, S: Clone + Eq + Hash> DFA<A, S> {
+    /// Equivalent [`DFA`] with the minimum number of states, unreachable states
+    /// are removed and equivalent states merged, each merged state keeps the
+    /// tags of its first state in breadth first order from the initial state
+    #[must_use]
+    pub fn minimize(&self) -> Self {
+        let table = self.table();
+        let states = reachable(table.initial, &table.delta);
+        let (classes, count) = partition(&states, &table.delta, |state| table.is_final(state));
+
+        let mut representatives = vec![usize::MAX; count];
+
+        for state in states.iter().rev() {
+            representatives[classes[*state]] = *state;
+        }
+
+        let delta = representatives.iter()
+            .map(|state| table.delta[*state].iter().map(|next| classes[*next]).collect())
+            .collect::<Vec<_>>();
+
+        let states = representatives.iter()
+            .map(|state| (table.states[*state].to_vec(), table.is_final(*state)))
+            .collect();
+
+        Self::from_table(self.alphabet().clone(), states, classes[table.initial], &delta).with_intervals_of(self)
+    }
+}
+
+// Moore's partition refinement, starting from the partition given by `class`
+// and refining until stable, classes are numbered in order of first appearance
+// in `states`, returns the class of each state and the number of classes
+pub fn partition<C: Eq + Hash>(
+    states: &[usize], delta: &[Vec<usize>], class: impl Fn(usize) -> C,
+) -> (Vec<usize>, usize) {
+    let mut classes = vec![usize::MAX; delta.len()];
+    let mut count = number(states, &mut classes, class);
+
+    loop {
+        let previous = classes.clone();
+
+        let refined = number(states, &mut classes, |state| (
+            previous[state],
+            delta[state].iter().map(|next| previous[*next]).collect::<Vec<_>>(),
+        ));
+
+        if refined == count {
+            return (classes, count);
+        }
+
+        count = refined;
+    }
+}
+
+fn number<K: Eq + Hash>(states: &[usize], classes: &mut [usize], key: impl Fn(usize) -> K) -> usize {
+    let mut numbers = HashMap::new();
+
+    for state in states {
+        let next = numbers.len();
+
+        classes[*state] = *numbers.entry(key(*state)).or_insert(next);
+    }
+
+    numbers.len()
+}
@@ -1,17 +1,56 @@
-//!
+//! Finite automata, deterministic and nondeterministic, transducers, pushdown automata, Büchi automata,
+//! weighted, probabilistic and symbolic automata, and the algorithms operating on them
 
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
+pub use aho_corasick::{AhoCorasick, Occurrence};
+pub use algebra::{BooleanAlgebra, Discrete, Ranges};
+pub use buchi::Buchi;
+pub use count::GeneratingFunction;
+pub use dawg::Dawg;
 pub use dfa::DFA;
+pub use explain::Explanation;
+pub use levenshtein::Levenshtein;
+pub use mealy::Mealy;
+pub use moore::Moore;
 pub use nfa::NFA;
+pub use pda::{Acceptance, Configuration, DPDA, Move, PDA};
+pub use probabilistic::ProbabilisticAutomaton;
+pub use semiring::{Boolean, Counting, Probability, Semiring, Tropical};
+pub use suffix::SuffixAutomaton;
+pub use symbolic::SymbolicAutomaton;
+pub use trace::{Active, NfaTrace, Trace, Transition};
+pub use weighted::WeightedAutomaton;
 
 use crate::model::{F, δ};
 use crate::model::state::{Phase, Q, State};
 use crate::UNREACHABLE_ERR;
 
+pub(crate) mod aho_corasick;
+pub(crate) mod algebra;
+pub(crate) mod buchi;
+pub(crate) mod count;
+pub(crate) mod dawg;
+pub(crate) mod determinize;
 pub(crate) mod dfa;
+pub(crate) mod equivalence;
+pub(crate) mod explain;
+pub(crate) mod levenshtein;
+pub(crate) mod mealy;
+pub(crate) mod minimize;
+pub(crate) mod moore;
 pub(crate) mod nfa;
+pub(crate) mod pda;
+pub(crate) mod probabilistic;
+pub(crate) mod sample;
+pub(crate) mod semiring;
+pub(crate) mod suffix;
+pub(crate) mod symbolic;
+pub(crate) mod table;
+pub(crate) mod trace;
+pub(crate) mod weighted;
+pub(crate) mod words;
 
 pub(crate) const ERR_DANGLING_STATE: &str = "Transition functions has a dangling state";
 pub(crate) const ERR_INVALID_INPUT: &str = "Undefined Input Symbol";
@@ -20,11 +59,13 @@ pub(crate) const ERR_UNDEFINED_INITIAL_STATE: &str = "Initial state q0 is not de
 pub(crate) const ERR_UNREFERENCED_STATE_Q: &str = "Q contains a state that does not have a transition function defined";
 
 type Transitions<A, S> = HashMap<State<S>, HashMap<A, Vec<S>>>;
+type Defaults<S> = HashMap<State<S>, Vec<S>>;
+type StateTransitions<A, S> = (Vec<State<S>>, Transitions<A, S>);
 
 #[allow(non_snake_case)]
 fn convert_to_transitions<A: Eq, S: Eq + Hash>(
     Q: Q<S>, q0: S, F: &F<S>, δ: &δ<A, S>,
-) -> Result<Transitions<A, S>, &'static str> {
+) -> Result<StateTransitions<A, S>, &'static str> {
     let states: Vec<_> = Q.into();
     let δ = δ.states_transitioned().collect::<HashSet<_>>();
     let q0 = vec![q0];
@@ -36,18 +77,30 @@ fn convert_to_transitions<A: Eq, S: Eq + Hash>(
             let initial = tags == q0;
             let r#final = F.contains(&tags);
 
-            let phase = match (initial, r#final) {
-                (false, false) => Phase::Interim,
-                (true, false) => Phase::Initial,
-                (false, true) => Phase::Final,
-                (true, true) => Phase::Both,
-            };
-
-            Ok((State::new(tags, phase)?, <HashMap<A, Vec<S>>>::new()))
+            State::new(tags, Phase::of(initial, r#final))
         })
-        .collect::<Result<HashMap<_, _>, _>>()?;
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let transitions = states.iter()
+        .map(|state| (state.clone(), <HashMap<A, Vec<S>>>::new()))
+        .collect();
 
-    Ok(states)
+    Ok((states, transitions))
+}
+
+// default transitions of the states that have one, taken on the symbols of Σ
+// they define no input transition for
+fn convert_to_defaults<A, S: Eq + Hash>(
+    transitions: &Transitions<A, S>, defaults: Vec<(S, S)>,
+) -> Result<Defaults<S>, &'static str> {
+    defaults.into_iter()
+        .map(|(state, next)| {
+            // states without transitions to them are not in the transitions table
+            let (state, _) = transitions.get_key_value(&vec![state]).ok_or(ERR_DANGLING_STATE)?;
+
+            Ok((state.clone(), vec![next]))
+        })
+        .collect()
 }
 
 fn get_initial_state<A, S: Eq>(transitions: &Transitions<A, S>) -> State<S> {
@@ -68,4 +121,3 @@ fn validate_fa_configuration<A, S: Eq + Hash>(
         Ok(())
     }
 }
-
@@ -0,0 +1,257 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+
+use crate::automata::{ERR_DANGLING_STATE, ERR_INVALID_INPUT, ERR_UNDEFINED_INITIAL_STATE, ERR_UNREFERENCED_STATE_Q, Mealy};
+use crate::automata::dfa::{ERR_INCOMPLETE_INPUT_TRANSITIONS, ERR_UNDEFINED_SYMBOL};
+use crate::automata::mealy::ERR_UNDEFINED_STATE;
+use crate::model::{Phase, Q, State, δ, Σ};
+use crate::utils::duped::Duped;
+use crate::UNREACHABLE_ERR;
+
+pub const ERR_DUPLICATE_STATE_OUTPUT: &str = "Each state must define a single output";
+pub const ERR_MISSING_STATE_OUTPUT: &str = "Q contains a state that does not define an output";
+pub const ERR_UNDEFINED_OUTPUT_STATE: &str = "Output state is not defined in Q";
+
+/// Moore machine, a transducer emitting the output of each state it enters, (Q, Σ, Γ, δ, λ, q0)
+#[allow(non_snake_case)]
+pub struct Moore<I, O, S: Hash> {
+    Σ: Σ<I>,
+    states: Vec<State<S>>,
+    index: HashMap<State<S>, usize>,
+    delta: Vec<Vec<usize>>,
+    outputs: Vec<O>,
+    current: usize,
+}
+
+impl<I: Clone + Eq + Hash, O, S: Eq + Hash> Moore<I, O, S> {
+    /// # Errors
+    #[allow(non_snake_case)]
+    #[allow(clippy::missing_panics_doc)] // states and symbols are validated before indexing
+    pub fn new(Q: Q<S>, Σ: &Σ<I>, δ: δ<I, S>, q0: S, λ: Vec<(S, O)>) -> Result<Self, &'static str> {
+        if Q.iter().any(|q| δ.iter().all(|(state, _)| state != q)) {
+            return Err(ERR_UNREFERENCED_STATE_Q);
+        } else if δ.iter().any(|(state, _)| !Q.contains(state)) {
+            return Err(ERR_UNDEFINED_STATE);
+        } else if !Q.contains(&q0) {
+            return Err(ERR_UNDEFINED_INITIAL_STATE);
+        } else if λ.iter().map(|(state, _)| state).has_dupes() {
+            return Err(ERR_DUPLICATE_STATE_OUTPUT);
+        } else if Q.iter().any(|q| λ.iter().all(|(state, _)| state != q)) {
+            return Err(ERR_MISSING_STATE_OUTPUT);
+        } else if λ.iter().any(|(state, _)| !Q.contains(state)) {
+            return Err(ERR_UNDEFINED_OUTPUT_STATE);
+        }
+
+        let states = Vec::from(Q).into_iter()
+            .map(|tag| {
+                let phase = Phase::of(tag == q0, false);
+
+                State::new(vec![tag], phase)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let index = states.iter().enumerate().map(|(idx, state)| (state.clone(), idx)).collect::<HashMap<_, _>>();
+        let mut delta = vec![Vec::new(); states.len()];
+
+        let (δ, defaults) = δ.into_parts();
+
+        let defaults = defaults.into_iter()
+            .map(|(state, next)| (index[&vec![state]], index[&vec![next]]))
+            .collect::<HashMap<_, _>>();
+
+        for (state, transitions) in δ {
+            let state = index[&vec![state]];
+            let default = defaults.get(&state);
+
+            if default.is_none() && Σ.iter().any(|sym| transitions.iter().all(|(input, _)| input != sym)) {
+                return Err(ERR_INCOMPLETE_INPUT_TRANSITIONS);
+            } else if transitions.iter().any(|(sym, _)| !Σ.contains(sym)) {
+                return Err(ERR_UNDEFINED_SYMBOL);
+            }
+
+            let transitions = transitions.into_iter()
+                .map(|(sym, next)| (sym, index[&vec![next]]))
+                .collect::<HashMap<_, _>>();
+
+            delta[state] = Σ.iter()
+                .map(|sym| *transitions.get(sym).or(default).expect(UNREACHABLE_ERR))
+                .collect();
+        }
+
+        let mut outputs = λ.into_iter().map(|(state, output)| (index[&vec![state]], output)).collect::<Vec<_>>();
+
+        outputs.sort_by_key(|(state, _)| *state);
+
+        let initial = index[&vec![q0]];
+
+        if (0..states.len()).any(|state|
+            state != initial && delta.iter().enumerate().all(|(from, transitions)|
+                from == state || !transitions.contains(&state)
+            )
+        ) {
+            return Err(ERR_DANGLING_STATE);
+        }
+
+        Ok(Self {
+            Σ: Σ.clone(),
+            states,
+            index,
+            delta,
+            outputs: outputs.into_iter().map(|(_, output)| output).collect(),
+            current: initial,
+        })
+    }
+
+    /// Alphabet Σ of this machine, in declared order
+    #[must_use]
+    pub const fn alphabet(&self) -> &Σ<I> {
+        &self.Σ
+    }
+
+    /// Current state of this machine
+    #[must_use]
+    pub fn current(&self) -> &State<S> {
+        &self.states[self.current]
+    }
+
+    /// Initial state q0 of this machine
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)] // validated on construction
+    pub fn initial(&self) -> &State<S> {
+        self.states.iter().find(|state| state.is_initial()).expect(UNREACHABLE_ERR)
+    }
+
+    /// Output of the current state
+    #[must_use]
+    pub fn output(&self) -> &O {
+        &self.outputs[self.current]
+    }
+
+    /// Output λ(state), `None` if the state is not defined in this machine
+    #[must_use]
+    pub fn output_of(&self, state: &State<S>) -> Option<&O> {
+        self.index.get(state).map(|state| &self.outputs[*state])
+    }
+
+    /// Sets the current state back to the initial state q0
+    #[allow(clippy::missing_panics_doc)] // validated on construction
+    pub fn reset(&mut self) {
+        self.current = self.states.iter().position(State::is_initial).expect(UNREACHABLE_ERR);
+    }
+
+    /// States Q of this machine, in declared order
+    pub fn states(&self) -> impl Iterator<Item=&State<S>> {
+        self.states.iter()
+    }
+
+    /// Transitions on the input, returning the output of the state entered
+    ///
+    /// # Errors
+    /// an input symbol that is not defined in Σ
+    pub fn step(&mut self, input: &I) -> Result<&O, &'static str> {
+        let sym = self.Σ.iter().position(|sym| sym == input).ok_or(ERR_INVALID_INPUT)?;
+
+        self.current = self.delta[self.current][sym];
+
+        Ok(&self.outputs[self.current])
+    }
+
+    /// Transition δ(state, input), `None` if either is not defined in this machine
+    #[must_use]
+    pub fn transition(&self, state: &State<S>, input: &I) -> Option<&State<S>> {
+        let sym = self.Σ.iter().position(|sym| sym == input)?;
+
+        Some(&self.states[self.delta[*self.index.get(state)?][sym]])
+    }
+}
+
+impl<I: Clone + Eq + Hash, O: Clone, S: Eq + Hash> Moore<I, O, S> {
+    /// Steps through the inputs, returning the output of each state entered,
+    /// the output of the state before the first input is given by [`Moore::output`]
+    ///
+    /// # Errors
+    /// an input symbol that is not defined in Σ
+    pub fn transduce(&mut self, inputs: &[I]) -> Result<Vec<O>, &'static str> {
+        inputs.iter().map(|input| self.step(input).cloned()).collect()
+    }
+}
+
+impl<I: Clone + Eq + Hash, O: Clone + Eq + Hash, S: Clone + Eq + Hash> Moore<I, O, S> {
+    /// Equivalent [`Mealy`] machine, each transition emits the output of the
+    /// state it enters, states not reachable from the initial state are dropped
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)] // validated on construction
+    pub fn to_mealy(&self) -> Mealy<I, O, S> {
+        let initial = self.states.iter().position(State::is_initial).expect(UNREACHABLE_ERR);
+
+        let delta = self.delta.iter()
+            .map(|transitions| transitions.iter().map(|next| (*next, self.outputs[*next].clone())).collect())
+            .collect();
+
+        Mealy::from_table(self.Σ.clone(), self.states.iter().map(|state| state.to_vec()).collect(), initial, delta)
+    }
+}
+
+impl<I: Clone + Eq + Hash, O: Clone + Eq + Hash, S: Clone + Eq + Hash> Mealy<I, O, S> {
+    /// Equivalent [`Moore`] machine, each state is split by the outputs emitted
+    /// on the transitions entering it, giving states tagged (state, output)
+    ///
+    /// The initial state is tagged with the given output, which is the output of
+    /// [`Moore::output`] before any input and never emitted by [`Moore::transduce`]
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)] // transitions are defined for all states and symbols
+    pub fn to_moore(&self, initial: O) -> Moore<I, O, (S, O)> {
+        let symbols = self.alphabet().iter().cloned().collect::<Vec<_>>();
+        let mut index = HashMap::from([((self.initial(), initial.clone()), 0)]);
+        let mut pairs = vec![(self.initial(), initial)];
+        let mut delta = Vec::new();
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(pair) = queue.pop_front() {
+            let state = pairs[pair].0;
+
+            delta.push(symbols.iter()
+                .map(|sym| {
+                    let (next, output) = self.transition(state, sym).expect(UNREACHABLE_ERR);
+
+                    *index.entry((next, output.clone())).or_insert_with(|| {
+                        pairs.push((next, output.clone()));
+                        queue.push_back(pairs.len() - 1);
+
+                        pairs.len() - 1
+                    })
+                })
+                .collect::<Vec<_>>());
+        }
+
+        let states = pairs.iter()
+            .enumerate()
+            .map(|(idx, (state, output))| State::from_tags(
+                state.iter().map(|tag| (tag.clone(), output.clone())).collect(),
+                Phase::of(idx == 0, false),
+            ))
+            .collect::<Vec<_>>();
+
+        Moore {
+            Σ: self.alphabet().clone(),
+            index: states.iter().enumerate().map(|(idx, state)| (state.clone(), idx)).collect(),
+            states,
+            delta,
+            outputs: pairs.into_iter().map(|(_, output)| output).collect(),
+            current: 0,
+        }
+    }
+}
+
+impl<I: Debug, O: Debug, S: Debug + Hash> Debug for Moore<I, O, S> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Moore")
+            .field("Σ", &self.Σ)
+            .field("states", &self.states)
+            .field("δ", &self.delta)
+            .field("λ", &self.outputs)
+            .field("current", &self.states[self.current])
+            .finish_non_exhaustive()
+    }
+}
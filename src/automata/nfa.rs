@@ -3,11 +3,12 @@ use std::fmt::{self, Debug, Formatter};
 use std::hash::Hash;
 
 use crate::automata::{
-    convert_to_transitions, ERR_DANGLING_STATE, ERR_INVALID_INPUT,
-    get_initial_state, Transitions, validate_fa_configuration,
+    convert_to_defaults, convert_to_transitions, Defaults, ERR_DANGLING_STATE,
+    ERR_INVALID_INPUT, get_initial_state, Transitions, validate_fa_configuration,
 };
+use crate::automata::table::NfaTable;
 use crate::model::{F, δ, Σ};
-use crate::model::state::{Q, State};
+use crate::model::state::{Phase, Q, State};
 use crate::UNREACHABLE_ERR;
 
 pub const ERR_UNDEFINED_SYMBOL: &str = "Symbol is not defined in input transitions";
@@ -15,72 +16,89 @@ pub const ERR_UNDEFINED_TRANSITION_STATE: &str = "State transition not in States
 
 const EXPECTED_TRANSITION_DEFINED: &str = "NFA expects all transition states defined in transitions table";
 
-///
+/// Nondeterministic finite automaton, (Q, Σ, δ, q0, F)
 #[allow(clippy::upper_case_acronyms)]
 #[allow(non_snake_case)]
 pub struct NFA<A, S: Hash> {
     Σ: Σ<A>,
+    states: Vec<State<S>>,
     current: Vec<State<S>>,
     transitions: Transitions<A, S>,
+    defaults: Defaults<S>,
 }
 
-impl<A: Eq + Hash, S: Copy + Eq + Hash> NFA<A, S> {
+impl<A: Eq + Hash, S: Clone + Eq + Hash> NFA<A, S> {
     /// # Errors
     #[allow(non_snake_case)]
     pub fn new(Q: Q<S>, Σ: Σ<A>, δ: δ<A, S>, q0: S, F: &F<S>) -> Result<Self, &'static str> {
         validate_fa_configuration(&Q, &δ, &q0, F)?;
 
-        let mut transitions = convert_to_transitions(Q, q0, F, &δ)?;
+        let (states, mut transitions) = convert_to_transitions(Q, q0, F, &δ)?;
 
-        Self::add_input_transitions(&mut transitions, &Σ, δ)?;
+        let defaults = Self::add_input_transitions(&mut transitions, &Σ, δ)?;
 
-        Self::validate_nfa(&transitions)?;
+        Self::validate_nfa(&transitions, &defaults)?;
 
         let nfa = Self {
             Σ,
+            states,
             current: vec![get_initial_state(&transitions)],
             transitions,
+            defaults,
         };
 
         Ok(nfa)
     }
 
-    ///
+    /// Alphabet Σ of this automaton, in declared order
+    #[must_use]
+    pub const fn alphabet(&self) -> &Σ<A> {
+        &self.Σ
+    }
+
+    /// Current set of active states of this automaton
+    #[must_use]
+    pub const fn current(&self) -> &Vec<State<S>> {
+        &self.current
+    }
+
+    /// Initial state q0 of this automaton
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)] // validated on construction
+    pub fn initial(&self) -> &State<S> {
+        self.states.iter().find(|state| state.is_initial()).expect(UNREACHABLE_ERR)
+    }
+
+    /// Does any of the current states belong to the final states F
     #[must_use]
     pub fn matches(&self) -> bool {
         self.current.iter().any(State::is_final)
     }
 
-    ///
+    /// Sets the current states back to the initial state q0
     pub fn reset(&mut self) {
         self.current = vec![get_initial_state(&self.transitions)];
     }
 
+    /// States Q of this automaton, in declared order
+    pub fn states(&self) -> impl Iterator<Item=&State<S>> {
+        self.states.iter()
+    }
+
     /// # Errors
+    #[allow(clippy::missing_panics_doc)] // validated on construction
     pub fn step(&mut self, input: &A) -> Result<&Vec<State<S>>, &'static str> {
         if !self.Σ.contains(input) {
             return Err(ERR_INVALID_INPUT);
         }
 
-        let transitions = self.current.iter().map(
-            |current| self.transitions.get(current).expect(EXPECTED_TRANSITION_DEFINED)
-        );
-
-        let current = transitions
-            .filter_map(|transitions| transitions.get(input))
+        let current = self.current.iter()
+            .filter_map(|current| self.next(current, input))
             .filter(|next| next.iter().any(|tag| self.transitions.keys().any(|key| key.contains(tag))))
-            .fold(
-                Ok(Vec::new()),
-                |mut acc, next| {
-                    if let Ok(ref mut tags) = acc {
-                        for tag in next {
-                            tags.push(self.transitions.get_key_value(&vec![*tag]).expect(UNREACHABLE_ERR).0.clone());
-                        }
-                    }
-
-                    acc
-                },
-            )?;
+            .flat_map(|next| next.iter().map(
+                |tag| self.transitions.get_key_value(&vec![tag.clone()]).expect(UNREACHABLE_ERR).0.clone()
+            ))
+            .collect();
 
         self.current = current;
 
@@ -96,53 +114,73 @@ impl<A: Eq + Hash, S: Copy + Eq + Hash> NFA<A, S> {
         Ok(&self.current)
     }
 
+    /// Transitions δ(state, input), the default transition of the state if it
+    /// defines none for the input, empty if either is not defined in this automaton
+    #[allow(clippy::missing_panics_doc)] // validated on construction
+    pub fn transitions<'a>(&'a self, state: &State<S>, input: &A) -> impl Iterator<Item=&'a State<S>> {
+        self.transitions.get(state)
+            .and_then(|_| self.next(state, input).filter(|_| self.Σ.contains(input)))
+            .into_iter()
+            .flatten()
+            .map(|tag| self.transitions.get_key_value(&vec![tag.clone()]).expect(UNREACHABLE_ERR).0)
+    }
+
+    pub(crate) fn table(&self) -> NfaTable<'_, A, S> {
+        NfaTable::new(&self.Σ, &self.states, &self.transitions, &self.defaults)
+    }
+
+    // targets of the state on the input, those of its default transition if it
+    // defines none for the input
+    #[allow(clippy::missing_panics_doc)] // validated on construction
+    fn next(&self, state: &State<S>, input: &A) -> Option<&Vec<S>> {
+        self.transitions.get(state)
+            .expect(EXPECTED_TRANSITION_DEFINED)
+            .get(input)
+            .or_else(|| self.defaults.get(state))
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn add_input_transitions(
         state_transitions: &mut Transitions<A, S>, Σ: &Σ<A>, δ: δ<A, S>,
-    ) -> Result<(), &'static str> {
+    ) -> Result<Defaults<S>, &'static str> {
+        let (δ, defaults) = δ.into_parts();
+        let defaults = convert_to_defaults(state_transitions, defaults)?;
+
         for (state, input_transitions) in δ {
             let state_key = vec![state];
+            let mut inputs = HashMap::new();
+
+            for (sym, state) in input_transitions {
+                if !Σ.contains(&sym) {
+                    return Err(ERR_UNDEFINED_SYMBOL);
+                }
+
+                let mut state = vec![state];
+
+                if !state_transitions.contains_key(&state) {
+                    return Err(ERR_UNDEFINED_TRANSITION_STATE);
+                }
+
+                let state = state.pop().expect(UNREACHABLE_ERR);
+                let entry = inputs.entry(sym).or_insert_with(Vec::new);
+
+                if !entry.contains(&state) {
+                    entry.push(state);
+                }
+            }
 
-            let inputs = input_transitions.into_iter()
-                .fold(
-                    Ok(HashMap::new()),
-                    |acc, (sym, state)|
-                        match acc {
-                            Ok(mut acc) => {
-                                if Σ.contains(&sym) {
-                                    let mut state = vec![state];
-
-                                    if state_transitions.contains_key(&state) {
-                                        let state = state.pop().expect(UNREACHABLE_ERR);
-                                        let entry = acc.entry(sym).or_insert_with(Vec::new);
-
-                                        if !entry.contains(&state) {
-                                            entry.push(state);
-                                        }
-
-                                        Ok(acc)
-                                    } else {
-                                        Err(ERR_UNDEFINED_TRANSITION_STATE)
-                                    }
-                                } else {
-                                    Err(ERR_UNDEFINED_SYMBOL)
-                                }
-                            }
-                            err @ Err(_) => err
-                        },
-                );
-
-            let entry = state_transitions.get_mut(&state_key).expect(UNREACHABLE_ERR);
-
-            entry.extend(inputs?);
+            // states without transitions to them are not in the transitions table
+            let entry = state_transitions.get_mut(&state_key).ok_or(ERR_DANGLING_STATE)?;
+
+            entry.extend(inputs);
         }
 
-        Ok(())
+        Ok(defaults)
     }
 
     #[inline]
-    fn validate_nfa(state_transitions: &Transitions<A, S>) -> Result<(), &'static str> {
+    fn validate_nfa(state_transitions: &Transitions<A, S>, defaults: &Defaults<S>) -> Result<(), &'static str> {
         let transition_states = |transition_state| state_transitions.iter()
             .filter_map(
                 move |(state, input_transitions)|
@@ -158,7 +196,8 @@ impl<A: Eq + Hash, S: Copy + Eq + Hash> NFA<A, S> {
         if state_transitions.keys().any(|state|
             !state.is_initial() &&
                 transition_states(state)
-                    .all(|input_transition| state.as_ref().iter().all(|s| s != input_transition))
+                    .all(|input_transition| state.as_ref().iter().all(|s| s != input_transition)) &&
+                defaults.iter().all(|(from, next)| from == state || next != state.as_ref())
         ) {
             Err(ERR_DANGLING_STATE)
         } else {
@@ -167,11 +206,48 @@ impl<A: Eq + Hash, S: Copy + Eq + Hash> NFA<A, S> {
     }
 }
 
+impl<A: Clone + Eq + Hash, S: Clone + Eq + Hash> NFA<A, S> {
+    // builds an nfa from an index based table, δ[state][symbol] -> {states}, of
+    // unique states
+    #[allow(non_snake_case)]
+    pub(crate) fn from_table(
+        Σ: Σ<A>, states: Vec<(S, bool)>, initial: usize, delta: &[Vec<Vec<usize>>],
+    ) -> Self {
+        let states = states.into_iter()
+            .enumerate()
+            .map(|(idx, (tag, r#final))| State::from_tags(vec![tag], Phase::of(idx == initial, r#final)))
+            .collect::<Vec<_>>();
+
+        let transitions = states.iter()
+            .zip(delta)
+            .map(|(state, transitions)| {
+                let inputs = Σ.iter()
+                    .zip(transitions)
+                    .filter(|(_, next)| !next.is_empty())
+                    .map(|(sym, next)| (sym.clone(), next.iter().map(|next| states[*next][0].clone()).collect()))
+                    .collect();
+
+                (state.clone(), inputs)
+            })
+            .collect();
+
+        Self {
+            Σ,
+            current: vec![states[initial].clone()],
+            states,
+            transitions,
+            defaults: HashMap::new(),
+        }
+    }
+}
+
 impl<A: Debug, S: Debug + Hash> Debug for NFA<A, S> {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
         fmt.debug_struct("NFA")
+            .field("Σ", &self.Σ)
             .field("δ", &self.transitions)
+            .field("defaults", &self.defaults)
             .field("current", &self.current)
-            .finish()
+            .finish_non_exhaustive()
     }
 }
@@ -0,0 +1,410 @@
+//! Pushdown automata, finite automata with a stack
+//!
+//! A [`Move`] reads an input symbol, or nothing, an ε move, pops the top of the
+//! stack, or leaves it, and pushes a string of stack symbols, written top first,
+//! so pushing `[X, Z]` leaves `X` on top of `Z`
+
+use std::collections::HashSet;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::Hash;
+
+use crate::automata::{ERR_INVALID_INPUT, ERR_UNDEFINED_FINAL_STATE, ERR_UNDEFINED_INITIAL_STATE};
+use crate::automata::dfa::ERR_UNDEFINED_SYMBOL;
+use crate::model::{F, Phase, Q, State, Σ};
+use crate::utils::duped::Duped;
+use crate::UNREACHABLE_ERR;
+
+pub const ERR_CONFIGURATION_LIMIT: &str = "Configurations grow beyond the configuration limit, ε moves may branch without bound";
+pub const ERR_DUPLICATE_MOVE: &str = "Moves must be a unique collection of moves";
+pub const ERR_NONDETERMINISTIC_MOVES: &str = "More than one move applies to the same state, input and stack top";
+pub const ERR_STACK_LIMIT: &str = "Stack grows beyond the stack limit, ε moves may push without bound";
+pub const ERR_UNDEFINED_INITIAL_STACK_SYMBOL: &str = "Initial stack symbol Z0 is not defined in Γ";
+pub const ERR_UNDEFINED_MOVE_STATE: &str = "Move state is not defined in Q";
+pub const ERR_UNDEFINED_STACK_SYMBOL: &str = "Move stack symbol is not defined in Γ";
+
+/// Maximum depth of the stack of any configuration, bounding ε moves which push without popping
+pub const STACK_LIMIT: usize = 1 << 10;
+
+/// Maximum number of configurations after any step, bounding ε moves which branch while pushing
+pub const CONFIGURATION_LIMIT: usize = 1 << 16;
+
+/// How a pushdown automaton accepts its input
+pub enum Acceptance<S: Hash> {
+    /// Input is accepted when a configuration is in a final state
+    FinalState(F<S>),
+
+    /// Input is accepted when a configuration has an empty stack
+    EmptyStack,
+}
+
+/// Move of a pushdown automaton, δ(from, input, pop) ∋ (to, push)
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Move<A, S, G> {
+    /// State the move is taken from
+    pub from: S,
+
+    /// Input symbol read, `None` for an ε move
+    pub input: Option<A>,
+
+    /// Symbol popped off the top of the stack, `None` to leave the stack as is
+    pub pop: Option<G>,
+
+    /// State the move leads to
+    pub to: S,
+
+    /// Symbols pushed onto the stack, top first
+    pub push: Vec<G>,
+}
+
+impl<A, S, G> Move<A, S, G> {
+    /// Move δ(from, input, pop) ∋ (to, push)
+    #[must_use]
+    pub const fn new(from: S, input: Option<A>, pop: Option<G>, to: S, push: Vec<G>) -> Self {
+        Self { from, input, pop, to, push }
+    }
+}
+
+/// Configuration of a pushdown automaton, its state and stack
+#[derive(Debug)]
+pub struct Configuration<'a, S, G> {
+    /// State of the configuration
+    pub state: &'a State<S>,
+
+    /// Stack of the configuration, bottom first, its top being the last symbol
+    pub stack: &'a [G],
+}
+
+impl<S: Display, G: Display> Display for Configuration<'_, S, G> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{} [", self.state)?;
+
+        for (idx, sym) in self.stack.iter().rev().enumerate() {
+            write!(fmt, "{}{sym}", if idx == 0 { "" } else { " " })?;
+        }
+
+        fmt.write_str("]")
+    }
+}
+
+// move with its states as indices and its push bottom first
+struct Rule<A, G> {
+    from: usize,
+    input: Option<A>,
+    pop: Option<G>,
+    to: usize,
+    push: Vec<G>,
+}
+
+/// Nondeterministic pushdown automaton, (Q, Σ, Γ, δ, q0, Z0, F)
+#[allow(non_snake_case)]
+pub struct PDA<A, S, G> {
+    Σ: Σ<A>,
+    Γ: Σ<G>,
+    states: Vec<State<S>>,
+    rules: Vec<Rule<A, G>>,
+    empty_stack: bool,
+    start: Vec<(usize, Vec<G>)>,
+    current: Vec<(usize, Vec<G>)>,
+}
+
+impl<A: Eq, S: Eq + Hash, G: Clone + Eq + Hash> PDA<A, S, G> {
+    /// # Errors
+    #[allow(non_snake_case)]
+    #[allow(clippy::missing_panics_doc)] // states are validated before indexing
+    pub fn new(
+        Q: Q<S>, Σ: Σ<A>, Γ: Σ<G>, δ: Vec<Move<A, S, G>>, q0: S, Z0: G, acceptance: Acceptance<S>,
+    ) -> Result<Self, &'static str> {
+        if δ.iter().has_dupes() {
+            return Err(ERR_DUPLICATE_MOVE);
+        } else if δ.iter().any(|rule| !Q.contains(&rule.from) || !Q.contains(&rule.to)) {
+            return Err(ERR_UNDEFINED_MOVE_STATE);
+        } else if δ.iter().any(|rule| rule.input.as_ref().is_some_and(|sym| !Σ.contains(sym))) {
+            return Err(ERR_UNDEFINED_SYMBOL);
+        } else if δ.iter().any(|rule| rule.pop.iter().chain(&rule.push).any(|sym| !Γ.contains(sym))) {
+            return Err(ERR_UNDEFINED_STACK_SYMBOL);
+        } else if !Q.contains(&q0) {
+            return Err(ERR_UNDEFINED_INITIAL_STATE);
+        } else if !Γ.contains(&Z0) {
+            return Err(ERR_UNDEFINED_INITIAL_STACK_SYMBOL);
+        }
+
+        let (F, empty_stack) = match acceptance {
+            Acceptance::FinalState(F) => (Some(F), false),
+            Acceptance::EmptyStack => (None, true),
+        };
+
+        if F.as_ref().is_some_and(|F| F.iter().any(|f| f.iter().any(|f| !Q.contains(f)))) {
+            return Err(ERR_UNDEFINED_FINAL_STATE);
+        }
+
+        let states = Vec::from(Q).into_iter()
+            .map(|tag| {
+                let tag = vec![tag];
+                let phase = Phase::of(tag[0] == q0, F.as_ref().is_some_and(|F| F.contains(&tag)));
+
+                State::new(tag, phase)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let index = |tag: S| states.iter().position(|state| state[0] == tag).expect(UNREACHABLE_ERR);
+
+        let rules = δ.into_iter()
+            .map(|Move { from, input, pop, to, mut push }| {
+                push.reverse();
+
+                Rule { from: index(from), input, pop, to: index(to), push }
+            })
+            .collect();
+
+        let initial = index(q0);
+
+        let mut pda = Self { Σ, Γ, states, rules, empty_stack, start: Vec::new(), current: Vec::new() };
+
+        pda.start = pda.closure(vec![(initial, vec![Z0])])?;
+        pda.current = pda.start.clone();
+
+        Ok(pda)
+    }
+
+    /// Input alphabet Σ of this automaton, in declared order
+    #[must_use]
+    pub const fn alphabet(&self) -> &Σ<A> {
+        &self.Σ
+    }
+
+    /// Configurations this automaton is currently in, in breadth first order of the moves taken
+    pub fn configurations(&self) -> impl Iterator<Item=Configuration<'_, S, G>> {
+        self.current.iter().map(|(state, stack)| Configuration { state: &self.states[*state], stack })
+    }
+
+    /// Initial state q0 of this automaton
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)] // validated on construction
+    pub fn initial(&self) -> &State<S> {
+        self.states.iter().find(|state| state.is_initial()).expect(UNREACHABLE_ERR)
+    }
+
+    /// Is the input read so far accepted, by a configuration in a final state or
+    /// with an empty stack, depending on the acceptance of this automaton
+    #[must_use]
+    pub fn matches(&self) -> bool {
+        self.current.iter().any(|(state, stack)|
+            if self.empty_stack { stack.is_empty() } else { self.states[*state].is_final() }
+        )
+    }
+
+    /// Sets the configurations back to the initial configurations, q0 with Z0 on the stack
+    pub fn reset(&mut self) {
+        self.current.clone_from(&self.start);
+    }
+
+    /// Stack alphabet Γ of this automaton, in declared order
+    #[must_use]
+    pub const fn stack_alphabet(&self) -> &Σ<G> {
+        &self.Γ
+    }
+
+    /// States Q of this automaton, in declared order
+    pub fn states(&self) -> impl Iterator<Item=&State<S>> {
+        self.states.iter()
+    }
+
+    /// Takes every move reading the input from the current configurations,
+    /// followed by any ε moves, returning the resulting configurations
+    ///
+    /// # Errors
+    /// an input symbol that is not defined in Σ, a stack growing beyond [`STACK_LIMIT`], or
+    /// configurations growing beyond [`CONFIGURATION_LIMIT`]
+    pub fn step(&mut self, input: &A) -> Result<Vec<Configuration<'_, S, G>>, &'static str> {
+        self.advance(input)?;
+
+        Ok(self.configurations().collect())
+    }
+
+    /// Steps through the inputs, returning the resulting configurations
+    ///
+    /// # Errors
+    /// an input symbol that is not defined in Σ, a stack growing beyond [`STACK_LIMIT`], or
+    /// configurations growing beyond [`CONFIGURATION_LIMIT`]
+    pub fn steps(&mut self, inputs: &[A]) -> Result<Vec<Configuration<'_, S, G>>, &'static str> {
+        for input in inputs {
+            self.advance(input)?;
+        }
+
+        Ok(self.configurations().collect())
+    }
+
+    fn advance(&mut self, input: &A) -> Result<(), &'static str> {
+        if !self.Σ.contains(input) {
+            return Err(ERR_INVALID_INPUT);
+        }
+
+        let mut seen = HashSet::new();
+
+        let next = self.current.iter()
+            .flat_map(|configuration| self.apply(configuration, Some(input)))
+            .filter(|configuration| seen.insert(configuration.clone()))
+            .collect();
+
+        self.current = self.closure(next)?;
+
+        Ok(())
+    }
+
+    // configurations reached by the moves reading the input, or ε, from a configuration
+    fn apply<'a>(
+        &'a self, (state, stack): &'a (usize, Vec<G>), input: Option<&'a A>,
+    ) -> impl Iterator<Item=(usize, Vec<G>)> + 'a {
+        self.rules.iter()
+            .filter(move |rule|
+                rule.from == *state
+                    && rule.input.as_ref() == input
+                    && rule.pop.as_ref().is_none_or(|pop| stack.last() == Some(pop))
+            )
+            .map(|rule| {
+                let mut stack = stack.clone();
+
+                if rule.pop.is_some() {
+                    stack.pop();
+                }
+
+                stack.extend(rule.push.iter().cloned());
+
+                (rule.to, stack)
+            })
+    }
+
+    // configurations extended with those reachable through ε moves
+    fn closure(&self, mut configurations: Vec<(usize, Vec<G>)>) -> Result<Vec<(usize, Vec<G>)>, &'static str> {
+        let mut seen = configurations.iter().cloned().collect::<HashSet<_>>();
+        let mut current = 0;
+
+        while current < configurations.len() {
+            let next = self.apply(&configurations[current], None).collect::<Vec<_>>();
+
+            for configuration in next {
+                if configuration.1.len() > STACK_LIMIT {
+                    return Err(ERR_STACK_LIMIT);
+                } else if seen.insert(configuration.clone()) {
+                    configurations.push(configuration);
+                }
+
+                if configurations.len() > CONFIGURATION_LIMIT {
+                    return Err(ERR_CONFIGURATION_LIMIT);
+                }
+            }
+
+            current += 1;
+        }
+
+        Ok(configurations)
+    }
+}
+
+impl<A: Debug, S: Debug, G: Debug> Debug for PDA<A, S, G> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("PDA")
+            .field("Σ", &self.Σ)
+            .field("Γ", &self.Γ)
+            .field("states", &self.states)
+            .field("current", &self.current.iter().map(|(state, stack)| (&self.states[*state], stack)).collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Deterministic pushdown automaton, a [`PDA`] with at most one applicable move
+///
+/// Moves must not overlap on any state, input symbol and stack top, ε moves
+/// overlap with every input symbol and moves leaving the stack with every stack top
+pub struct DPDA<A, S, G>(PDA<A, S, G>);
+
+impl<A: Eq, S: Eq + Hash, G: Clone + Eq + Hash> DPDA<A, S, G> {
+    /// # Errors
+    #[allow(non_snake_case)]
+    pub fn new(
+        Q: Q<S>, Σ: Σ<A>, Γ: Σ<G>, δ: Vec<Move<A, S, G>>, q0: S, Z0: G, acceptance: Acceptance<S>,
+    ) -> Result<Self, &'static str> {
+        let overlaps = |a: &Move<A, S, G>, b: &Move<A, S, G>|
+            a.from == b.from
+                && (a.input.is_none() || b.input.is_none() || a.input == b.input)
+                && (a.pop.is_none() || b.pop.is_none() || a.pop == b.pop);
+
+        if δ.iter().enumerate().any(|(idx, a)| δ.iter().skip(idx + 1).any(|b| overlaps(a, b))) {
+            return Err(ERR_NONDETERMINISTIC_MOVES);
+        }
+
+        PDA::new(Q, Σ, Γ, δ, q0, Z0, acceptance).map(Self)
+    }
+
+    /// Input alphabet Σ of this automaton, in declared order
+    #[must_use]
+    pub const fn alphabet(&self) -> &Σ<A> {
+        self.0.alphabet()
+    }
+
+    /// Configuration this automaton is currently in, `None` once no move applies to an input
+    #[must_use]
+    pub fn configuration(&self) -> Option<Configuration<'_, S, G>> {
+        self.0.configurations().last()
+    }
+
+    /// Initial state q0 of this automaton
+    #[must_use]
+    pub fn initial(&self) -> &State<S> {
+        self.0.initial()
+    }
+
+    /// Is the input read so far accepted, by a final state or an empty stack,
+    /// depending on the acceptance of this automaton
+    #[must_use]
+    pub fn matches(&self) -> bool {
+        self.0.matches()
+    }
+
+    /// Sets the configuration back to the initial configuration, q0 with Z0 on the stack
+    pub fn reset(&mut self) {
+        self.0.reset();
+    }
+
+    /// Stack alphabet Γ of this automaton, in declared order
+    #[must_use]
+    pub const fn stack_alphabet(&self) -> &Σ<G> {
+        self.0.stack_alphabet()
+    }
+
+    /// States Q of this automaton, in declared order
+    pub fn states(&self) -> impl Iterator<Item=&State<S>> {
+        self.0.states()
+    }
+
+    /// Takes the move reading the input, followed by any ε moves, returning the
+    /// resulting configuration
+    ///
+    /// # Errors
+    /// an input symbol that is not defined in Σ, a stack growing beyond [`STACK_LIMIT`], or
+    /// configurations growing beyond [`CONFIGURATION_LIMIT`]
+    pub fn step(&mut self, input: &A) -> Result<Option<Configuration<'_, S, G>>, &'static str> {
+        self.0.advance(input)?;
+
+        Ok(self.configuration())
+    }
+
+    /// Steps through the inputs, returning the resulting configuration
+    ///
+    /// # Errors
+    /// an input symbol that is not defined in Σ, a stack growing beyond [`STACK_LIMIT`], or
+    /// configurations growing beyond [`CONFIGURATION_LIMIT`]
+    pub fn steps(&mut self, inputs: &[A]) -> Result<Option<Configuration<'_, S, G>>, &'static str> {
+        for input in inputs {
+            self.0.advance(input)?;
+        }
+
+        Ok(self.configuration())
+    }
+}
+
+impl<A: Debug, S: Debug, G: Debug> Debug for DPDA<A, S, G> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple("DPDA").field(&self.0).finish()
+    }
+}
@@ -0,0 +1,264 @@
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+
+use crate::automata::{ERR_INVALID_INPUT, ERR_UNDEFINED_FINAL_STATE, ERR_UNDEFINED_INITIAL_STATE};
+use crate::automata::dfa::ERR_UNDEFINED_SYMBOL;
+use crate::automata::mealy::ERR_UNDEFINED_STATE;
+use crate::automata::weighted::{ERR_DUPLICATE_FINAL_WEIGHT, ERR_DUPLICATE_TRANSITION};
+use crate::model::{Phase, Q, State, Σ};
+use crate::random::Rng;
+use crate::UNREACHABLE_ERR;
+use crate::utils::duped::Duped;
+
+pub const ERR_INVALID_PROBABILITY: &str = "Probability must be a number between 0 and 1";
+pub const ERR_NOT_STOCHASTIC: &str = "Probabilities of the transitions of a state and of stopping in it must sum to 1";
+
+// path of states, with its probability
+type Path<'a, S> = (Vec<&'a State<S>>, f64);
+
+// allowed rounding error of the sum of the probabilities of a state
+const TOLERANCE: f64 = 1e-9;
+
+/// Probabilistic automaton, (Q, Σ, δ, q0, ρ), generating words from q0
+///
+/// Each step stops in the current state with probability ρ(state), or moves on a
+/// symbol to a state with probability δ(state, symbol, state). The probabilities
+/// of stopping in and of leaving each state sum to 1, so the automaton defines a
+/// distribution over the words it generates
+#[allow(non_snake_case)]
+pub struct ProbabilisticAutomaton<A, S> {
+    Σ: Σ<A>,
+    states: Vec<State<S>>,
+    delta: Vec<Vec<Vec<(usize, f64)>>>,
+    stop: Vec<f64>,
+    initial: usize,
+}
+
+impl<A: Eq, S: Eq + Hash> ProbabilisticAutomaton<A, S> {
+    /// # Errors
+    #[allow(non_snake_case)]
+    #[allow(clippy::missing_panics_doc)] // states and symbols are validated before indexing
+    pub fn new(Q: Q<S>, Σ: Σ<A>, δ: Vec<(S, A, S, f64)>, q0: S, ρ: Vec<(S, f64)>) -> Result<Self, &'static str> {
+        if δ.iter().any(|(state, _, next, _)| !Q.contains(state) || !Q.contains(next)) {
+            return Err(ERR_UNDEFINED_STATE);
+        } else if δ.iter().any(|(_, sym, _, _)| !Σ.contains(sym)) {
+            return Err(ERR_UNDEFINED_SYMBOL);
+        } else if δ.iter().enumerate().any(|(idx, (state, sym, next, _))|
+            δ[..idx].iter().any(|(other, other_sym, other_next, _)| other == state && other_sym == sym && other_next == next)
+        ) {
+            return Err(ERR_DUPLICATE_TRANSITION);
+        } else if !Q.contains(&q0) {
+            return Err(ERR_UNDEFINED_INITIAL_STATE);
+        } else if ρ.iter().any(|(state, _)| !Q.contains(state)) {
+            return Err(ERR_UNDEFINED_FINAL_STATE);
+        } else if ρ.iter().map(|(state, _)| state).has_dupes() {
+            return Err(ERR_DUPLICATE_FINAL_WEIGHT);
+        } else if δ.iter().map(|(_, _, _, probability)| probability).chain(ρ.iter().map(|(_, probability)| probability))
+            .any(|probability| !(0.0..=1.0).contains(probability)) {
+            return Err(ERR_INVALID_PROBABILITY);
+        }
+
+        let q0 = [q0];
+        let index = |tag: &S| Q.iter().position(|state| state == tag).expect(UNREACHABLE_ERR);
+        let initial = index(&q0[0]);
+        let mut delta = (0..Q.len()).map(|_| (0..Σ.len()).map(|_| Vec::new()).collect::<Vec<_>>()).collect::<Vec<_>>();
+        let mut stop = vec![0.0; Q.len()];
+
+        for (state, sym, next, probability) in δ {
+            let sym = Σ.iter().position(|symbol| *symbol == sym).expect(UNREACHABLE_ERR);
+
+            delta[index(&state)][sym].push((index(&next), probability));
+        }
+
+        for (state, probability) in ρ {
+            stop[index(&state)] = probability;
+        }
+
+        if delta.iter().zip(&stop).any(|(transitions, stop)| {
+            let total = transitions.iter().flatten().map(|(_, probability)| probability).sum::<f64>() + stop;
+
+            (total - 1.0).abs() > TOLERANCE
+        }) {
+            return Err(ERR_NOT_STOCHASTIC);
+        }
+
+        let states = Vec::from(Q).into_iter()
+            .enumerate()
+            .map(|(idx, tag)| State::new(vec![tag], Phase::of(idx == initial, stop[idx] > 0.0)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { Σ, states, delta, stop, initial })
+    }
+
+    /// Alphabet Σ of this automaton, in declared order
+    #[must_use]
+    pub const fn alphabet(&self) -> &Σ<A> {
+        &self.Σ
+    }
+
+    /// Initial state q0 of this automaton
+    #[must_use]
+    pub fn initial(&self) -> &State<S> {
+        &self.states[self.initial]
+    }
+
+    /// States Q of this automaton, in declared order, final when they have a
+    /// probability of stopping
+    pub fn states(&self) -> impl Iterator<Item=&State<S>> {
+        self.states.iter()
+    }
+
+    /// Probability of generating exactly the input, the sum over its paths of the
+    /// product of the probabilities of their transitions and of stopping at their end
+    ///
+    /// # Errors
+    /// an input symbol that is not defined in Σ
+    pub fn likelihood(&self, inputs: &[A]) -> Result<f64, &'static str> {
+        self.log_likelihood(inputs).map(f64::exp)
+    }
+
+    /// Natural logarithm of the [`likelihood`](Self::likelihood) of the input,
+    /// negative infinity if it cannot be generated, computed with the forward
+    /// algorithm rescaling the probabilities of the states at each position so
+    /// long inputs do not underflow
+    ///
+    /// # Errors
+    /// an input symbol that is not defined in Σ
+    pub fn log_likelihood(&self, inputs: &[A]) -> Result<f64, &'static str> {
+        let mut forward = vec![0.0; self.states.len()];
+        let mut log_scale = 0.0;
+
+        forward[self.initial] = 1.0;
+
+        for input in inputs {
+            let sym = self.symbol(input)?;
+            let mut next = vec![0.0; self.states.len()];
+
+            for (state, probability) in forward.iter().enumerate().filter(|(_, probability)| **probability > 0.0) {
+                for (to, transition) in &self.delta[state][sym] {
+                    next[*to] += probability * transition;
+                }
+            }
+
+            let scale = next.iter().sum::<f64>();
+
+            if scale == 0.0 {
+                return Ok(f64::NEG_INFINITY);
+            }
+
+            forward = next.into_iter().map(|probability| probability / scale).collect();
+            log_scale += scale.ln();
+        }
+
+        Ok(log_scale + forward.iter().zip(&self.stop).map(|(probability, stop)| probability * stop).sum::<f64>().ln())
+    }
+
+    /// Most likely path of states generating exactly the input, from the initial
+    /// state, with its probability, `None` if the input cannot be generated, found
+    /// with the Viterbi algorithm over log probabilities, ties going to the first
+    /// state in declared order
+    ///
+    /// # Errors
+    /// an input symbol that is not defined in Σ
+    pub fn most_likely_path(&self, inputs: &[A]) -> Result<Option<Path<'_, S>>, &'static str> {
+        let mut best = vec![f64::NEG_INFINITY; self.states.len()];
+        let mut previous = Vec::with_capacity(inputs.len());
+
+        best[self.initial] = 0.0;
+
+        for input in inputs {
+            let sym = self.symbol(input)?;
+            let mut next = vec![f64::NEG_INFINITY; self.states.len()];
+            let mut from = vec![usize::MAX; self.states.len()];
+
+            for (state, log) in best.iter().enumerate().filter(|(_, log)| log.is_finite()) {
+                for (to, probability) in &self.delta[state][sym] {
+                    let candidate = log + probability.ln();
+
+                    if candidate > next[*to] {
+                        next[*to] = candidate;
+                        from[*to] = state;
+                    }
+                }
+            }
+
+            best = next;
+            previous.push(from);
+        }
+
+        let last = best.iter()
+            .zip(&self.stop)
+            .map(|(log, stop)| log + stop.ln())
+            .enumerate()
+            .filter(|(_, log)| log.is_finite())
+            .fold(None, |best: Option<(usize, f64)>, (state, log)| match best {
+                Some((_, max)) if max >= log => best,
+                _ => Some((state, log)),
+            });
+
+        Ok(last.map(|(last, log)| {
+            let mut path = vec![last];
+
+            for from in previous.iter().rev() {
+                path.push(from[path[path.len() - 1]]);
+            }
+
+            path.reverse();
+
+            (path.into_iter().map(|state| &self.states[state]).collect(), log.exp())
+        }))
+    }
+
+    fn symbol(&self, input: &A) -> Result<usize, &'static str> {
+        self.Σ.iter().position(|sym| sym == input).ok_or(ERR_INVALID_INPUT)
+    }
+}
+
+impl<A: Clone + Eq, S: Eq + Hash> ProbabilisticAutomaton<A, S> {
+    /// Random word generated according to the distribution of this automaton,
+    /// `None` if it is not complete after the given number of symbols
+    pub fn sample(&self, random: &mut impl Rng, limit: usize) -> Option<Vec<A>> {
+        let mut word = Vec::new();
+        let mut state = self.initial;
+
+        loop {
+            let mut draw = random.next_f64() - self.stop[state];
+
+            if draw < 0.0 {
+                return Some(word);
+            } else if word.len() == limit {
+                return None;
+            }
+
+            // rounding may leave the draw past the last transition, which is then taken
+            let mut last = None;
+
+            'transitions: for (sym, transitions) in self.delta[state].iter().enumerate() {
+                for (next, probability) in transitions.iter().filter(|(_, probability)| *probability > 0.0) {
+                    last = Some((sym, *next));
+                    draw -= probability;
+
+                    if draw < 0.0 {
+                        break 'transitions;
+                    }
+                }
+            }
+
+            let (sym, next) = last?;
+
+            word.push(self.Σ[sym].clone());
+            state = next;
+        }
+    }
+}
+
+impl<A: Debug, S: Debug> Debug for ProbabilisticAutomaton<A, S> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("ProbabilisticAutomaton")
+            .field("Σ", &self.Σ)
+            .field("states", &self.states)
+            .field("δ", &self.delta)
+            .field("ρ", &self.stop)
+            .finish_non_exhaustive()
+    }
+}
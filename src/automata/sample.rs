@@ -0,0 +1,120 @@
+use std::hash::Hash;
+
+use crate::automata::{DFA, NFA};
+use crate::automata::count::completions;
+use crate::random::Rng;
+use crate::UNREACHABLE_ERR;
+
+impl<A: Clone + Eq + Hash, S: Eq + Hash> DFA<A, S> {
+    /// Random word of `len` symbols accepted by this automaton, drawn uniformly
+    /// among all of them, `None` if it accepts none of that length
+    ///
+    /// A single number below the count of the words is drawn and decoded into
+    /// symbols by the counts of the words of the remaining length from each
+    /// state, see [`DFA::count_words`], and with an interval alphabet into the
+    /// symbols of their classes
+    ///
+    /// # Errors
+    #[allow(clippy::missing_panics_doc)] // draws are below the size of the class
+    pub fn sample(&self, len: usize, random: &mut impl Rng) -> Result<Option<Vec<A>>, &'static str> {
+        let table = self.table();
+
+        let mut counts = Vec::with_capacity(len + 1);
+
+        // words of each length leading from each state to a final state
+        completions(&table, len, |words| counts.push(words.to_vec()))?;
+
+        let total = counts[len][table.initial];
+
+        if total == 0 {
+            return Ok(None);
+        }
+
+        let mut draw = below(random, total);
+        let mut state = table.initial;
+        let mut word = Vec::with_capacity(len);
+
+        for left in (0..len).rev() {
+            for (sym, next) in table.delta[state].iter().enumerate() {
+                // words through the class, by symbol of the class
+                let words = counts[left][*next] * table.size(sym);
+
+                if draw < words {
+                    let n = draw / counts[left][*next];
+
+                    word.push(table.intervals.map_or_else(
+                        || table.symbols[sym].clone(),
+                        |intervals| intervals.nth(sym, n).expect(UNREACHABLE_ERR),
+                    ));
+                    draw %= counts[left][*next];
+                    state = *next;
+                    break;
+                }
+
+                draw -= words;
+            }
+        }
+
+        Ok(Some(word))
+    }
+}
+
+impl<A: Clone + Eq + Hash, S: Clone + Eq + Hash> NFA<A, S> {
+    /// Random word of `len` symbols accepted by this automaton, `None` if it
+    /// accepts none of that length
+    ///
+    /// A random walk from the initial state, taking one of the transitions still
+    /// leading to a final state in the remaining symbols uniformly at each step,
+    /// so unlike [`DFA::sample`] words are not drawn uniformly, but the number of
+    /// words is never counted
+    pub fn random_walk(&self, len: usize, random: &mut impl Rng) -> Option<Vec<A>> {
+        let table = self.table();
+
+        // states reaching a final state in exactly k symbols, by k
+        let mut reach = vec![(0..table.states.len()).map(|state| table.is_final(state)).collect::<Vec<_>>()];
+
+        for left in 1..=len {
+            let next = table.delta.iter()
+                .map(|transitions| transitions.iter().flatten().any(|next| reach[left - 1][*next]))
+                .collect();
+
+            reach.push(next);
+        }
+
+        if !reach[len][table.initial] {
+            return None;
+        }
+
+        let mut state = table.initial;
+        let mut word = Vec::with_capacity(len);
+
+        for left in (0..len).rev() {
+            let moves = table.delta[state].iter()
+                .enumerate()
+                .flat_map(|(sym, targets)| targets.iter().map(move |next| (sym, *next)))
+                .filter(|(_, next)| reach[left][*next])
+                .collect::<Vec<_>>();
+
+            let (sym, next) = moves[random.next_below(moves.len())];
+
+            word.push(table.symbols[sym].clone());
+            state = next;
+        }
+
+        Some(word)
+    }
+}
+
+// uniformly distributed number in [0, bound), rejecting the draws of the last,
+// incomplete, multiple of the bound
+fn below(random: &mut impl Rng, bound: u128) -> u128 {
+    let limit = u128::MAX - u128::MAX % bound;
+
+    loop {
+        let draw = u128::from(random.next_u64()) << 64 | u128::from(random.next_u64());
+
+        if draw < limit {
+            return draw % bound;
+        }
+    }
+}
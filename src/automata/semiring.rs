@@ -0,0 +1,130 @@
+use std::fmt::Debug;
+
+/// Semiring (K, ⊕, ⊗, 0, 1) of the weights of a [`WeightedAutomaton`](crate::automata::WeightedAutomaton),
+/// ⊗ combining the weights along a path and ⊕ the weights of alternative paths
+pub trait Semiring: Clone + Debug + PartialEq {
+    /// Identity of ⊕, annihilator of ⊗, the weight of no path
+    fn zero() -> Self;
+
+    /// Identity of ⊗, the weight of the empty path
+    fn one() -> Self;
+
+    /// Weight of either of two alternatives, ⊕
+    #[must_use]
+    fn plus(&self, other: &Self) -> Self;
+
+    /// Weight of one step followed by another, ⊗
+    #[must_use]
+    fn times(&self, other: &Self) -> Self;
+
+    /// Closure w* = 1 ⊕ w ⊕ w ⊗ w ⊕ ..., the weight of any number of turns of a
+    /// cycle, `None` if it diverges
+    fn star(&self) -> Option<Self>;
+}
+
+/// Boolean semiring ({false, true}, ∨, ∧, false, true), is there an accepting path
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Boolean(pub bool);
+
+impl Semiring for Boolean {
+    fn zero() -> Self {
+        Self(false)
+    }
+
+    fn one() -> Self {
+        Self(true)
+    }
+
+    fn plus(&self, other: &Self) -> Self {
+        Self(self.0 || other.0)
+    }
+
+    fn times(&self, other: &Self) -> Self {
+        Self(self.0 && other.0)
+    }
+
+    fn star(&self) -> Option<Self> {
+        Some(Self::one())
+    }
+}
+
+/// Tropical semiring (ℝ ∪ {∞}, min, +, ∞, 0), the cost of the cheapest path,
+/// diverging on cycles of negative cost
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Tropical(pub f64);
+
+impl Semiring for Tropical {
+    fn zero() -> Self {
+        Self(f64::INFINITY)
+    }
+
+    fn one() -> Self {
+        Self(0.0)
+    }
+
+    fn plus(&self, other: &Self) -> Self {
+        Self(self.0.min(other.0))
+    }
+
+    fn times(&self, other: &Self) -> Self {
+        Self(self.0 + other.0)
+    }
+
+    fn star(&self) -> Option<Self> {
+        (self.0 >= 0.0).then(Self::one)
+    }
+}
+
+/// Probability semiring ([0, ∞), +, ×, 0, 1), the probability of any path,
+/// diverging on cycles of probability one or more
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Probability(pub f64);
+
+impl Semiring for Probability {
+    fn zero() -> Self {
+        Self(0.0)
+    }
+
+    fn one() -> Self {
+        Self(1.0)
+    }
+
+    fn plus(&self, other: &Self) -> Self {
+        Self(self.0 + other.0)
+    }
+
+    fn times(&self, other: &Self) -> Self {
+        Self(self.0 * other.0)
+    }
+
+    fn star(&self) -> Option<Self> {
+        (self.0 < 1.0).then(|| Self(1.0 / (1.0 - self.0)))
+    }
+}
+
+/// Counting semiring (ℕ, +, ×, 0, 1), the number of paths, saturating at
+/// `u64::MAX` and diverging on any cycle
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Counting(pub u64);
+
+impl Semiring for Counting {
+    fn zero() -> Self {
+        Self(0)
+    }
+
+    fn one() -> Self {
+        Self(1)
+    }
+
+    fn plus(&self, other: &Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    fn times(&self, other: &Self) -> Self {
+        Self(self.0.saturating_mul(other.0))
+    }
+
+    fn star(&self) -> Option<Self> {
+        (self.0 == 0).then(Self::one)
+    }
+}
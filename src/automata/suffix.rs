@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::automata::DFA;
+use crate::model::Σ;
+
+/// Suffix automaton of a text, the minimal automaton of its suffixes, answering
+/// substring queries in time linear in the query
+///
+/// Built online by the algorithm of Blumer et al., one symbol of the text at a
+/// time, it has fewer than `2n` states for a text of `n > 1` symbols, the initial
+/// state being 0, each state standing for the substrings sharing their ends in
+/// the text, its suffix link leading to the state of their longest suffix ending
+/// elsewhere too
+#[derive(Clone, Debug)]
+pub struct SuffixAutomaton<A> {
+    states: Vec<Node<A>>,
+    // symbols of the text, in order of their first occurrence
+    symbols: Vec<A>,
+    len: usize,
+}
+
+#[derive(Clone, Debug)]
+struct Node<A> {
+    // length of the longest substring of the state
+    len: usize,
+    link: Option<usize>,
+    next: HashMap<A, usize>,
+    // number of ends of the substrings of the state in the text
+    count: usize,
+}
+
+impl<A: Clone + Eq + Hash> SuffixAutomaton<A> {
+    /// Suffix automaton of the text
+    #[must_use]
+    pub fn new(text: &[A]) -> Self {
+        let mut states = vec![Node { len: 0, link: None, next: HashMap::new(), count: 0 }];
+        let mut symbols = Vec::new();
+        let mut last = 0;
+
+        for sym in text {
+            if !states[0].next.contains_key(sym) {
+                symbols.push(sym.clone());
+            }
+
+            let current = states.len();
+
+            states.push(Node { len: states[last].len + 1, link: None, next: HashMap::new(), count: 1 });
+
+            // the suffixes of the text so far without a transition on the symbol
+            let mut state = Some(last);
+
+            while let Some(suffix) = state.filter(|suffix| !states[*suffix].next.contains_key(sym)) {
+                states[suffix].next.insert(sym.clone(), current);
+                state = states[suffix].link;
+            }
+
+            states[current].link = Some(state.map_or(0, |suffix| split(&mut states, suffix, sym)));
+
+            last = current;
+        }
+
+        // ends propagate along suffix links, from the longest substrings down
+        let mut order = (1..states.len()).collect::<Vec<_>>();
+
+        order.sort_unstable_by_key(|state| std::cmp::Reverse(states[*state].len));
+
+        for state in order {
+            if let Some(link) = states[state].link {
+                states[link].count += states[state].count;
+            }
+        }
+
+        Self { states, symbols, len: text.len() }
+    }
+
+    /// Is the pattern a substring of the text, the empty pattern always being one
+    #[must_use]
+    pub fn contains(&self, pattern: &[A]) -> bool {
+        self.walk(pattern).is_some()
+    }
+
+    /// Number of occurrences of the pattern in the text, overlapping ones included,
+    /// the empty pattern occurring at each of the `n + 1` positions of the text
+    #[must_use]
+    pub fn occurrences(&self, pattern: &[A]) -> usize {
+        match self.walk(pattern) {
+            Some(0) => self.len + 1,
+            Some(state) => self.states[state].count,
+            None => 0,
+        }
+    }
+
+    /// Number of distinct non empty substrings of the text
+    #[must_use]
+    pub fn distinct_substrings(&self) -> usize {
+        self.states.iter()
+            .filter_map(|state| state.link.map(|link| state.len - self.states[link].len))
+            .sum()
+    }
+
+    /// Longest substring of the text that is also a substring of the other text,
+    /// the first one in the other text if several are as long
+    #[must_use]
+    pub fn longest_common_substring(&self, other: &[A]) -> Vec<A> {
+        let (mut state, mut len) = (0, 0);
+        let (mut best, mut end) = (0, 0);
+
+        for (position, sym) in other.iter().enumerate() {
+            // shortens the match to its longest suffix that can be extended
+            while state != 0 && !self.states[state].next.contains_key(sym) {
+                state = self.states[state].link.unwrap_or(0);
+                len = self.states[state].len;
+            }
+
+            if let Some(next) = self.states[state].next.get(sym) {
+                state = *next;
+                len += 1;
+            } else {
+                len = 0;
+            }
+
+            if len > best {
+                best = len;
+                end = position + 1;
+            }
+        }
+
+        other[end - best..end].to_vec()
+    }
+
+    /// [`DFA`] over the symbols of the text, in order of their first occurrence,
+    /// accepting its substrings, every state of the automaton being final, they
+    /// are tagged with their number and a rejecting dead state, numbered after
+    /// them, completes its transitions
+    ///
+    /// # Errors
+    pub fn to_dfa(&self) -> Result<DFA<A, usize>, &'static str> {
+        let dead = self.states.len();
+
+        let delta = self.states.iter()
+            .map(|state| self.symbols.iter().map(|sym| state.next.get(sym).copied().unwrap_or(dead)).collect())
+            .chain([vec![dead; self.symbols.len()]])
+            .collect::<Vec<_>>();
+
+        let states = (0..=dead).map(|idx| (vec![idx], idx != dead)).collect();
+
+        Ok(DFA::from_table(Σ::new(self.symbols.clone())?, states, 0, &delta))
+    }
+
+    fn walk(&self, pattern: &[A]) -> Option<usize> {
+        pattern.iter().try_fold(0, |state, sym| self.states[state].next.get(sym).copied())
+    }
+}
+
+impl<A> SuffixAutomaton<A> {
+    /// Number of symbols of the text
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Is the text empty
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of states of this automaton, without a dead state
+    #[must_use]
+    pub const fn state_count(&self) -> usize {
+        self.states.len()
+    }
+}
+
+// state to link the new state to, given the longest suffix with a transition on
+// the symbol, the state it leads to unless that state also holds longer
+// substrings, which are split off into a clone
+fn split<A: Clone + Eq + Hash>(states: &mut Vec<Node<A>>, suffix: usize, sym: &A) -> usize {
+    let next = states[suffix].next[sym];
+
+    if states[suffix].len + 1 == states[next].len {
+        return next;
+    }
+
+    let clone = states.len();
+
+    states.push(Node {
+        len: states[suffix].len + 1,
+        link: states[next].link,
+        next: states[next].next.clone(),
+        count: 0,
+    });
+
+    let mut state = Some(suffix);
+
+    while let Some(redirected) = state.filter(|redirected| states[*redirected].next.get(sym) == Some(&next)) {
+        states[redirected].next.insert(sym.clone(), clone);
+        state = states[redirected].link;
+    }
+
+    states[next].link = Some(clone);
+
+    clone
+}
@@ -0,0 +1,406 @@
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+
+use crate::automata::{DFA, validate_fa_configuration};
+use crate::automata::algebra::BooleanAlgebra;
+use crate::automata::mealy::ERR_UNDEFINED_STATE;
+use crate::automata::minimize::partition;
+use crate::automata::table::reachable;
+use crate::model::{F, Phase, Q, State, δ, Σ};
+use crate::UNREACHABLE_ERR;
+
+// product state, the states of either automaton, `None` once it has no run
+type Pair = (Option<usize>, Option<usize>);
+
+/// Symbolic finite automaton, (Q, δ, q0, F), whose transitions δ are labelled by
+/// predicates of a [`BooleanAlgebra`] instead of single symbols of an alphabet
+///
+/// A transition is taken on every symbol its predicate holds for, so large
+/// alphabets like `char` need no more than one transition per range of symbols.
+/// Transitions of a state may overlap, making the automaton nondeterministic
+pub struct SymbolicAutomaton<P, S> {
+    states: Vec<State<S>>,
+    delta: Vec<Vec<(P, usize)>>,
+    initial: usize,
+}
+
+impl<P: BooleanAlgebra, S: Eq + Hash> SymbolicAutomaton<P, S> {
+    /// # Errors
+    #[allow(non_snake_case)]
+    #[allow(clippy::missing_panics_doc)] // states are validated before indexing
+    pub fn new(Q: Q<S>, δ: δ<P, S>, q0: S, F: &F<S>) -> Result<Self, &'static str> {
+        validate_fa_configuration(&Q, &δ, &q0, F)?;
+
+        if δ.iter().any(|(state, _)| !Q.contains(state)) {
+            return Err(ERR_UNDEFINED_STATE);
+        }
+
+        let q0 = [q0];
+
+        let states = Vec::from(Q).into_iter()
+            .map(|tag| {
+                let tag = vec![tag];
+                let phase = Phase::of(tag[0] == q0[0], F.contains(&tag));
+
+                State::new(tag, phase)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let index = |tag: &S| states.iter().position(|state| state[0] == *tag).expect(UNREACHABLE_ERR);
+        let mut delta = (0..states.len()).map(|_| Vec::new()).collect::<Vec<_>>();
+
+        let (δ, defaults) = δ.into_parts();
+
+        for (state, transitions) in δ {
+            for (predicate, next) in transitions {
+                delta[index(&state)].push((predicate, index(&next)));
+            }
+        }
+
+        // the default transition is taken on the symbols no predicate holds for
+        for (state, next) in defaults {
+            let transitions = &mut delta[index(&state)];
+            let otherwise = transitions.iter().fold(P::bottom(), |any, (predicate, _)| any.or(predicate)).not();
+
+            if otherwise.is_satisfiable() {
+                transitions.push((otherwise, index(&next)));
+            }
+        }
+
+        let initial = index(&q0[0]);
+
+        Ok(Self { states, delta, initial })
+    }
+
+    /// Does this automaton accept the input, following every transition whose
+    /// predicate holds for each symbol
+    pub fn accepts(&self, inputs: &[P::Symbol]) -> bool {
+        let mut current = vec![false; self.states.len()];
+
+        current[self.initial] = true;
+
+        for input in inputs {
+            let mut next = vec![false; self.states.len()];
+
+            for (state, _) in current.iter().enumerate().filter(|(_, active)| **active) {
+                for (predicate, to) in &self.delta[state] {
+                    next[*to] |= predicate.holds(input);
+                }
+            }
+
+            current = next;
+        }
+
+        current.iter().enumerate().any(|(state, active)| *active && self.states[state].is_final())
+    }
+
+    /// Initial state q0 of this automaton
+    #[must_use]
+    pub fn initial(&self) -> &State<S> {
+        &self.states[self.initial]
+    }
+
+    /// Is there at most one transition of each state on any symbol, i.e. are the
+    /// satisfiable predicates of each state pairwise disjoint
+    #[must_use]
+    pub fn is_deterministic(&self) -> bool {
+        self.delta.iter().all(|transitions| transitions.iter()
+            .enumerate()
+            .all(|(idx, (predicate, _))| transitions[..idx].iter()
+                .all(|(other, _)| !predicate.and(other).is_satisfiable())))
+    }
+
+    /// Minterms of the predicates of this automaton, the satisfiable conjunctions
+    /// of each predicate or its negation, which partition the symbols into classes
+    /// no transition distinguishes
+    #[must_use]
+    pub fn minterms(&self) -> Vec<P> {
+        minterms(self.delta.iter().flatten().map(|(predicate, _)| predicate))
+    }
+
+    /// States Q of this automaton, in declared order
+    pub fn states(&self) -> impl Iterator<Item=&State<S>> {
+        self.states.iter()
+    }
+
+    /// Predicate labelled transitions δ(state), empty if the state is not defined
+    /// in this automaton
+    pub fn transitions<'a>(&'a self, state: &State<S>) -> impl Iterator<Item=(&'a P, &'a State<S>)> {
+        self.states.iter()
+            .position(|candidate| candidate == state)
+            .into_iter()
+            .flat_map(|state| self.delta[state].iter().map(|(predicate, next)| (predicate, &self.states[*next])))
+    }
+}
+
+impl<P: BooleanAlgebra, S: Clone + Eq + Hash> SymbolicAutomaton<P, S> {
+    /// Complete deterministic automaton accepting the inputs this automaton does
+    /// not accept
+    #[must_use]
+    pub fn complement(&self) -> Self {
+        let mut complement = self.determinize();
+
+        complement.states = complement.states.iter()
+            .enumerate()
+            .map(|(idx, state)| State::from_tags(state.to_vec(), Phase::of(idx == complement.initial, !state.is_final())))
+            .collect();
+
+        complement
+    }
+
+    /// Complete deterministic automaton accepting the same inputs, by the subset
+    /// construction over the minterms of the predicates leaving each subset, each
+    /// state tagged with the tags of its subset, the empty subset being a dead state,
+    /// transitions to the same subset are merged into one predicate
+    #[must_use]
+    pub fn determinize(&self) -> Self {
+        let mut subsets = vec![vec![self.initial]];
+        let mut index = HashMap::from([(vec![self.initial], 0)]);
+        let mut delta = Vec::new();
+
+        while delta.len() < subsets.len() {
+            let subset = subsets[delta.len()].clone();
+            let predicates = subset.iter().flat_map(|state| self.delta[*state].iter().map(|(predicate, _)| predicate));
+            let mut transitions: Vec<(P, usize)> = Vec::new();
+
+            for minterm in minterms(predicates) {
+                let mut next = subset.iter()
+                    .flat_map(|state| self.delta[*state].iter())
+                    .filter(|(predicate, _)| predicate.and(&minterm).is_satisfiable())
+                    .map(|(_, next)| *next)
+                    .collect::<Vec<_>>();
+
+                next.sort_unstable();
+                next.dedup();
+
+                let target = *index.entry(next.clone()).or_insert_with(|| {
+                    subsets.push(next);
+                    subsets.len() - 1
+                });
+
+                merge(&mut transitions, minterm, target);
+            }
+
+            delta.push(transitions);
+        }
+
+        let states = subsets.iter()
+            .enumerate()
+            .map(|(idx, subset)| State::from_tags(
+                subset.iter().flat_map(|state| self.states[*state].iter().cloned()).collect(),
+                Phase::of(idx == 0, subset.iter().any(|state| self.states[*state].is_final())),
+            ))
+            .collect();
+
+        Self { states, delta, initial: 0 }
+    }
+
+    /// Automaton accepting the inputs accepted by both automata, whose states are
+    /// the pairs of their states reachable on the conjunctions of their predicates
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)] // pairs of an intersection always have both states
+    pub fn intersection<T: Clone + Eq + Hash>(&self, other: &SymbolicAutomaton<P, T>) -> SymbolicAutomaton<P, (S, T)> {
+        let (pairs, delta) = self.product(other, false);
+
+        let states = pairs.iter()
+            .enumerate()
+            .map(|(idx, (left, right))| {
+                let (left, right) = (&self.states[left.expect(UNREACHABLE_ERR)], &other.states[right.expect(UNREACHABLE_ERR)]);
+                let tags = left.iter().flat_map(|left| right.iter().map(|right| (left.clone(), right.clone()))).collect();
+
+                State::from_tags(tags, Phase::of(idx == 0, left.is_final() && right.is_final()))
+            })
+            .collect();
+
+        SymbolicAutomaton { states, delta, initial: 0 }
+    }
+
+    /// Equivalent complete deterministic automaton with the minimum number of
+    /// states, determinizing this automaton and merging its equivalent states
+    /// with Moore's partition refinement over the minterms of its predicates,
+    /// each merged state keeps the tags of its first state in breadth first order
+    #[must_use]
+    pub fn minimize(&self) -> Self {
+        let dfa = self.determinize();
+        let (minterms, next) = dfa.table();
+        let states = reachable(dfa.initial, &next);
+        let (classes, count) = partition(&states, &next, |state| dfa.states[state].is_final());
+
+        let mut representatives = vec![usize::MAX; count];
+
+        for state in states.iter().rev() {
+            representatives[classes[*state]] = *state;
+        }
+
+        let delta = representatives.iter()
+            .map(|state| {
+                let mut transitions = Vec::new();
+
+                for (minterm, next) in minterms.iter().zip(&next[*state]) {
+                    merge(&mut transitions, minterm.clone(), classes[*next]);
+                }
+
+                transitions
+            })
+            .collect();
+
+        let states = representatives.iter()
+            .enumerate()
+            .map(|(idx, state)| State::from_tags(
+                dfa.states[*state].to_vec(),
+                Phase::of(idx == classes[dfa.initial], dfa.states[*state].is_final()),
+            ))
+            .collect();
+
+        Self { states, delta, initial: classes[dfa.initial] }
+    }
+
+    /// [`DFA`] over the minterms of the determinized automaton, each minterm being
+    /// a symbol standing for all the symbols it holds for, stepping on the minterm
+    /// holding for a symbol follows the transition this automaton takes on it
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)] // minterms are unique and satisfiable
+    pub fn to_dfa(&self) -> DFA<P, S> where P: Hash {
+        let dfa = self.determinize();
+        let (minterms, next) = dfa.table();
+
+        let states = dfa.states.iter()
+            .map(|state| (state.to_vec(), state.is_final()))
+            .collect();
+
+        DFA::from_table(Σ::new(minterms).expect(UNREACHABLE_ERR), states, dfa.initial, &next)
+    }
+
+    /// Automaton accepting the inputs accepted by either automaton, whose states are
+    /// the pairs of their states reachable on the conjunctions of their predicates,
+    /// `None` once an automaton has no run on the input read so far
+    #[must_use]
+    pub fn union<T: Clone + Eq + Hash>(&self, other: &SymbolicAutomaton<P, T>) -> SymbolicAutomaton<P, (Option<S>, Option<T>)> {
+        let (pairs, delta) = self.product(other, true);
+
+        let states = pairs.iter()
+            .enumerate()
+            .map(|(idx, (left, right))| {
+                let left = left.map(|left| &self.states[left]);
+                let right = right.map(|right| &other.states[right]);
+                let left_tags = left.map_or_else(|| vec![None], |left| left.iter().cloned().map(Some).collect());
+                let right_tags = right.map_or_else(|| vec![None], |right| right.iter().cloned().map(Some).collect::<Vec<_>>());
+                let tags = left_tags.iter()
+                    .flat_map(|left| right_tags.iter().map(|right| (left.clone(), right.clone())))
+                    .collect();
+                let r#final = left.is_some_and(State::is_final) || right.is_some_and(State::is_final);
+
+                State::from_tags(tags, Phase::of(idx == 0, r#final))
+            })
+            .collect();
+
+        SymbolicAutomaton { states, delta, initial: 0 }
+    }
+
+    // reachable pairs of states of both automata, from the pair of their initial
+    // states, moving on the satisfiable conjunctions of their predicates, for a
+    // union either automaton may also have no run, on the symbols none of its
+    // predicates hold for
+    fn product<T>(&self, other: &SymbolicAutomaton<P, T>, union: bool) -> (Vec<Pair>, Vec<Vec<(P, usize)>>) {
+        let moves = |delta: &[Vec<(P, usize)>], state: Option<usize>| -> Vec<(P, Option<usize>)> {
+            let Some(state) = state else {
+                return vec![(P::top(), None)];
+            };
+
+            let mut moves = delta[state].iter()
+                .map(|(predicate, next)| (predicate.clone(), Some(*next)))
+                .collect::<Vec<_>>();
+
+            if union {
+                let stuck = moves.iter().fold(P::bottom(), |any, (predicate, _)| any.or(predicate)).not();
+
+                moves.push((stuck, None));
+            }
+
+            moves
+        };
+
+        let mut pairs = vec![(Some(self.initial), Some(other.initial))];
+        let mut index = HashMap::from([(pairs[0], 0)]);
+        let mut delta = Vec::new();
+
+        while delta.len() < pairs.len() {
+            let (left, right) = pairs[delta.len()];
+            let mut transitions = Vec::new();
+
+            for (left_predicate, left_next) in moves(&self.delta, left) {
+                for (right_predicate, right_next) in moves(&other.delta, right) {
+                    let predicate = left_predicate.and(&right_predicate);
+
+                    if !predicate.is_satisfiable() || (left_next.is_none() && right_next.is_none()) {
+                        continue;
+                    }
+
+                    let next = (left_next, right_next);
+                    let target = *index.entry(next).or_insert_with(|| {
+                        pairs.push(next);
+                        pairs.len() - 1
+                    });
+
+                    merge(&mut transitions, predicate, target);
+                }
+            }
+
+            delta.push(transitions);
+        }
+
+        (pairs, delta)
+    }
+
+    // minterms of a deterministic automaton, with the target of each state on
+    // each of them, δ[state][minterm]
+    fn table(&self) -> (Vec<P>, Vec<Vec<usize>>) {
+        let minterms = self.minterms();
+
+        let next = self.delta.iter()
+            .map(|transitions| minterms.iter()
+                .map(|minterm| transitions.iter()
+                    .find(|(predicate, _)| predicate.and(minterm).is_satisfiable())
+                    .map(|(_, next)| *next)
+                    .expect(UNREACHABLE_ERR))
+                .collect())
+            .collect();
+
+        (minterms, next)
+    }
+}
+
+impl<P: Debug, S: Debug> Debug for SymbolicAutomaton<P, S> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SymbolicAutomaton")
+            .field("states", &self.states)
+            .field("δ", &self.delta)
+            .finish_non_exhaustive()
+    }
+}
+
+// refines ⊤ by each predicate, keeping the satisfiable parts inside and outside of it
+fn minterms<'a, P: BooleanAlgebra + 'a>(predicates: impl Iterator<Item=&'a P>) -> Vec<P> {
+    let mut minterms = vec![P::top()];
+
+    for predicate in predicates {
+        let negated = predicate.not();
+
+        minterms = minterms.into_iter()
+            .flat_map(|minterm| [minterm.and(predicate), minterm.and(&negated)])
+            .filter(BooleanAlgebra::is_satisfiable)
+            .collect();
+    }
+
+    minterms
+}
+
+// adds a transition, or widens the predicate of the transition to the same target
+fn merge<P: BooleanAlgebra>(transitions: &mut Vec<(P, usize)>, predicate: P, target: usize) {
+    if let Some((existing, _)) = transitions.iter_mut().find(|(_, next)| *next == target) {
+        *existing = existing.or(&predicate);
+    } else {
+        transitions.push((predicate, target));
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::automata::{Defaults, Transitions};
+use crate::model::{Intervals, State};
+use crate::UNREACHABLE_ERR;
+
+// index based view of a deterministic automaton, used by algorithms that
+// need to treat states and symbols as dense indices, δ[state][symbol], with an
+// interval alphabet each symbol standing for the symbols of its class
+pub struct DfaTable<'a, A, S> {
+    pub symbols: &'a [A],
+    pub intervals: Option<&'a Intervals<A>>,
+    pub states: &'a [State<S>],
+    pub initial: usize,
+    pub delta: Vec<Vec<usize>>,
+}
+
+impl<'a, A: Eq + Hash, S: Eq + Hash> DfaTable<'a, A, S> {
+    pub fn new(
+        symbols: &'a [A], intervals: Option<&'a Intervals<A>>, states: &'a [State<S>],
+        transitions: &Transitions<A, S>, defaults: &Defaults<S>,
+    ) -> Self {
+        let index = index_states(states);
+
+        let delta = states.iter()
+            .map(|state| {
+                let inputs = transitions.get(state).expect(UNREACHABLE_ERR);
+
+                symbols.iter()
+                    .map(|sym| index[inputs.get(sym).or_else(|| defaults.get(state)).expect(UNREACHABLE_ERR)])
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            symbols,
+            intervals,
+            states,
+            initial: states.iter().position(State::is_initial).expect(UNREACHABLE_ERR),
+            delta,
+        }
+    }
+
+    // symbol standing for the input, the index of its class with an interval alphabet
+    #[inline]
+    pub fn symbol(&self, input: &A) -> Option<usize> {
+        self.intervals.map_or_else(|| self.symbols.iter().position(|sym| sym == input), |intervals| intervals.class(input))
+    }
+}
+
+impl<A, S> DfaTable<'_, A, S> {
+    #[inline]
+    pub fn is_final(&self, state: usize) -> bool {
+        self.states[state].is_final()
+    }
+
+    // number of inputs the symbol stands for, the size of its class
+    #[inline]
+    pub fn size(&self, sym: usize) -> u128 {
+        self.intervals.map_or(1, |intervals| intervals.size(sym))
+    }
+}
+
+impl<A: Clone, S> DfaTable<'_, A, S> {
+    // inputs the symbol stands for, in ascending order with an interval alphabet
+    pub fn inputs(&self, sym: usize) -> impl Iterator<Item=A> + '_ {
+        let intervals = self.intervals;
+
+        (0..self.size(sym)).map(move |n| intervals.map_or_else(
+            || self.symbols[sym].clone(),
+            |intervals| intervals.nth(sym, n).expect(UNREACHABLE_ERR),
+        ))
+    }
+}
+
+// index based view of a nondeterministic automaton, δ[state][symbol] -> {states}
+pub struct NfaTable<'a, A, S> {
+    pub symbols: &'a [A],
+    pub states: &'a [State<S>],
+    pub initial: usize,
+    pub delta: Vec<Vec<Vec<usize>>>,
+}
+
+impl<'a, A: Eq + Hash, S: Eq + Hash> NfaTable<'a, A, S> {
+    pub fn new(
+        symbols: &'a [A], states: &'a [State<S>], transitions: &Transitions<A, S>, defaults: &Defaults<S>,
+    ) -> Self {
+        let index = states.iter()
+            .enumerate()
+            .map(|(idx, state)| (&state[0], idx))
+            .collect::<HashMap<_, _>>();
+
+        let delta = states.iter()
+            .map(|state| {
+                let inputs = transitions.get(state).expect(UNREACHABLE_ERR);
+
+                symbols.iter()
+                    .map(|sym| inputs.get(sym)
+                        .or_else(|| defaults.get(state))
+                        .map(|next| next.iter().map(|tag| index[tag]).collect())
+                        .unwrap_or_default())
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            symbols,
+            states,
+            initial: states.iter().position(State::is_initial).expect(UNREACHABLE_ERR),
+            delta,
+        }
+    }
+
+    #[inline]
+    pub fn is_final(&self, state: usize) -> bool {
+        self.states[state].is_final()
+    }
+}
+
+pub fn index_states<S: Eq + Hash>(states: &[State<S>]) -> HashMap<&Vec<S>, usize> {
+    states.iter()
+        .enumerate()
+        .map(|(idx, state)| (std::borrow::Borrow::borrow(state), idx))
+        .collect()
+}
+
+// states reachable from the initial state, in breadth first order over the
+// declared order of Σ, the initial state is always first
+pub fn reachable(initial: usize, delta: &[Vec<usize>]) -> Vec<usize> {
+    let mut visited = vec![false; delta.len()];
+    let mut order = vec![initial];
+    let mut queue = VecDeque::from([initial]);
+
+    visited[initial] = true;
+
+    while let Some(state) = queue.pop_front() {
+        for &next in &delta[state] {
+            if !visited[next] {
+                visited[next] = true;
+                order.push(next);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    order
+}
+
+// builds the transitions table for automata derived from index based tables,
+// the states are expected to be unique and δ complete for deterministic automata
+pub fn build_transitions<A, S, I>(
+    symbols: &[A], states: &[State<S>], delta: &[I],
+) -> Transitions<A, S>
+    where A: Clone + Eq + Hash,
+          S: Clone + Eq + Hash,
+          I: AsRef<[Vec<usize>]>
+{
+    states.iter()
+        .zip(delta)
+        .map(|(state, inputs)| {
+            let inputs = symbols.iter()
+                .zip(inputs.as_ref())
+                .filter(|(_, next)| !next.is_empty())
+                .map(|(sym, next)| (
+                    sym.clone(),
+                    next.iter().flat_map(|idx| states[*idx].iter().cloned()).collect(),
+                ))
+                .collect();
+
+            (state.clone(), inputs)
+        })
+        .collect()
+}
@@ -0,0 +1,213 @@
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+use std::ops::Deref;
+
+use crate::automata::{DFA, NFA};
+use crate::model::State;
+
+/// Transition taken by a [`DFA`] while tracing its execution
+pub struct Transition<A, S> {
+    /// Position of the symbol in the traced input
+    pub position: usize,
+
+    /// Input symbol
+    pub symbol: A,
+
+    /// State before reading the symbol
+    pub from: State<S>,
+
+    /// State after reading the symbol
+    pub to: State<S>,
+}
+
+/// Path taken through a [`DFA`] by an input
+pub struct Trace<A, S> {
+    start: State<S>,
+    transitions: Vec<Transition<A, S>>,
+}
+
+impl<A, S> Trace<A, S> {
+    /// Did the traced input end in a final state
+    #[must_use]
+    pub fn accepted(&self) -> bool {
+        self.end().is_final()
+    }
+
+    /// State the traced input ended in
+    #[must_use]
+    pub fn end(&self) -> &State<S> {
+        self.transitions.last().map_or(&self.start, |transition| &transition.to)
+    }
+
+    /// State the traced input started in
+    #[must_use]
+    pub const fn start(&self) -> &State<S> {
+        &self.start
+    }
+
+    /// Sequence of states visited, starting with the start state
+    pub fn states(&self) -> impl Iterator<Item=&State<S>> {
+        std::iter::once(&self.start).chain(self.transitions.iter().map(|transition| &transition.to))
+    }
+}
+
+impl<A, S> Deref for Trace<A, S> {
+    type Target = [Transition<A, S>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.transitions
+    }
+}
+
+impl<A: Display, S: Display> Display for Trace<A, S> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}", self.start)?;
+
+        for transition in &self.transitions {
+            write!(fmt, " -{}-> {}", transition.symbol, transition.to)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Active states of an [`NFA`] after reading a symbol while tracing its execution
+pub struct Active<A, S> {
+    /// Position of the symbol in the traced input
+    pub position: usize,
+
+    /// Input symbol
+    pub symbol: A,
+
+    /// Active states after reading the symbol
+    pub states: Vec<State<S>>,
+
+    // for each active state, the index of an active state of the previous step transitioning to it
+    predecessors: Vec<usize>,
+}
+
+/// Sets of active states of an [`NFA`] for each symbol of an input
+pub struct NfaTrace<A, S> {
+    start: Vec<State<S>>,
+    steps: Vec<Active<A, S>>,
+}
+
+impl<A, S> NfaTrace<A, S> {
+    /// Did the traced input end with a final state active
+    #[must_use]
+    pub fn accepted(&self) -> bool {
+        self.end().iter().any(State::is_final)
+    }
+
+    /// States active at the end of the traced input
+    #[must_use]
+    pub fn end(&self) -> &[State<S>] {
+        self.steps.last().map_or(&self.start, |step| &step.states)
+    }
+
+    /// States active at the start of the traced input
+    #[must_use]
+    pub fn start(&self) -> &[State<S>] {
+        &self.start
+    }
+
+    /// One concrete path of states, starting with a start state, through which
+    /// the traced input reaches a final state, `None` if the input was not accepted
+    #[must_use]
+    pub fn witness(&self) -> Option<Vec<State<S>>> {
+        let mut current = self.end().iter().position(State::is_final)?;
+        let mut path = Vec::with_capacity(self.steps.len() + 1);
+
+        for step in self.steps.iter().rev() {
+            path.push(step.states[current].clone());
+            current = step.predecessors[current];
+        }
+
+        path.push(self.start[current].clone());
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+impl<A, S> Deref for NfaTrace<A, S> {
+    type Target = [Active<A, S>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.steps
+    }
+}
+
+impl<A: Display, S: Display> Display for NfaTrace<A, S> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        let write_states = |fmt: &mut Formatter<'_>, states: &[State<S>]| {
+            fmt.write_str("{")?;
+
+            for (idx, state) in states.iter().enumerate() {
+                write!(fmt, "{}{state}", if idx == 0 { "" } else { " " })?;
+            }
+
+            fmt.write_str("}")
+        };
+
+        write_states(fmt, &self.start)?;
+
+        for step in &self.steps {
+            write!(fmt, " -{}-> ", step.symbol)?;
+            write_states(fmt, &step.states)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<A: Clone + Eq + Hash, S: Eq + Hash> DFA<A, S> {
+    /// Steps through the inputs, like [`DFA::steps`], recording each transition taken
+    ///
+    /// # Errors
+    /// an input symbol that is not defined for this automaton
+    pub fn trace(&mut self, inputs: &[A]) -> Result<Trace<A, S>, &'static str> {
+        let mut trace = Trace { start: self.current().clone(), transitions: Vec::with_capacity(inputs.len()) };
+
+        for (position, input) in inputs.iter().enumerate() {
+            let from = self.current().clone();
+            let to = self.step(input)?.clone();
+
+            trace.transitions.push(Transition { position, symbol: input.clone(), from, to });
+        }
+
+        Ok(trace)
+    }
+}
+
+impl<A: Clone + Eq + Hash, S: Clone + Eq + Hash> NFA<A, S> {
+    /// Steps through the inputs, like [`NFA::steps`], recording the active states after each step
+    ///
+    /// # Errors
+    /// an input symbol that is not defined for this automaton
+    pub fn trace(&mut self, inputs: &[A]) -> Result<NfaTrace<A, S>, &'static str> {
+        let mut trace = NfaTrace { start: self.current().clone(), steps: Vec::with_capacity(inputs.len()) };
+
+        for (position, input) in inputs.iter().enumerate() {
+            let previous = self.current().clone();
+
+            self.step(input)?;
+
+            let mut states = Vec::new();
+            let mut predecessors = Vec::new();
+
+            for (idx, state) in previous.iter().enumerate() {
+                for next in self.transitions(state, input) {
+                    if !states.contains(next) {
+                        states.push(next.clone());
+                        predecessors.push(idx);
+                    }
+                }
+            }
+
+            trace.steps.push(Active { position, symbol: input.clone(), states, predecessors });
+        }
+
+        Ok(trace)
+    }
+}
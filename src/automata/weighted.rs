@@ -0,0 +1,226 @@
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+
+use crate::automata::{ERR_INVALID_INPUT, ERR_UNDEFINED_FINAL_STATE, ERR_UNDEFINED_INITIAL_STATE, NFA};
+use crate::automata::dfa::ERR_UNDEFINED_SYMBOL;
+use crate::automata::mealy::ERR_UNDEFINED_STATE;
+use crate::automata::semiring::{Boolean, Semiring};
+use crate::automata::table::reachable;
+use crate::model::{Phase, Q, State, Σ};
+use crate::UNREACHABLE_ERR;
+use crate::utils::duped::Duped;
+
+pub const ERR_DIVERGENT_DISTANCE: &str = "Shortest distance diverges on a cycle of the automaton";
+pub const ERR_DUPLICATE_FINAL_WEIGHT: &str = "Final state has more than one weight";
+pub const ERR_DUPLICATE_TRANSITION: &str = "Weighted transition is defined more than once";
+
+/// Weighted automaton over a [`Semiring`], (Q, Σ, δ, q0, ρ), with weighted
+/// transitions δ and final weights ρ
+///
+/// The weight of an input is the ⊕ over its paths from the initial state of the ⊗
+/// of the weights of their transitions and of the final weight of their last state,
+/// states without a final weight having weight zero
+#[allow(non_snake_case)]
+pub struct WeightedAutomaton<A, S, W> {
+    Σ: Σ<A>,
+    states: Vec<State<S>>,
+    delta: Vec<Vec<Vec<(usize, W)>>>,
+    finals: Vec<W>,
+    initial: usize,
+}
+
+impl<A: Eq, S: Eq + Hash, W: Semiring> WeightedAutomaton<A, S, W> {
+    /// # Errors
+    #[allow(non_snake_case)]
+    #[allow(clippy::missing_panics_doc)] // states and symbols are validated before indexing
+    pub fn new(Q: Q<S>, Σ: Σ<A>, δ: Vec<(S, A, S, W)>, q0: S, ρ: Vec<(S, W)>) -> Result<Self, &'static str> {
+        if δ.iter().any(|(state, _, next, _)| !Q.contains(state) || !Q.contains(next)) {
+            return Err(ERR_UNDEFINED_STATE);
+        } else if δ.iter().any(|(_, sym, _, _)| !Σ.contains(sym)) {
+            return Err(ERR_UNDEFINED_SYMBOL);
+        } else if δ.iter().enumerate().any(|(idx, (state, sym, next, _))|
+            δ[..idx].iter().any(|(other, other_sym, other_next, _)| other == state && other_sym == sym && other_next == next)
+        ) {
+            return Err(ERR_DUPLICATE_TRANSITION);
+        } else if !Q.contains(&q0) {
+            return Err(ERR_UNDEFINED_INITIAL_STATE);
+        } else if ρ.iter().any(|(state, _)| !Q.contains(state)) {
+            return Err(ERR_UNDEFINED_FINAL_STATE);
+        } else if ρ.iter().map(|(state, _)| state).has_dupes() {
+            return Err(ERR_DUPLICATE_FINAL_WEIGHT);
+        }
+
+        let q0 = [q0];
+        let index = |tag: &S| Q.iter().position(|state| state == tag).expect(UNREACHABLE_ERR);
+        let initial = index(&q0[0]);
+        let mut delta = (0..Q.len()).map(|_| (0..Σ.len()).map(|_| Vec::new()).collect::<Vec<_>>()).collect::<Vec<_>>();
+        let mut finals = vec![W::zero(); Q.len()];
+
+        for (state, sym, next, weight) in δ {
+            let sym = Σ.iter().position(|symbol| *symbol == sym).expect(UNREACHABLE_ERR);
+
+            delta[index(&state)][sym].push((index(&next), weight));
+        }
+
+        for (state, weight) in ρ {
+            finals[index(&state)] = weight;
+        }
+
+        let states = Vec::from(Q).into_iter()
+            .enumerate()
+            .map(|(idx, tag)| State::new(vec![tag], Phase::of(idx == initial, finals[idx] != W::zero())))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { Σ, states, delta, finals, initial })
+    }
+
+    /// Alphabet Σ of this automaton, in declared order
+    #[must_use]
+    pub const fn alphabet(&self) -> &Σ<A> {
+        &self.Σ
+    }
+
+    /// Final weight ρ(state), zero for states that are not final, `None` if the
+    /// state is not defined in this automaton
+    #[must_use]
+    pub fn final_weight(&self, state: &State<S>) -> Option<&W> {
+        self.states.iter().position(|candidate| candidate == state).map(|state| &self.finals[state])
+    }
+
+    /// Initial state q0 of this automaton
+    #[must_use]
+    pub fn initial(&self) -> &State<S> {
+        &self.states[self.initial]
+    }
+
+    /// States Q of this automaton, in declared order, final when their final
+    /// weight is not zero
+    pub fn states(&self) -> impl Iterator<Item=&State<S>> {
+        self.states.iter()
+    }
+
+    /// Weighted transitions δ(state, input), empty if either is not defined in this automaton
+    pub fn transitions<'a>(&'a self, state: &State<S>, input: &A) -> impl Iterator<Item=(&'a State<S>, &'a W)> {
+        let state = self.states.iter().position(|candidate| candidate == state);
+        let sym = self.Σ.iter().position(|sym| sym == input);
+
+        state.zip(sym)
+            .into_iter()
+            .flat_map(|(state, sym)| self.delta[state][sym].iter().map(|(next, weight)| (&self.states[*next], weight)))
+    }
+
+    /// Weight of the input, zero if it is not accepted
+    ///
+    /// # Errors
+    /// an input symbol that is not defined in Σ
+    pub fn weight_of(&self, inputs: &[A]) -> Result<W, &'static str> {
+        let mut forward = vec![W::zero(); self.states.len()];
+
+        forward[self.initial] = W::one();
+
+        for input in inputs {
+            let sym = self.Σ.iter().position(|sym| sym == input).ok_or(ERR_INVALID_INPUT)?;
+            let mut next = vec![W::zero(); self.states.len()];
+
+            for (state, weight) in forward.iter().enumerate().filter(|(_, weight)| **weight != W::zero()) {
+                for (to, transition) in &self.delta[state][sym] {
+                    next[*to] = next[*to].plus(&weight.times(transition));
+                }
+            }
+
+            forward = next;
+        }
+
+        Ok(forward.iter().zip(&self.finals).fold(W::zero(), |total, (weight, r#final)| total.plus(&weight.times(r#final))))
+    }
+
+    /// Shortest distance from the initial state to each state, in declared order,
+    /// the ⊕ over the paths reaching it, on any input, of the ⊗ of their weights,
+    /// zero for states that are not reachable
+    ///
+    /// Distances are computed by the closure of the matrix of the weights between
+    /// reachable states, eliminating one state at a time
+    ///
+    /// # Errors
+    /// a reachable cycle whose weight has no closure, see [`Semiring::star`]
+    pub fn shortest_distance(&self) -> Result<Vec<W>, &'static str> {
+        let next = self.delta.iter()
+            .map(|transitions| transitions.iter().flatten().map(|(next, _)| *next).collect())
+            .collect::<Vec<_>>();
+        let reachable = reachable(self.initial, &next);
+        let size = reachable.len();
+        let mut index = vec![usize::MAX; self.states.len()];
+
+        for (idx, state) in reachable.iter().enumerate() {
+            index[*state] = idx;
+        }
+
+        let mut matrix = vec![vec![W::zero(); size]; size];
+
+        for (idx, state) in reachable.iter().enumerate() {
+            for (next, weight) in self.delta[*state].iter().flatten() {
+                matrix[idx][index[*next]] = matrix[idx][index[*next]].plus(weight);
+            }
+        }
+
+        // after eliminating k, weights are over the paths through states up to k only
+        for k in 0..size {
+            let closure = matrix[k][k].star().ok_or(ERR_DIVERGENT_DISTANCE)?;
+            let previous = matrix.clone();
+
+            for (i, row) in matrix.iter_mut().enumerate() {
+                let through = previous[i][k].times(&closure);
+
+                for (j, weight) in row.iter_mut().enumerate() {
+                    *weight = previous[i][j].plus(&through.times(&previous[k][j]));
+                }
+            }
+        }
+
+        // paths of one or more steps from the initial state, first, and its empty path
+        let mut distances = vec![W::zero(); self.states.len()];
+
+        for (idx, state) in reachable.iter().enumerate() {
+            distances[*state] = matrix[0][idx].clone();
+        }
+
+        distances[self.initial] = distances[self.initial].plus(&W::one());
+
+        Ok(distances)
+    }
+}
+
+impl<A: Clone + Eq + Hash, S: Clone + Eq + Hash> NFA<A, S> {
+    /// Weighted automaton over the [`Boolean`] semiring with the states of this
+    /// automaton, each transition and final state weighing true, the weight of an
+    /// input being whether this automaton accepts it
+    #[must_use]
+    pub fn to_weighted(&self) -> WeightedAutomaton<A, S, Boolean> {
+        let table = self.table();
+
+        let delta = table.delta.iter()
+            .map(|transitions| transitions.iter()
+                .map(|next| next.iter().map(|next| (*next, Boolean(true))).collect())
+                .collect())
+            .collect();
+
+        WeightedAutomaton {
+            Σ: self.alphabet().clone(),
+            states: table.states.to_vec(),
+            delta,
+            finals: (0..table.states.len()).map(|state| Boolean(table.is_final(state))).collect(),
+            initial: table.initial,
+        }
+    }
+}
+
+impl<A: Debug, S: Debug, W: Debug> Debug for WeightedAutomaton<A, S, W> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("WeightedAutomaton")
+            .field("Σ", &self.Σ)
+            .field("states", &self.states)
+            .field("δ", &self.delta)
+            .field("ρ", &self.finals)
+            .finish_non_exhaustive()
+    }
+}
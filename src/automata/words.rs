@@ -0,0 +1,260 @@
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use crate::automata::{DFA, NFA};
+use crate::model::Intervals;
+use crate::UNREACHABLE_ERR;
+
+impl<A: Clone + Eq + Hash, S: Eq + Hash> DFA<A, S> {
+    /// Words accepted by this automaton in shortlex order, by length and then
+    /// lexicographically in the declared order of Σ, ending once every word of a
+    /// finite language has been yielded
+    ///
+    /// With an interval alphabet each class is expanded into its symbols, in
+    /// ascending order
+    pub fn words(&self) -> impl Iterator<Item=Vec<A>> {
+        self.words_bounded(None)
+    }
+
+    /// Words accepted by this automaton of at most `len` symbols, in shortlex
+    /// order, see [`DFA::words`]
+    pub fn words_up_to(&self, len: usize) -> impl Iterator<Item=Vec<A>> {
+        self.words_bounded(Some(len))
+    }
+
+    fn words_bounded(&self, len: Option<usize>) -> Words<A> {
+        let table = self.table();
+        let delta = table.delta.iter().map(|transitions| transitions.iter().map(|next| vec![*next]).collect()).collect();
+        let finals = (0..table.states.len()).map(|state| table.is_final(state)).collect();
+
+        Words::new(table.symbols.to_vec(), table.intervals.cloned(), delta, finals, table.initial, len)
+    }
+}
+
+impl<A: Clone + Eq + Hash, S: Clone + Eq + Hash> NFA<A, S> {
+    /// Words accepted by this automaton in shortlex order, by length and then
+    /// lexicographically in the declared order of Σ, each once however many runs
+    /// accept it, ending once every word of a finite language has been yielded
+    pub fn words(&self) -> impl Iterator<Item=Vec<A>> {
+        self.words_bounded(None)
+    }
+
+    /// Words accepted by this automaton of at most `len` symbols, in shortlex
+    /// order, see [`NFA::words`]
+    pub fn words_up_to(&self, len: usize) -> impl Iterator<Item=Vec<A>> {
+        self.words_bounded(Some(len))
+    }
+
+    fn words_bounded(&self, len: Option<usize>) -> Words<A> {
+        let table = self.table();
+        let finals = (0..table.states.len()).map(|state| table.is_final(state)).collect();
+
+        Words::new(table.symbols.to_vec(), None, table.delta, finals, table.initial, len)
+    }
+}
+
+// words of each length in turn, by depth first search over the sets of states
+// reached, in the order of the symbols, only entering sets from which a final
+// state is reachable in exactly the symbols left
+struct Words<A> {
+    symbols: Vec<A>,
+    // classes the symbols stand for with an interval alphabet
+    intervals: Option<Intervals<A>>,
+    delta: Vec<Vec<Vec<usize>>>,
+    // states reaching a final state in exactly k symbols, by k
+    reach: Vec<Vec<bool>>,
+    initial: usize,
+    // longest words to yield, bounded by the language when it is finite
+    longest: Option<usize>,
+    // length of the words being searched, and of the next ones
+    len: usize,
+    next_len: usize,
+    // sets of states along the current word, with their next symbol and the
+    // number of inputs of its class already taken
+    stack: Vec<(Vec<usize>, usize, u128)>,
+    word: Vec<A>,
+}
+
+impl<A> Words<A> {
+    fn new(
+        symbols: Vec<A>, intervals: Option<Intervals<A>>, delta: Vec<Vec<Vec<usize>>>, finals: Vec<bool>, initial: usize,
+        len: Option<usize>,
+    ) -> Self {
+        let longest = match (longest_word(&delta, &finals, initial), len) {
+            (Some(longest), Some(len)) => Some(longest.min(len)),
+            (longest, len) => longest.or(len),
+        };
+
+        Self {
+            symbols,
+            intervals,
+            delta,
+            reach: vec![finals],
+            initial,
+            longest,
+            len: 0,
+            next_len: 0,
+            stack: Vec::new(),
+            word: Vec::new(),
+        }
+    }
+
+    fn reaches(&mut self, state: usize, len: usize) -> bool {
+        while self.reach.len() <= len {
+            let previous = &self.reach[self.reach.len() - 1];
+
+            let reach = self.delta.iter()
+                .map(|transitions| transitions.iter().flatten().any(|next| previous[*next]))
+                .collect();
+
+            self.reach.push(reach);
+        }
+
+        self.reach[len][state]
+    }
+}
+
+impl<A: Clone> Iterator for Words<A> {
+    type Item = Vec<A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some((states, sym, taken)) = self.stack.last_mut() else {
+                if self.longest.is_some_and(|longest| self.next_len > longest) {
+                    return None;
+                }
+
+                self.len = self.next_len;
+                self.next_len += 1;
+
+                if self.reaches(self.initial, self.len) {
+                    self.stack.push((vec![self.initial], 0, 0));
+                }
+
+                continue;
+            };
+
+            let left = self.len - self.word.len();
+
+            if left == 0 {
+                let word = self.word.clone();
+
+                self.stack.pop();
+                self.word.pop();
+
+                return Some(word);
+            } else if *sym == self.symbols.len() {
+                self.stack.pop();
+                self.word.pop();
+
+                continue;
+            }
+
+            let (current, input) = (*sym, *taken);
+
+            let mut next = states.iter().flat_map(|state| self.delta[*state][current].iter().copied()).collect::<Vec<_>>();
+
+            next.sort_unstable();
+            next.dedup();
+
+            let leads = next.iter().any(|state| self.reaches(*state, left - 1));
+            let (_, sym, taken) = self.stack.last_mut().expect(UNREACHABLE_ERR);
+
+            // the next input of the class, unless none of them leads anywhere
+            if leads && self.intervals.as_ref().is_some_and(|intervals| input + 1 < intervals.size(current)) {
+                *taken += 1;
+            } else {
+                *sym += 1;
+                *taken = 0;
+            }
+
+            if leads {
+                self.word.push(self.intervals.as_ref().map_or_else(
+                    || self.symbols[current].clone(),
+                    |intervals| intervals.nth(current, input).expect(UNREACHABLE_ERR),
+                ));
+                self.stack.push((next, 0, 0));
+            }
+        }
+    }
+}
+
+// length of the longest accepted word, `None` if the language is infinite, a
+// cycle through states both reachable and reaching a final state, and 0 if it
+// is empty
+fn longest_word(delta: &[Vec<Vec<usize>>], finals: &[bool], initial: usize) -> Option<usize> {
+    let successors = delta.iter()
+        .map(|transitions| {
+            let mut next = transitions.iter().flatten().copied().collect::<Vec<_>>();
+
+            next.sort_unstable();
+            next.dedup();
+            next
+        })
+        .collect::<Vec<_>>();
+
+    let mut predecessors = vec![Vec::new(); delta.len()];
+
+    for (state, next) in successors.iter().enumerate() {
+        for next in next {
+            predecessors[*next].push(state);
+        }
+    }
+
+    let reachable = search(&successors, [initial]);
+    let reaching = search(&predecessors, finals.iter().enumerate().filter(|(_, r#final)| **r#final).map(|(state, _)| state));
+    let useful = reachable.iter().zip(&reaching).map(|(lhs, rhs)| *lhs && *rhs).collect::<Vec<_>>();
+
+    // longest path to each useful state, taking them in topological order
+    let mut incoming = vec![0; delta.len()];
+
+    for (_, next) in successors.iter().enumerate().filter(|(state, _)| useful[*state]) {
+        for next in next.iter().filter(|next| useful[**next]) {
+            incoming[*next] += 1;
+        }
+    }
+
+    let mut queue = (0..delta.len()).filter(|state| useful[*state] && incoming[*state] == 0).collect::<VecDeque<_>>();
+    let mut depth = vec![0; delta.len()];
+    let mut visited = 0;
+    let mut longest = 0;
+
+    while let Some(state) = queue.pop_front() {
+        visited += 1;
+
+        if finals[state] {
+            longest = longest.max(depth[state]);
+        }
+
+        for next in successors[state].iter().filter(|next| useful[**next]) {
+            depth[*next] = depth[*next].max(depth[state] + 1);
+            incoming[*next] -= 1;
+
+            if incoming[*next] == 0 {
+                queue.push_back(*next);
+            }
+        }
+    }
+
+    (visited == useful.iter().filter(|useful| **useful).count()).then_some(longest)
+}
+
+fn search(edges: &[Vec<usize>], start: impl IntoIterator<Item=usize>) -> Vec<bool> {
+    let mut visited = vec![false; edges.len()];
+    let mut queue = start.into_iter().collect::<VecDeque<_>>();
+
+    for state in &queue {
+        visited[*state] = true;
+    }
+
+    while let Some(state) = queue.pop_front() {
+        for next in &edges[state] {
+            if !visited[*next] {
+                visited[*next] = true;
+                queue.push_back(*next);
+            }
+        }
+    }
+
+    visited
+}
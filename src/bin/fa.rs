@@ -0,0 +1,289 @@
+//! `fa`, command line tool for working with finite automata definition files
+//!
+//! ```text
+//! fa check <file>                 validate a definition
+//! fa run <file> [symbols...]      feed input and print the trace and acceptance
+//! fa determinize <file>           equivalent dfa, using the subset construction
+//! fa minimize <file>              equivalent dfa with the minimum number of states
+//! fa dot <file>                   graphviz dot representation
+//! fa equiv <file> <file>          are both automata equivalent
+//! fa stats <file>                 statistics of an automaton
+//! ```
+//!
+//! Definitions are read in either the text definition format, see `formats::text`,
+//! the JSON definition format, see `formats::json`, recognized by a leading `{`,
+//! the JFLAP format, see `formats::jflap`, recognized by a leading `<`, or the
+//! HOA format, see `formats::hoa`, recognized by a leading `HOA:`,
+//! `-` reads a definition from stdin, `determinize` and `minimize` write their
+//! result, in the format of their input, to stdout, or to a file given with `-o <file>`
+//!
+//! Input symbols given to `run` are separate arguments, an argument which is not
+//! a symbol of the alphabet is split into its characters, i.e. `fa run m.json 0110`
+//!
+//! Büchi automata, accepting infinite words, are supported by `check`, `dot` and `stats`
+
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::nursery)]
+
+use std::{env, fs};
+use std::collections::HashSet;
+use std::io::{self, Read};
+use std::process::ExitCode;
+use std::sync::OnceLock;
+
+use finite_state_machine::automata::{DFA, NFA};
+use finite_state_machine::formats::{Definition, Kind};
+use finite_state_machine::model::State;
+
+const USAGE: &str = "\
+usage: fa <command> [arguments]
+
+commands:
+    check <file>                 validate a definition
+    run <file> [symbols...]      feed input and print the trace and acceptance
+    determinize <file> [-o out]  equivalent dfa, using the subset construction
+    minimize <file> [-o out]     equivalent dfa with the minimum number of states
+    dot <file>                   graphviz dot representation
+    equiv <file> <file>          are both automata equivalent
+    stats <file>                 statistics of an automaton
+
+definitions are text, json, jflap or hoa files, use - to read from stdin";
+
+const ERR_BUCHI: &str = "Büchi automata are not supported by this command";
+
+enum Format {
+    Hoa,
+    Jflap,
+    Json,
+    Text,
+}
+
+impl Format {
+    fn of(source: &str) -> Self {
+        if source.trim_start().starts_with("HOA:") {
+            return Self::Hoa;
+        }
+
+        match source.trim_start().chars().next() {
+            Some('<') => Self::Jflap,
+            Some('{') => Self::Json,
+            _ => Self::Text
+        }
+    }
+
+    fn write(&self, definition: &Definition) -> Result<String, String> {
+        match self {
+            Self::Hoa => definition.to_hoa().map_err(String::from),
+            Self::Jflap => Ok(definition.to_jflap()),
+            Self::Json => Ok(definition.to_json()),
+            Self::Text => Ok(definition.to_text()),
+        }
+    }
+}
+
+enum Machine {
+    Dfa(DFA<String, String>),
+    Nfa(NFA<String, String>),
+}
+
+impl Machine {
+    fn into_dfa(self) -> DFA<String, String> {
+        match self {
+            Self::Dfa(dfa) => dfa,
+            Self::Nfa(nfa) => nfa.determinize(),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    match execute(&args) {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("fa: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn execute(args: &[String]) -> Result<ExitCode, String> {
+    let (command, args) = args.split_first().ok_or_else(|| USAGE.to_string())?;
+
+    match (command.as_str(), args) {
+        ("check", [file]) if read(file)?.kind == Kind::Buchi => {
+            read(file)?.to_buchi().map_err(|err| format!("{file}: {err}"))?;
+
+            println!("{file}: valid buchi");
+        }
+        ("check", [file]) => {
+            let machine = load(file)?;
+            let kind = match machine {
+                Machine::Dfa(_) => "dfa",
+                Machine::Nfa(_) => "nfa",
+            };
+
+            println!("{file}: valid {kind}");
+        }
+        ("run", [file, inputs @ ..]) => return run(load(file)?, inputs),
+        ("determinize" | "minimize", [file, output @ ..]) => {
+            let format = Format::of(&source(file)?);
+            let dfa = load(file)?.into_dfa();
+            let dfa = if command == "minimize" { dfa.minimize() } else { dfa };
+
+            write(output, &format.write(&Definition::from_dfa(&dfa))?)?;
+        }
+        ("dot", [file]) => print!("{}", read(file)?.to_dot()),
+        ("equiv", [lhs, rhs]) => {
+            let lhs = load(lhs)?.into_dfa();
+            let rhs = load(rhs)?.into_dfa();
+
+            return Ok(lhs.distinguishing_word(&rhs).map_or_else(
+                || {
+                    println!("equivalent");
+                    ExitCode::SUCCESS
+                },
+                |word| {
+                    println!("not equivalent, distinguished by \"{}\"", word.join(" "));
+                    ExitCode::FAILURE
+                },
+            ));
+        }
+        ("stats", [file]) => stats(&read(file)?)?,
+        ("help" | "--help" | "-h", []) => println!("{USAGE}"),
+        _ => return Err(USAGE.to_string())
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn load(file: &str) -> Result<Machine, String> {
+    let definition = read(file)?;
+
+    match definition.kind {
+        Kind::Dfa => definition.to_dfa().map(Machine::Dfa),
+        Kind::Nfa => definition.to_nfa().map(Machine::Nfa),
+        Kind::Buchi => Err(ERR_BUCHI),
+    }.map_err(|err| format!("{file}: {err}"))
+}
+
+fn read(file: &str) -> Result<Definition, String> {
+    let source = source(file)?;
+
+    match Format::of(&source) {
+        Format::Hoa => Definition::from_hoa(&source),
+        Format::Jflap => Definition::from_jflap(&source),
+        Format::Json => Definition::from_json(&source),
+        Format::Text => Definition::from_text(&source),
+    }.map_err(|err| format!("{file}:{err}"))
+}
+
+// stdin can only be read once, its contents are kept for subsequent reads
+fn source(file: &str) -> Result<String, String> {
+    static STDIN: OnceLock<Result<String, String>> = OnceLock::new();
+
+    if file == "-" {
+        STDIN.get_or_init(|| {
+            let mut source = String::new();
+
+            io::stdin().read_to_string(&mut source).map(|_| source).map_err(|err| format!("stdin: {err}"))
+        }).clone()
+    } else {
+        fs::read_to_string(file).map_err(|err| format!("{file}: {err}"))
+    }
+}
+
+fn run(machine: Machine, inputs: &[String]) -> Result<ExitCode, String> {
+    let alphabet = match &machine {
+        Machine::Dfa(dfa) => dfa.alphabet().to_vec(),
+        Machine::Nfa(nfa) => nfa.alphabet().to_vec(),
+    };
+
+    let inputs = inputs.iter()
+        .flat_map(|input| if alphabet.contains(input) {
+            vec![input.clone()]
+        } else {
+            input.chars().map(String::from).collect()
+        })
+        .collect::<Vec<_>>();
+
+    let accepted = match machine {
+        Machine::Dfa(mut dfa) => {
+            let explanation = dfa.explain(&inputs);
+            let trace = dfa.trace(&inputs).map_err(|_| explanation.to_string())?;
+
+            println!("{trace}");
+
+            if !trace.accepted() {
+                println!("{explanation}");
+            }
+
+            trace.accepted()
+        }
+        Machine::Nfa(mut nfa) => {
+            let trace = nfa.trace(&inputs).map_err(|err| format!("{}: {err}", inputs.join(" ")))?;
+
+            println!("{trace}");
+
+            if let Some(witness) = trace.witness() {
+                println!("witness: {}", witness.iter().map(ToString::to_string).collect::<Vec<_>>().join(" "));
+            }
+
+            trace.accepted()
+        }
+    };
+
+    println!("{}", if accepted { "accepted" } else { "rejected" });
+
+    Ok(if accepted { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+}
+
+fn stats(definition: &Definition) -> Result<(), String> {
+    // Büchi automata accept infinite words, their finite word constructions do not apply
+    let (buchi, nfa) = if definition.kind == Kind::Buchi {
+        (Some(definition.to_buchi()?), None)
+    } else {
+        (None, Some(definition.to_nfa()?))
+    };
+
+    let deterministic = definition.states.iter().all(|state|
+        definition.alphabet.iter().all(|sym|
+            definition.transitions.iter().filter(|(from, on, _)| from == state && on == sym).count() == 1
+        )
+    );
+
+    println!("kind: {:?}", definition.kind);
+    println!("alphabet: {}", definition.alphabet.len());
+    println!("states: {}", definition.states.len());
+    println!("final states: {}", definition.finals.len());
+    println!("transitions: {}", definition.transitions.len());
+    println!("deterministic: {deterministic}");
+
+    if let Some(buchi) = buchi {
+        println!("empty language: {}", buchi.is_empty());
+    }
+
+    if let Some(nfa) = nfa {
+        let dfa = nfa.determinize();
+        let minimal = dfa.minimize();
+
+        println!("reachable states: {}", dfa.states().flat_map(|state| state.iter()).collect::<HashSet<_>>().len());
+        println!("determinized states: {}", dfa.states().count());
+        println!("minimal states: {}", minimal.states().count());
+        println!("empty language: {}", !minimal.states().any(State::is_final));
+    }
+
+    Ok(())
+}
+
+fn write(output: &[String], contents: &str) -> Result<(), String> {
+    match output {
+        [] => {
+            print!("{contents}");
+            Ok(())
+        }
+        [flag, file] if flag == "-o" => fs::write(file, contents).map_err(|err| format!("{file}: {err}")),
+        _ => Err(USAGE.to_string())
+    }
+}
@@ -0,0 +1,49 @@
+//! Graphviz DOT export
+
+use std::fmt::Write;
+
+use crate::formats::{Definition, Kind};
+
+impl Definition {
+    /// Graphviz DOT representation of this definition, transitions between the
+    /// same states are combined into a single edge labelled with all their symbols
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let name = match self.kind {
+            Kind::Dfa => "DFA",
+            Kind::Nfa => "NFA",
+            Kind::Buchi => "Buchi"
+        };
+
+        let mut dot = format!("digraph {name} {{\n    rankdir = LR;\n    node [shape = circle];\n");
+
+        let _ = writeln!(dot, "    \"\" [shape = none, label = \"\"];");
+
+        for state in &self.finals {
+            let _ = writeln!(dot, "    {} [shape = doublecircle];", quote(state));
+        }
+
+        let _ = writeln!(dot, "    \"\" -> {};", quote(&self.initial));
+
+        let mut edges: Vec<(&str, &str, Vec<&str>)> = Vec::new();
+
+        for (from, sym, to) in &self.transitions {
+            match edges.iter_mut().find(|(edge_from, edge_to, _)| edge_from == from && edge_to == to) {
+                Some((_, _, symbols)) => symbols.push(sym),
+                None => edges.push((from, to, vec![sym]))
+            }
+        }
+
+        for (from, to, symbols) in edges {
+            let _ = writeln!(dot, "    {} -> {} [label = {}];", quote(from), quote(to), quote(&symbols.join(", ")));
+        }
+
+        dot.push_str("}\n");
+
+        dot
+    }
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
@@ -0,0 +1,619 @@
+//! Hanoi Omega-Automata, HOA, format for Büchi automata
+//!
+//! Automata with state based Büchi acceptance, `Acceptance: 1 Inf(0)`, are read,
+//! states in acceptance set 0 being final, as well as `Acceptance: 0 t`, all states
+//! being final. Edges are labelled with boolean expressions over the atomic
+//! propositions, `AP`, each valuation of the propositions being a symbol, named by
+//! its true propositions joined by `&`, or `{}` if none is true
+//!
+//! Written automata have one proposition per symbol, a symbol being the valuation
+//! in which only its proposition is true, so symbols are read back by their name
+
+use std::fmt::Write;
+
+use crate::formats::{Definition, Kind, ParseError};
+
+pub const ERR_ABORTED: &str = "Automaton is aborted by --ABORT--";
+pub const ERR_ALIAS: &str = "Aliases are not supported";
+pub const ERR_ALTERNATION: &str = "Conjunctions of states, alternating automata, are not supported";
+pub const ERR_DUPLICATE_STATE: &str = "State is defined more than once";
+pub const ERR_EXPECTED_HOA: &str = "Automaton must start with the header HOA: v1";
+pub const ERR_EXPECTED_INTEGER: &str = "Expected an integer";
+pub const ERR_EXPECTED_STRING: &str = "Expected a string";
+pub const ERR_IMPLICIT_LABEL: &str = "Edges without a state label must have an explicit label";
+pub const ERR_MISSING_INITIAL_STATE: &str = "Automaton does not define an initial state";
+pub const ERR_MULTIPLE_INITIAL_STATES: &str = "Automaton defines more than one initial state";
+pub const ERR_NOT_BUCHI: &str = "Acceptance condition must be Büchi, 1 Inf(0), or 0 t";
+pub const ERR_TOO_MANY_PROPOSITIONS: &str = "Automaton has more atomic propositions than supported";
+pub const ERR_TOO_MANY_STATES: &str = "Automaton has more states than supported";
+pub const ERR_TRANSITION_ACCEPTANCE: &str = "Transition based acceptance is not supported";
+pub const ERR_UNDEFINED_PROPOSITION: &str = "Atomic proposition is not declared in AP";
+pub const ERR_UNDEFINED_STATE: &str = "State is not defined in States";
+pub const ERR_UNEXPECTED_CHARACTER: &str = "Unexpected character";
+pub const ERR_UNEXPECTED_END: &str = "Unexpected end of input";
+pub const ERR_UNEXPECTED_TOKEN: &str = "Unexpected token";
+
+// symbols are all valuations of the propositions, bounding their number
+const MAX_PROPOSITIONS: usize = 12;
+
+// states are indexed by their number, bounding it, declared or not
+const MAX_STATES: usize = 1 << 16;
+
+impl Definition {
+    /// Reads a [`Kind::Buchi`] definition from a HOA automaton
+    ///
+    /// # Errors
+    /// [`ParseError`] with the position of malformed HOA, or of a feature other
+    /// than a state based Büchi automaton with explicit labels
+    #[allow(clippy::too_many_lines)]
+    pub fn from_hoa(source: &str) -> Result<Self, ParseError> {
+        let mut parser = Parser { lexemes: tokenize(source)?, position: 0 };
+        let hoa = parser.next()?;
+
+        if !matches!(hoa.token, Token::Header(ref name) if name == "HOA")
+            || !matches!(parser.next()?.token, Token::Identifier(ref version) if version == "v1") {
+            return Err(hoa.error(ERR_EXPECTED_HOA));
+        }
+
+        let mut count = None;
+        let mut start = None;
+        let mut propositions = Vec::new();
+        let mut acceptance = None;
+
+        loop {
+            let lexeme = parser.next()?;
+
+            match &lexeme.token {
+                Token::Body => break,
+                Token::Header(name) => match name.as_str() {
+                    "States" => {
+                        let size = parser.integer()?;
+
+                        if size > MAX_STATES {
+                            return Err(lexeme.error(ERR_TOO_MANY_STATES));
+                        }
+
+                        count = Some(size);
+                    }
+                    "Start" => {
+                        let state = parser.integer()?;
+
+                        if parser.peek_is('&') {
+                            return Err(lexeme.error(ERR_ALTERNATION));
+                        } else if start.replace((state, lexeme.clone())).is_some() {
+                            return Err(lexeme.error(ERR_MULTIPLE_INITIAL_STATES));
+                        }
+                    }
+                    "AP" => {
+                        let size = parser.integer()?;
+
+                        if size > MAX_PROPOSITIONS {
+                            return Err(lexeme.error(ERR_TOO_MANY_PROPOSITIONS));
+                        }
+
+                        propositions = (0..size).map(|_| parser.string()).collect::<Result<_, _>>()?;
+                    }
+                    "Acceptance" => {
+                        let condition = parser.values().iter().map(|lexeme| lexeme.token.to_string()).collect::<Vec<_>>();
+
+                        acceptance = Some(match condition.join(" ").as_str() {
+                            "1 Inf ( 0 )" => true,
+                            "0 t" => false,
+                            _ => return Err(lexeme.error(ERR_NOT_BUCHI))
+                        });
+                    }
+                    "Alias" => return Err(lexeme.error(ERR_ALIAS)),
+                    _ => {
+                        parser.values();
+                    }
+                },
+                _ => return Err(lexeme.error(ERR_UNEXPECTED_TOKEN))
+            }
+        }
+
+        let inf = acceptance.ok_or_else(|| hoa.error(ERR_NOT_BUCHI))?;
+        let (start, start_lexeme) = start.ok_or_else(|| hoa.error(ERR_MISSING_INITIAL_STATE))?;
+
+        // index error of a state beyond the declared states, or any supported number of them
+        let beyond = |index: usize| count.map_or_else(
+            || (index >= MAX_STATES).then_some(ERR_TOO_MANY_STATES),
+            |count| (index >= count).then_some(ERR_UNDEFINED_STATE),
+        );
+
+        if let Some(message) = beyond(start) {
+            return Err(start_lexeme.error(message));
+        }
+
+        // states as (name, final), edges as (from, label, to) with the position of their target
+        let mut states: Vec<Option<(String, bool, Lexeme)>> = Vec::new();
+        let mut edges = Vec::new();
+        let mut current: Option<(usize, Option<Label>)> = None;
+
+        loop {
+            let lexeme = parser.next()?;
+
+            match &lexeme.token {
+                Token::End => break,
+                Token::Abort => return Err(lexeme.error(ERR_ABORTED)),
+                Token::Header(name) if name == "State" => {
+                    let label = if parser.peek_is('[') {
+                        parser.next()?;
+
+                        Some(parser.label(propositions.len())?)
+                    } else {
+                        None
+                    };
+
+                    let index = parser.integer()?;
+                    let name = if matches!(parser.peek(), Some(Lexeme { token: Token::String(_), .. })) {
+                        parser.string()?
+                    } else {
+                        index.to_string()
+                    };
+
+                    let accepting = if parser.peek_is('{') {
+                        parser.next()?;
+                        parser.sets()?.contains(&0)
+                    } else {
+                        false
+                    };
+
+                    if let Some(message) = beyond(index) {
+                        return Err(lexeme.error(message));
+                    } else if states.len() <= index {
+                        states.resize(index + 1, None);
+                    }
+
+                    if states[index].replace((name, accepting || !inf, lexeme.clone())).is_some() {
+                        return Err(lexeme.error(ERR_DUPLICATE_STATE));
+                    }
+
+                    current = Some((index, label));
+                }
+                Token::Punctuation('[') | Token::Integer(_) => {
+                    let (from, state_label) = current.as_ref().ok_or_else(|| lexeme.error(ERR_UNEXPECTED_TOKEN))?;
+
+                    let (label, to) = if let Token::Integer(to) = lexeme.token {
+                        (state_label.clone().ok_or_else(|| lexeme.error(ERR_IMPLICIT_LABEL))?, (to, lexeme.clone()))
+                    } else {
+                        let label = parser.label(propositions.len())?;
+                        let target = parser.peek().cloned();
+
+                        (label, (parser.integer()?, target.ok_or_else(|| lexeme.error(ERR_UNEXPECTED_END))?))
+                    };
+
+                    if parser.peek_is('&') {
+                        return Err(lexeme.error(ERR_ALTERNATION));
+                    } else if parser.peek_is('{') {
+                        return Err(lexeme.error(ERR_TRANSITION_ACCEPTANCE));
+                    }
+
+                    edges.push((*from, label, to));
+                }
+                _ => return Err(lexeme.error(ERR_UNEXPECTED_TOKEN))
+            }
+        }
+
+        let count = count.unwrap_or_else(|| states.len().max(start + 1));
+
+        if let Some((_, _, (_, lexeme))) = edges.iter().find(|(_, _, (to, _))| *to >= count) {
+            return Err(lexeme.error(ERR_UNDEFINED_STATE));
+        }
+
+        states.resize(count, None);
+
+        let mut names: Vec<String> = Vec::with_capacity(count);
+        let mut finals = Vec::new();
+
+        for (index, state) in states.into_iter().enumerate() {
+            let (name, accepting) = match state {
+                Some((name, _, lexeme)) if names.contains(&name) => return Err(lexeme.error(ERR_DUPLICATE_STATE)),
+                Some((name, accepting, _)) => (name, accepting),
+                None => (index.to_string(), !inf)
+            };
+
+            if accepting {
+                finals.push(name.clone());
+            }
+
+            names.push(name);
+        }
+
+        let valuations = 1_usize << propositions.len();
+        let symbol = |valuation: usize| {
+            let name = propositions.iter()
+                .enumerate()
+                .filter(|(idx, _)| valuation & (1 << idx) != 0)
+                .map(|(_, proposition)| proposition.as_str())
+                .collect::<Vec<_>>()
+                .join("&");
+
+            if name.is_empty() { String::from("{}") } else { name }
+        };
+
+        let mut used = vec![false; valuations];
+        let mut transitions = Vec::new();
+
+        for (from, label, (to, _)) in &edges {
+            for valuation in (0..valuations).filter(|valuation| label.holds(*valuation)) {
+                let transition = (names[*from].clone(), symbol(valuation), names[*to].clone());
+
+                used[valuation] = true;
+
+                if !transitions.contains(&transition) {
+                    transitions.push(transition);
+                }
+            }
+        }
+
+        Ok(Self {
+            kind: Kind::Buchi,
+            alphabet: (0..valuations).filter(|valuation| used[*valuation]).map(symbol).collect(),
+            initial: names[start].clone(),
+            states: names,
+            finals,
+            transitions,
+        })
+    }
+
+    /// HOA representation of this definition, as a state based Büchi automaton
+    /// regardless of its kind, with one atomic proposition per symbol
+    ///
+    /// # Errors
+    /// an alphabet with more symbols than the atomic propositions [`Definition::from_hoa`] reads
+    pub fn to_hoa(&self) -> Result<String, &'static str> {
+        if self.alphabet.len() > MAX_PROPOSITIONS {
+            return Err(ERR_TOO_MANY_PROPOSITIONS);
+        }
+
+        let id = |name: &String| self.states.iter().position(|state| state == name).unwrap_or(usize::MAX);
+        let mut hoa = String::from("HOA: v1\n");
+
+        let _ = writeln!(hoa, "States: {}", self.states.len());
+        let _ = writeln!(hoa, "Start: {}", id(&self.initial));
+        let _ = write!(hoa, "AP: {}", self.alphabet.len());
+
+        for sym in &self.alphabet {
+            let _ = write!(hoa, " {}", quote(sym));
+        }
+
+        hoa.push_str("\nacc-name: Buchi\nAcceptance: 1 Inf(0)\nproperties: explicit-labels state-acc\n--BODY--\n");
+
+        let label = |sym: &String| {
+            let proposition = self.alphabet.iter().position(|symbol| symbol == sym).unwrap_or(usize::MAX);
+
+            (0..self.alphabet.len())
+                .map(|idx| if idx == proposition { idx.to_string() } else { format!("!{idx}") })
+                .collect::<Vec<_>>()
+                .join("&")
+        };
+
+        for (idx, state) in self.states.iter().enumerate() {
+            let _ = writeln!(
+                hoa,
+                "State: {idx} {}{}",
+                quote(state),
+                if self.finals.contains(state) { " {0}" } else { "" }
+            );
+
+            for (_, sym, to) in self.transitions.iter().filter(|(from, _, _)| from == state) {
+                let _ = writeln!(hoa, "[{}] {}", label(sym), id(to));
+            }
+        }
+
+        hoa.push_str("--END--\n");
+
+        Ok(hoa)
+    }
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+// boolean expression over atomic propositions, by index
+#[derive(Clone)]
+enum Label {
+    Constant(bool),
+    Proposition(usize),
+    Not(Box<Self>),
+    And(Box<Self>, Box<Self>),
+    Or(Box<Self>, Box<Self>),
+}
+
+impl Label {
+    fn holds(&self, valuation: usize) -> bool {
+        match self {
+            Self::Constant(value) => *value,
+            Self::Proposition(idx) => valuation & (1 << idx) != 0,
+            Self::Not(label) => !label.holds(valuation),
+            Self::And(lhs, rhs) => lhs.holds(valuation) && rhs.holds(valuation),
+            Self::Or(lhs, rhs) => lhs.holds(valuation) || rhs.holds(valuation),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Token {
+    Abort,
+    Alias(String),
+    Body,
+    End,
+    Header(String),
+    Identifier(String),
+    Integer(usize),
+    Punctuation(char),
+    String(String),
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Abort => fmt.write_str("--ABORT--"),
+            Self::Alias(name) => write!(fmt, "@{name}"),
+            Self::Body => fmt.write_str("--BODY--"),
+            Self::End => fmt.write_str("--END--"),
+            Self::Header(name) => write!(fmt, "{name}:"),
+            Self::Identifier(name) => fmt.write_str(name),
+            Self::Integer(value) => write!(fmt, "{value}"),
+            Self::Punctuation(chr) => write!(fmt, "{chr}"),
+            Self::String(value) => fmt.write_str(&quote(value)),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Lexeme {
+    token: Token,
+    line: usize,
+    column: usize,
+}
+
+impl Lexeme {
+    const fn error(&self, message: &'static str) -> ParseError {
+        ParseError { line: self.line, column: self.column, message }
+    }
+}
+
+struct Parser {
+    lexemes: Vec<Lexeme>,
+    position: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Result<Lexeme, ParseError> {
+        let lexeme = self.lexemes.get(self.position).cloned().ok_or_else(|| self.end())?;
+
+        self.position += 1;
+
+        Ok(lexeme)
+    }
+
+    fn peek(&self) -> Option<&Lexeme> {
+        self.lexemes.get(self.position)
+    }
+
+    fn peek_is(&self, expected: char) -> bool {
+        matches!(self.peek(), Some(Lexeme { token: Token::Punctuation(chr), .. }) if *chr == expected)
+    }
+
+    // error at the end of the input, after the last lexeme
+    fn end(&self) -> ParseError {
+        let (line, column) = self.lexemes.last().map_or((1, 1), |lexeme| (lexeme.line, lexeme.column));
+
+        ParseError { line, column, message: ERR_UNEXPECTED_END }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        let lexeme = self.next()?;
+
+        if matches!(lexeme.token, Token::Punctuation(chr) if chr == expected) {
+            Ok(())
+        } else {
+            Err(lexeme.error(ERR_UNEXPECTED_TOKEN))
+        }
+    }
+
+    fn integer(&mut self) -> Result<usize, ParseError> {
+        let lexeme = self.next()?;
+
+        match lexeme.token {
+            Token::Integer(value) => Ok(value),
+            _ => Err(lexeme.error(ERR_EXPECTED_INTEGER))
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        let lexeme = self.next()?;
+
+        match lexeme.token {
+            Token::String(value) => Ok(value),
+            _ => Err(lexeme.error(ERR_EXPECTED_STRING))
+        }
+    }
+
+    // acceptance sets, after the opening brace
+    fn sets(&mut self) -> Result<Vec<usize>, ParseError> {
+        let mut sets = Vec::new();
+
+        while !self.peek_is('}') {
+            sets.push(self.integer()?);
+        }
+
+        self.expect('}')?;
+
+        Ok(sets)
+    }
+
+    // values of a header, up to the next header or the body
+    fn values(&mut self) -> Vec<Lexeme> {
+        let mut values = Vec::new();
+
+        while let Some(lexeme) = self.peek().filter(|lexeme| !matches!(lexeme.token, Token::Header(_) | Token::Body)) {
+            values.push(lexeme.clone());
+            self.position += 1;
+        }
+
+        values
+    }
+
+    // label expression, after the opening bracket, up to and including the closing bracket
+    fn label(&mut self, propositions: usize) -> Result<Label, ParseError> {
+        let label = self.disjunction(propositions)?;
+
+        self.expect(']')?;
+
+        Ok(label)
+    }
+
+    fn disjunction(&mut self, propositions: usize) -> Result<Label, ParseError> {
+        let mut label = self.conjunction(propositions)?;
+
+        while self.peek_is('|') {
+            self.next()?;
+            label = Label::Or(Box::new(label), Box::new(self.conjunction(propositions)?));
+        }
+
+        Ok(label)
+    }
+
+    fn conjunction(&mut self, propositions: usize) -> Result<Label, ParseError> {
+        let mut label = self.negation(propositions)?;
+
+        while self.peek_is('&') {
+            self.next()?;
+            label = Label::And(Box::new(label), Box::new(self.negation(propositions)?));
+        }
+
+        Ok(label)
+    }
+
+    fn negation(&mut self, propositions: usize) -> Result<Label, ParseError> {
+        let lexeme = self.next()?;
+
+        match lexeme.token {
+            Token::Punctuation('!') => Ok(Label::Not(Box::new(self.negation(propositions)?))),
+            Token::Punctuation('(') => {
+                let label = self.disjunction(propositions)?;
+
+                self.expect(')')?;
+
+                Ok(label)
+            }
+            Token::Identifier(ref name) if name == "t" => Ok(Label::Constant(true)),
+            Token::Identifier(ref name) if name == "f" => Ok(Label::Constant(false)),
+            Token::Integer(idx) if idx < propositions => Ok(Label::Proposition(idx)),
+            Token::Integer(_) => Err(lexeme.error(ERR_UNDEFINED_PROPOSITION)),
+            Token::Alias(_) => Err(lexeme.error(ERR_ALIAS)),
+            _ => Err(lexeme.error(ERR_UNEXPECTED_TOKEN))
+        }
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Lexeme>, ParseError> {
+    let chars = source.chars().collect::<Vec<_>>();
+    let mut lexemes = Vec::new();
+    let (mut position, mut line, mut column) = (0, 1, 1);
+
+    let advance = |position: &mut usize, line: &mut usize, column: &mut usize| {
+        if chars[*position] == '\n' {
+            *line += 1;
+            *column = 1;
+        } else {
+            *column += 1;
+        }
+
+        *position += 1;
+    };
+
+    while position < chars.len() {
+        let chr = chars[position];
+        let (start_line, start_column) = (line, column);
+        let error = |message| ParseError { line: start_line, column: start_column, message };
+
+        if chr.is_whitespace() {
+            advance(&mut position, &mut line, &mut column);
+            continue;
+        }
+
+        let token = if chars[position..].starts_with(&['/', '*']) {
+            while !chars[position..].starts_with(&['*', '/']) {
+                if position + 1 >= chars.len() {
+                    return Err(error(ERR_UNEXPECTED_END));
+                }
+
+                advance(&mut position, &mut line, &mut column);
+            }
+
+            advance(&mut position, &mut line, &mut column);
+            advance(&mut position, &mut line, &mut column);
+
+            continue;
+        } else if chr == '"' {
+            let mut value = String::new();
+
+            advance(&mut position, &mut line, &mut column);
+
+            loop {
+                match chars.get(position) {
+                    None => return Err(error(ERR_UNEXPECTED_END)),
+                    Some('"') => break,
+                    Some('\\') if position + 1 < chars.len() => {
+                        advance(&mut position, &mut line, &mut column);
+                        value.push(chars[position]);
+                    }
+                    Some(chr) => value.push(*chr),
+                }
+
+                advance(&mut position, &mut line, &mut column);
+            }
+
+            advance(&mut position, &mut line, &mut column);
+
+            Token::String(value)
+        } else if chr == '-' {
+            let marker = [("--BODY--", Token::Body), ("--END--", Token::End), ("--ABORT--", Token::Abort)]
+                .into_iter()
+                .find(|(marker, _)| chars[position..].starts_with(&marker.chars().collect::<Vec<_>>()))
+                .ok_or_else(|| error(ERR_UNEXPECTED_CHARACTER))?;
+
+            for _ in 0..marker.0.len() {
+                advance(&mut position, &mut line, &mut column);
+            }
+
+            marker.1
+        } else if chr.is_ascii_digit() || chr.is_alphabetic() || chr == '_' || chr == '@' {
+            let start = position;
+
+            advance(&mut position, &mut line, &mut column);
+
+            while chars.get(position).is_some_and(|chr| chr.is_alphanumeric() || *chr == '_' || *chr == '-') {
+                advance(&mut position, &mut line, &mut column);
+            }
+
+            let word = chars[start..position].iter().collect::<String>();
+
+            if chr == '@' {
+                Token::Alias(word[1..].to_string())
+            } else if chr.is_ascii_digit() {
+                Token::Integer(word.parse().map_err(|_| error(ERR_EXPECTED_INTEGER))?)
+            } else if chars.get(position) == Some(&':') {
+                advance(&mut position, &mut line, &mut column);
+
+                Token::Header(word)
+            } else {
+                Token::Identifier(word)
+            }
+        } else if "[]{}()&|!".contains(chr) {
+            advance(&mut position, &mut line, &mut column);
+
+            Token::Punctuation(chr)
+        } else {
+            return Err(error(ERR_UNEXPECTED_CHARACTER));
+        };
+
+        lexemes.push(Lexeme { token, line: start_line, column: start_column });
+    }
+
+    Ok(lexemes)
+}
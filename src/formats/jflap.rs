@@ -0,0 +1,222 @@
+//! JFLAP finite automaton, `.jff`, format
+//!
+//! States are identified by their `name`, or their `id` if they have no name,
+//! transitions read a symbol, an empty `<read/>` being an empty string, ε,
+//! transition
+//!
+//! Automata in this crate have no ε transitions, they are eliminated on import,
+//! giving an equivalent nondeterministic automaton, a state with an ε path to a
+//! final state becomes final
+//!
+//! States not reachable from the initial state are dropped on import, JFLAP
+//! allows them but automata in this crate do not, as are states only reachable
+//! through ε transitions
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::formats::{Definition, Kind, ParseError};
+use crate::formats::xml::{self, escape};
+
+pub const ERR_DUPLICATE_STATE: &str = "State id or name is defined more than once";
+pub const ERR_MISSING_INITIAL_STATE: &str = "Automaton does not define an initial state";
+pub const ERR_MISSING_STATE_ID: &str = "State does not define an id";
+pub const ERR_MISSING_TRANSITION_STATE: &str = "Transition must define a from and a to state";
+pub const ERR_MULTIPLE_INITIAL_STATES: &str = "Automaton defines more than one initial state";
+pub const ERR_NOT_FINITE_AUTOMATON: &str = "JFLAP structure is not a finite automaton";
+pub const ERR_UNDEFINED_STATE: &str = "Transition state is not defined";
+
+// layout of exported states, JFLAP requires coordinates
+const COLUMNS: usize = 6;
+const SPACING: usize = 120;
+
+impl Definition {
+    /// Reads a definition from a JFLAP finite automaton
+    ///
+    /// The definition is a [`Kind::Dfa`] when the automaton is deterministic and
+    /// complete, without ε transitions, otherwise a [`Kind::Nfa`]
+    ///
+    /// # Errors
+    /// [`ParseError`] with the position of malformed XML or an invalid automaton element
+    pub fn from_jflap(source: &str) -> Result<Self, ParseError> {
+        let structure = xml::parse(source)?;
+
+        if structure.name != "structure" || structure.child("type").is_none_or(|kind| kind.text.trim() != "fa") {
+            return Err(structure.error(ERR_NOT_FINITE_AUTOMATON));
+        }
+
+        // JFLAP 7 nests states and transitions in an automaton element, earlier versions do not
+        let automaton = structure.child("automaton").unwrap_or(&structure);
+
+        let mut ids = Vec::new();
+        let mut states = Vec::new();
+        let mut initial = None;
+        let mut finals = Vec::new();
+
+        for state in automaton.children("state") {
+            let id = state.attribute("id").ok_or_else(|| state.error(ERR_MISSING_STATE_ID))?.trim().to_string();
+            let name = state.attribute("name").map_or_else(|| id.clone(), ToString::to_string);
+
+            if ids.contains(&id) || states.contains(&name) {
+                return Err(state.error(ERR_DUPLICATE_STATE));
+            } else if state.child("initial").is_some() && initial.replace(name.clone()).is_some() {
+                return Err(state.error(ERR_MULTIPLE_INITIAL_STATES));
+            } else if state.child("final").is_some() {
+                finals.push(name.clone());
+            }
+
+            ids.push(id);
+            states.push(name);
+        }
+
+        let initial = initial.ok_or_else(|| automaton.error(ERR_MISSING_INITIAL_STATE))?;
+        let mut alphabet = Vec::new();
+        let mut transitions = Vec::new();
+        let mut empty = Vec::new();
+
+        for transition in automaton.children("transition") {
+            let state = |name| {
+                let id = transition.child(name).ok_or_else(|| transition.error(ERR_MISSING_TRANSITION_STATE))?;
+
+                ids.iter().position(|state| *state == id.text.trim())
+                    .map(|idx| states[idx].clone())
+                    .ok_or_else(|| id.error(ERR_UNDEFINED_STATE))
+            };
+
+            let (from, to) = (state("from")?, state("to")?);
+
+            match transition.child("read").map(|read| read.text.as_str()).filter(|read| !read.is_empty()) {
+                Some(read) => {
+                    if !alphabet.iter().any(|sym| sym == read) {
+                        alphabet.push(read.to_string());
+                    }
+
+                    transitions.push((from, read.to_string(), to));
+                }
+                None => empty.push((from, to))
+            }
+        }
+
+        let mut definition = Self { kind: Kind::Nfa, alphabet, states, initial, finals, transitions };
+
+        if !empty.is_empty() {
+            definition.eliminate_empty_transitions(&empty);
+        }
+
+        definition.drop_unreachable_states();
+
+        if empty.is_empty() && definition.is_deterministic() {
+            definition.kind = Kind::Dfa;
+        }
+
+        Ok(definition)
+    }
+
+    /// JFLAP finite automaton representation of this definition, states are
+    /// identified by their position in the declared states, laid out in a grid
+    #[must_use]
+    pub fn to_jflap(&self) -> String {
+        let mut jflap = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"no\"?>\n<structure>\n\t<type>fa</type>\n\t<automaton>\n"
+        );
+
+        for (id, state) in self.states.iter().enumerate() {
+            let _ = writeln!(jflap, "\t\t<state id=\"{id}\" name=\"{}\">", escape(state));
+            let _ = writeln!(jflap, "\t\t\t<x>{}.0</x>", SPACING * (1 + id % COLUMNS));
+            let _ = writeln!(jflap, "\t\t\t<y>{}.0</y>", SPACING * (1 + id / COLUMNS));
+
+            if *state == self.initial {
+                jflap.push_str("\t\t\t<initial/>\n");
+            }
+
+            if self.finals.contains(state) {
+                jflap.push_str("\t\t\t<final/>\n");
+            }
+
+            jflap.push_str("\t\t</state>\n");
+        }
+
+        let id = |name: &String| self.states.iter().position(|state| state == name).unwrap_or(usize::MAX);
+
+        for (from, sym, to) in &self.transitions {
+            let _ = writeln!(
+                jflap,
+                "\t\t<transition>\n\t\t\t<from>{}</from>\n\t\t\t<to>{}</to>\n\t\t\t<read>{}</read>\n\t\t</transition>",
+                id(from), id(to), escape(sym)
+            );
+        }
+
+        jflap.push_str("\t</automaton>\n</structure>\n");
+
+        jflap
+    }
+
+    fn is_deterministic(&self) -> bool {
+        self.states.iter().all(|state|
+            self.alphabet.iter().all(|sym|
+                self.transitions.iter().filter(|(from, on, _)| from == state && on == sym).count() == 1
+            )
+        )
+    }
+
+    // δ'(p, a) = δ(E(p), a) and p is final if E(p) contains a final state, where
+    // E(p) is the ε closure of p
+    fn eliminate_empty_transitions(&mut self, empty: &[(String, String)]) {
+        let mut transitions = Vec::new();
+        let mut finals = Vec::new();
+
+        for state in &self.states {
+            let closure = reach(state, empty.iter().map(|(from, to)| (from, to)));
+
+            if closure.iter().any(|state| self.finals.contains(state)) {
+                finals.push(state.clone());
+            }
+
+            for sym in &self.alphabet {
+                for (_, _, to) in self.transitions.iter().filter(|(from, on, _)| on == sym && closure.contains(&from)) {
+                    let transition = (state.clone(), sym.clone(), to.clone());
+
+                    if !transitions.contains(&transition) {
+                        transitions.push(transition);
+                    }
+                }
+            }
+        }
+
+        self.finals = finals;
+        self.transitions = transitions;
+    }
+
+    // keeps the states reachable from the initial state, and their transitions
+    fn drop_unreachable_states(&mut self) {
+        let reached = reach(&self.initial, self.transitions.iter().map(|(from, _, to)| (from, to)))
+            .into_iter()
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        self.states.retain(|state| reached.contains(state));
+        self.finals.retain(|state| reached.contains(state));
+        self.transitions.retain(|(from, _, _)| reached.contains(from));
+    }
+}
+
+// states reachable from a state following the given transitions, including the state itself
+fn reach<'a>(state: &'a String, transitions: impl Iterator<Item=(&'a String, &'a String)>) -> Vec<&'a String> {
+    let transitions = transitions.collect::<Vec<_>>();
+    let mut reached = vec![state];
+    let mut current = 0;
+
+    while current < reached.len() {
+        let from = reached[current];
+
+        for (_, to) in transitions.iter().filter(|(state, _)| *state == from) {
+            if !reached.contains(to) {
+                reached.push(to);
+            }
+        }
+
+        current += 1;
+    }
+
+    reached
+}
@@ -0,0 +1,353 @@
+//! JSON definition format
+//!
+//! ```json
+//! {
+//!   "kind": "dfa",
+//!   "alphabet": ["0", "1"],
+//!   "states": ["A", "B"],
+//!   "initial": "A",
+//!   "final": ["B"],
+//!   "transitions": [
+//!     ["A", "0", "A"], ["A", "1", "B"],
+//!     ["B", "0", "B"], ["B", "1", "A"]
+//!   ]
+//! }
+//! ```
+//!
+//! `kind` is one of `"dfa"`, `"nfa"` or `"buchi"`, symbols and states are strings, numbers
+//! are accepted as well and read as their literal text
+
+use std::fmt::Write;
+
+use crate::formats::{Definition, Kind, ParseError};
+
+pub const ERR_DUPLICATE_FIELD: &str = "Definition field is defined more than once";
+pub const ERR_EXPECTED_ARRAY: &str = "Expected an array";
+pub const ERR_EXPECTED_NAME: &str = "Expected a string or a number";
+pub const ERR_EXPECTED_OBJECT: &str = "Expected an object";
+pub const ERR_EXPECTED_TRANSITION: &str = "Expected a transition, [state, symbol, state]";
+pub const ERR_INVALID_ESCAPE: &str = "Invalid escape sequence in string";
+pub const ERR_INVALID_KIND: &str = "Kind must be one of \"dfa\", \"nfa\" or \"buchi\"";
+pub const ERR_MISSING_FIELD: &str = "Definition is missing a required field";
+pub const ERR_UNEXPECTED_CHARACTER: &str = "Unexpected character";
+pub const ERR_UNEXPECTED_END: &str = "Unexpected end of input";
+pub const ERR_UNKNOWN_FIELD: &str = "Unknown definition field";
+
+const FIELDS: [&str; 6] = ["kind", "alphabet", "states", "initial", "final", "transitions"];
+
+impl Definition {
+    /// Reads a definition from its JSON representation
+    ///
+    /// # Errors
+    /// [`ParseError`] with the position of malformed JSON or an invalid definition field
+    pub fn from_json(source: &str) -> Result<Self, ParseError> {
+        let mut parser = Parser::new(source);
+        let root = parser.value()?;
+
+        parser.skip_whitespace();
+
+        if parser.peek().is_some() {
+            return Err(parser.error(ERR_UNEXPECTED_CHARACTER));
+        }
+
+        let Json::Object(fields) = &root.json else {
+            return Err(root.error(ERR_EXPECTED_OBJECT));
+        };
+
+        let mut values: [Option<&Node>; 6] = [None; 6];
+
+        for (name, value) in fields {
+            let field = FIELDS.iter().position(|field| field == name).ok_or_else(|| value.error(ERR_UNKNOWN_FIELD))?;
+
+            if values[field].replace(value).is_some() {
+                return Err(value.error(ERR_DUPLICATE_FIELD));
+            }
+        }
+
+        let field = |idx: usize| values[idx].ok_or_else(|| root.error(ERR_MISSING_FIELD));
+
+        let kind = match field(0)?.name()?.as_str() {
+            "dfa" => Kind::Dfa,
+            "nfa" => Kind::Nfa,
+            "buchi" => Kind::Buchi,
+            _ => return Err(field(0)?.error(ERR_INVALID_KIND))
+        };
+
+        let transitions = field(5)?.array()?.iter()
+            .map(|node| match node.array()? {
+                [from, sym, to] => Ok((from.name()?, sym.name()?, to.name()?)),
+                _ => Err(node.error(ERR_EXPECTED_TRANSITION))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            kind,
+            alphabet: field(1)?.names()?,
+            states: field(2)?.names()?,
+            initial: field(3)?.name()?,
+            finals: field(4)?.names()?,
+            transitions,
+        })
+    }
+
+    /// JSON representation of this definition
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let names = |names: &[String]| names.iter().map(|name| quote(name)).collect::<Vec<_>>().join(", ");
+        let mut json = String::from("{\n");
+
+        let kind = match self.kind {
+            Kind::Dfa => "dfa",
+            Kind::Nfa => "nfa",
+            Kind::Buchi => "buchi"
+        };
+
+        let _ = writeln!(json, "  \"kind\": \"{kind}\",");
+        let _ = writeln!(json, "  \"alphabet\": [{}],", names(&self.alphabet));
+        let _ = writeln!(json, "  \"states\": [{}],", names(&self.states));
+        let _ = writeln!(json, "  \"initial\": {},", quote(&self.initial));
+        let _ = writeln!(json, "  \"final\": [{}],", names(&self.finals));
+
+        json.push_str("  \"transitions\": [");
+
+        for (idx, (from, sym, to)) in self.transitions.iter().enumerate() {
+            let separator = if idx == 0 { "" } else { "," };
+
+            let _ = write!(json, "{separator}\n    [{}, {}, {}]", quote(from), quote(sym), quote(to));
+        }
+
+        json.push_str(if self.transitions.is_empty() { "]\n}\n" } else { "\n  ]\n}\n" });
+
+        json
+    }
+}
+
+fn quote(value: &str) -> String {
+    let mut quoted = String::from("\"");
+
+    for chr in value.chars() {
+        match chr {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            chr if chr.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", chr as u32);
+            }
+            chr => quoted.push(chr)
+        }
+    }
+
+    quoted.push('"');
+
+    quoted
+}
+
+enum Json {
+    Array(Vec<Node>),
+    Literal,
+    Name(String),
+    Object(Vec<(String, Node)>),
+}
+
+struct Node {
+    json: Json,
+    line: usize,
+    column: usize,
+}
+
+impl Node {
+    fn array(&self) -> Result<&[Self], ParseError> {
+        match &self.json {
+            Json::Array(items) => Ok(items),
+            _ => Err(self.error(ERR_EXPECTED_ARRAY))
+        }
+    }
+
+    const fn error(&self, message: &'static str) -> ParseError {
+        ParseError { line: self.line, column: self.column, message }
+    }
+
+    fn name(&self) -> Result<String, ParseError> {
+        match &self.json {
+            Json::Name(name) => Ok(name.clone()),
+            _ => Err(self.error(ERR_EXPECTED_NAME))
+        }
+    }
+
+    fn names(&self) -> Result<Vec<String>, ParseError> {
+        self.array()?.iter().map(Self::name).collect()
+    }
+}
+
+struct Parser {
+    source: Vec<char>,
+    position: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Parser {
+    fn new(source: &str) -> Self {
+        Self { source: source.chars().collect(), position: 0, line: 1, column: 1 }
+    }
+
+    const fn error(&self, message: &'static str) -> ParseError {
+        ParseError { line: self.line, column: self.column, message }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some(chr) if chr == expected => {
+                self.next();
+                Ok(())
+            }
+            Some(_) => Err(self.error(ERR_UNEXPECTED_CHARACTER)),
+            None => Err(self.error(ERR_UNEXPECTED_END))
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let chr = self.peek()?;
+
+        self.position += 1;
+
+        if chr == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+
+        Some(chr)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.next();
+        }
+    }
+
+    fn value(&mut self) -> Result<Node, ParseError> {
+        self.skip_whitespace();
+
+        let (line, column) = (self.line, self.column);
+
+        let json = match self.peek() {
+            Some('{') => Json::Object(self.sequence('}', |parser| {
+                let key = parser.string()?;
+
+                parser.expect(':')?;
+
+                Ok((key, parser.value()?))
+            })?),
+            Some('[') => Json::Array(self.sequence(']', Self::value)?),
+            Some('"') => Json::Name(self.string()?),
+            Some(chr) if chr == '-' || chr.is_ascii_digit() => Json::Name(self.number()),
+            Some(chr) if chr.is_ascii_alphabetic() => {
+                let literal = self.take_while(|chr| chr.is_ascii_alphabetic());
+
+                if !["true", "false", "null"].contains(&literal.as_str()) {
+                    return Err(ParseError { line, column, message: ERR_UNEXPECTED_CHARACTER });
+                }
+
+                Json::Literal
+            }
+            Some(_) => return Err(self.error(ERR_UNEXPECTED_CHARACTER)),
+            None => return Err(self.error(ERR_UNEXPECTED_END))
+        };
+
+        Ok(Node { json, line, column })
+    }
+
+    fn number(&mut self) -> String {
+        self.take_while(|chr| chr.is_ascii_digit() || "+-.eE".contains(chr))
+    }
+
+    fn sequence<T>(&mut self, close: char, mut item: impl FnMut(&mut Self) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+        let mut items = Vec::new();
+
+        self.next();
+        self.skip_whitespace();
+
+        if self.peek() == Some(close) {
+            self.next();
+
+            return Ok(items);
+        }
+
+        loop {
+            items.push(item(self)?);
+            self.skip_whitespace();
+
+            match self.next() {
+                Some(',') => {}
+                Some(chr) if chr == close => return Ok(items),
+                Some(_) => return Err(ParseError { column: self.column - 1, ..self.error(ERR_UNEXPECTED_CHARACTER) }),
+                None => return Err(self.error(ERR_UNEXPECTED_END))
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.skip_whitespace();
+
+        if self.peek() != Some('"') {
+            return Err(self.error(if self.peek().is_some() { ERR_UNEXPECTED_CHARACTER } else { ERR_UNEXPECTED_END }));
+        }
+
+        self.next();
+
+        let mut string = String::new();
+
+        loop {
+            let (line, column) = (self.line, self.column);
+
+            match self.next() {
+                Some('"') => return Ok(string),
+                Some('\\') => {
+                    let escaped = match self.next() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => {
+                            let code = (0..4).filter_map(|_| self.next()).collect::<String>();
+
+                            u32::from_str_radix(&code, 16).ok().and_then(char::from_u32)
+                                .ok_or(ParseError { line, column, message: ERR_INVALID_ESCAPE })?
+                        }
+                        Some(_) => return Err(ParseError { line, column, message: ERR_INVALID_ESCAPE }),
+                        None => return Err(self.error(ERR_UNEXPECTED_END))
+                    };
+
+                    string.push(escaped);
+                }
+                Some(chr) => string.push(chr),
+                None => return Err(self.error(ERR_UNEXPECTED_END))
+            }
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> String {
+        let mut taken = String::new();
+
+        while let Some(chr) = self.peek().filter(|chr| predicate(*chr)) {
+            taken.push(chr);
+            self.next();
+        }
+
+        taken
+    }
+}
@@ -0,0 +1,218 @@
+//! Textual representations of finite automata, used to read, write and export automata
+//!
+//! Automata are exchanged through a [`Definition`], a string based description of
+//! (Q, Σ, δ, q0, F), which can be converted to and from a [`DFA`], an [`NFA`] or a [`Buchi`] automaton
+
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+
+use crate::automata::{Buchi, DFA, NFA};
+use crate::model::{F, Q, State, δ, Σ};
+
+pub(crate) mod dot;
+pub(crate) mod hoa;
+pub(crate) mod jflap;
+pub(crate) mod json;
+pub(crate) mod text;
+mod xml;
+
+pub(crate) const ERR_UNDEFINED_TRANSITION_STATE: &str = "Transition state is not defined in states";
+
+/// Kind of automaton a [`Definition`] describes
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// Deterministic finite automaton
+    Dfa,
+
+    /// Nondeterministic finite automaton
+    Nfa,
+
+    /// Nondeterministic Büchi automaton, accepting infinite words
+    Buchi,
+}
+
+/// String based definition of a finite automaton (Q, Σ, δ, q0, F)
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Definition {
+    /// Kind of automaton defined
+    pub kind: Kind,
+
+    /// Alphabet Σ, in declared order
+    pub alphabet: Vec<String>,
+
+    /// States Q, in declared order
+    pub states: Vec<String>,
+
+    /// Initial state q0
+    pub initial: String,
+
+    /// Final states F
+    pub finals: Vec<String>,
+
+    /// Transitions δ, as (state, symbol, state) triples
+    pub transitions: Vec<(String, String, String)>,
+}
+
+/// Error reading a textual representation of an automaton
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    /// Line, starting at one, where the error was found
+    pub line: usize,
+
+    /// Column, in characters starting at one, where the error was found
+    pub column: usize,
+
+    /// Description of the error
+    pub message: &'static str,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Definition {
+    /// Definition describing the given [`DFA`], see [`state_name`] for naming of states,
+    /// with an interval alphabet each class is expanded into its symbols
+    #[must_use]
+    pub fn from_dfa<A, S>(dfa: &DFA<A, S>) -> Self
+        where A: Clone + Display + Eq + Hash,
+              S: Display + Eq + Hash
+    {
+        let table = dfa.table();
+        let symbols = (0..table.symbols.len()).flat_map(|sym| table.inputs(sym)).collect::<Vec<_>>();
+
+        let transitions = dfa.states()
+            .flat_map(|state| symbols.iter().filter_map(move |sym|
+                dfa.transition(state, sym).map(|next| (state_name(state), sym.to_string(), state_name(next)))
+            ))
+            .collect();
+
+        Self {
+            kind: Kind::Dfa,
+            alphabet: symbols.iter().map(ToString::to_string).collect(),
+            states: dfa.states().map(state_name).collect(),
+            initial: state_name(dfa.initial()),
+            finals: dfa.states().filter(|state| state.is_final()).map(state_name).collect(),
+            transitions,
+        }
+    }
+
+    /// Definition describing the given [`NFA`], see [`state_name`] for naming of states
+    #[must_use]
+    pub fn from_nfa<A, S>(nfa: &NFA<A, S>) -> Self
+        where A: Display + Eq + Hash,
+              S: Clone + Display + Eq + Hash
+    {
+        let transitions = nfa.states()
+            .flat_map(|state| nfa.alphabet().iter().flat_map(move |sym|
+                nfa.transitions(state, sym).map(move |next| (state_name(state), sym.to_string(), state_name(next)))
+            ))
+            .collect();
+
+        Self {
+            kind: Kind::Nfa,
+            alphabet: nfa.alphabet().iter().map(ToString::to_string).collect(),
+            states: nfa.states().map(state_name).collect(),
+            initial: state_name(nfa.initial()),
+            finals: nfa.states().filter(|state| state.is_final()).map(state_name).collect(),
+            transitions,
+        }
+    }
+
+    /// Definition describing the given [`Buchi`] automaton, see [`state_name`] for naming of states
+    #[must_use]
+    pub fn from_buchi<A, S>(buchi: &Buchi<A, S>) -> Self
+        where A: Display + Eq,
+              S: Display + Eq + Hash
+    {
+        let transitions = buchi.states()
+            .flat_map(|state| buchi.alphabet().iter().flat_map(move |sym|
+                buchi.transitions(state, sym).map(move |next| (state_name(state), sym.to_string(), state_name(next)))
+            ))
+            .collect();
+
+        Self {
+            kind: Kind::Buchi,
+            alphabet: buchi.alphabet().iter().map(ToString::to_string).collect(),
+            states: buchi.states().map(state_name).collect(),
+            initial: state_name(buchi.initial()),
+            finals: buchi.states().filter(|state| state.is_final()).map(state_name).collect(),
+            transitions,
+        }
+    }
+
+    /// Builds the [`DFA`] described by this definition, regardless of its kind
+    ///
+    /// # Errors
+    /// any configuration error reported by [`DFA::new`]
+    #[allow(non_snake_case)]
+    pub fn to_dfa(&self) -> Result<DFA<String, String>, &'static str> {
+        let (Q, Σ, δ) = self.components()?;
+
+        DFA::new(Q, &Σ, δ, self.initial.clone(), &F::new(self.finals.clone())?)
+    }
+
+    /// Builds the [`NFA`] described by this definition, regardless of its kind
+    ///
+    /// # Errors
+    /// any configuration error reported by [`NFA::new`]
+    #[allow(non_snake_case)]
+    pub fn to_nfa(&self) -> Result<NFA<String, String>, &'static str> {
+        let (Q, Σ, δ) = self.components()?;
+
+        NFA::new(Q, Σ, δ, self.initial.clone(), &F::new(self.finals.clone())?)
+    }
+
+    /// Builds the [`Buchi`] automaton described by this definition, regardless of
+    /// its kind, accepting nothing without final states
+    ///
+    /// # Errors
+    /// any configuration error reported by [`Buchi::new`]
+    #[allow(non_snake_case)]
+    pub fn to_buchi(&self) -> Result<Buchi<String, String>, &'static str> {
+        let (Q, Σ, δ) = self.components()?;
+        let F = if self.finals.is_empty() { F::empty() } else { F::new(self.finals.clone())? };
+
+        Buchi::new(Q, Σ, δ, self.initial.clone(), &F)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn components(&self) -> Result<(Q<String>, Σ<String>, δ<String, String>), &'static str> {
+        if self.transitions.iter().any(|(from, _, to)| !self.states.contains(from) || !self.states.contains(to)) {
+            return Err(ERR_UNDEFINED_TRANSITION_STATE);
+        }
+
+        let delta = self.states.iter()
+            .map(|state| (
+                state.clone(),
+                self.transitions.iter()
+                    .filter(|(from, _, _)| from == state)
+                    .map(|(_, sym, to)| (sym.clone(), to.clone()))
+                    .collect(),
+            ))
+            .collect();
+
+        Ok((
+            Q::new(self.states.clone())?,
+            Σ::new(self.alphabet.clone())?,
+            δ::new(delta)?,
+        ))
+    }
+}
+
+/// Name of a state in a [`Definition`]
+///
+/// A state with a single tag is named by that tag, otherwise, i.e. a state derived
+/// by determinization, by its tags in braces, e.g. `{A,B}`, or `{}` for a dead state
+#[must_use]
+pub fn state_name<S: Display>(state: &State<S>) -> String {
+    if state.len() == 1 {
+        state[0].to_string()
+    } else {
+        format!("{{{}}}", state.iter().map(ToString::to_string).collect::<Vec<_>>().join(","))
+    }
+}
@@ -0,0 +1,225 @@
+//! Line based text definition format
+//!
+//! ```text
+//! # ends with 01
+//! nfa
+//! Σ: 0 1
+//! >(A) (B) ((C))
+//! A -0-> A
+//! A -1-> A
+//! A -0-> B
+//! B -1-> C
+//! ```
+//!
+//! * a definition starts with its kind, `dfa`, `nfa` or `buchi`
+//! * the alphabet is declared by `Σ:`, or `alphabet:`, followed by its symbols
+//! * states are declared using the notation of [`State`](crate::model::State),
+//!   `>(A)` initial, `(B)` interim, `((C))` final and `>((D))` initial and final
+//! * transitions are declared one per line, `A -0-> B`
+//! * empty lines and lines starting with `#` are ignored
+//!
+//! Symbols and states are separated by whitespace, so they can not contain any,
+//! nor can states contain parentheses
+
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::formats::{Definition, Kind, ParseError};
+
+pub const ERR_DUPLICATE_ALPHABET: &str = "Alphabet is declared more than once";
+pub const ERR_DUPLICATE_KIND: &str = "Kind is declared more than once";
+pub const ERR_DUPLICATE_STATE: &str = "State is declared more than once";
+pub const ERR_EXPECTED_KIND: &str = "Definition must start with its kind, dfa, nfa or buchi";
+pub const ERR_EXPECTED_STATE: &str = "Expected a state, i.e. >(A), (B), ((C)) or >((D))";
+pub const ERR_EXPECTED_TRANSITION: &str = "Expected a transition, i.e. A -0-> B";
+pub const ERR_MISSING_ALPHABET: &str = "Definition does not declare an alphabet";
+pub const ERR_MISSING_INITIAL_STATE: &str = "Definition does not declare an initial state";
+pub const ERR_MULTIPLE_INITIAL_STATES: &str = "Definition declares more than one initial state";
+pub const ERR_UNDECLARED_STATE: &str = "State is not declared";
+pub const ERR_UNDECLARED_SYMBOL: &str = "Symbol is not declared in the alphabet";
+
+const ALPHABET: [&str; 2] = ["Σ:", "alphabet:"];
+
+impl Definition {
+    /// Reads a definition from its text representation
+    ///
+    /// # Errors
+    /// [`ParseError`] with the position of a malformed or inconsistent declaration
+    pub fn from_text(source: &str) -> Result<Self, ParseError> {
+        let mut kind = None;
+        let mut alphabet: Option<Vec<String>> = None;
+        let mut states = Vec::new();
+        let mut initial = None;
+        let mut finals = Vec::new();
+        let mut transitions = Vec::new();
+        let mut lines = 0;
+
+        for (line, text) in source.lines().enumerate() {
+            let line = line + 1;
+            let tokens = tokenize(text);
+            let error = |column: usize, message| ParseError { line, column, message };
+
+            lines = line;
+
+            let Some(&(column, first)) = tokens.first() else {
+                continue;
+            };
+
+            if first.starts_with('#') {
+                continue;
+            }
+
+            if kind.is_none() {
+                kind = Some(match tokens.as_slice() {
+                    [(_, "dfa")] => Kind::Dfa,
+                    [(_, "nfa")] => Kind::Nfa,
+                    [(_, "buchi")] => Kind::Buchi,
+                    _ => return Err(error(column, ERR_EXPECTED_KIND))
+                });
+            } else if matches!(first, "dfa" | "nfa" | "buchi") && tokens.len() == 1 {
+                return Err(error(column, ERR_DUPLICATE_KIND));
+            } else if ALPHABET.contains(&first) {
+                if alphabet.is_some() {
+                    return Err(error(column, ERR_DUPLICATE_ALPHABET));
+                }
+
+                alphabet = Some(tokens[1..].iter().map(|(_, sym)| (*sym).to_string()).collect());
+            } else if first.starts_with(['>', '(']) {
+                for (column, token) in tokens {
+                    let (name, is_initial, is_final) = state(token).ok_or_else(|| error(column, ERR_EXPECTED_STATE))?;
+
+                    if states.contains(&name) {
+                        return Err(error(column, ERR_DUPLICATE_STATE));
+                    } else if is_initial && initial.replace(name.clone()).is_some() {
+                        return Err(error(column, ERR_MULTIPLE_INITIAL_STATES));
+                    } else if is_final {
+                        finals.push(name.clone());
+                    }
+
+                    states.push(name);
+                }
+            } else {
+                let [(from_column, from), (sym_column, sym), (to_column, to)] = tokens.as_slice() else {
+                    return Err(error(column, ERR_EXPECTED_TRANSITION));
+                };
+
+                let sym = sym.strip_prefix('-')
+                    .and_then(|sym| sym.strip_suffix("->"))
+                    .filter(|sym| !sym.is_empty())
+                    .ok_or_else(|| error(*sym_column, ERR_EXPECTED_TRANSITION))?;
+
+                let symbols = alphabet.as_ref().ok_or_else(|| error(column, ERR_MISSING_ALPHABET))?;
+
+                for (column, state) in [(from_column, from), (to_column, to)] {
+                    if !states.iter().any(|name| name == state) {
+                        return Err(error(*column, ERR_UNDECLARED_STATE));
+                    }
+                }
+
+                if !symbols.iter().any(|name| name == sym) {
+                    return Err(error(sym_column + 1, ERR_UNDECLARED_SYMBOL));
+                }
+
+                transitions.push(((*from).to_string(), sym.to_string(), (*to).to_string()));
+            }
+        }
+
+        let end = ParseError { line: lines + 1, column: 1, message: ERR_EXPECTED_KIND };
+
+        Ok(Self {
+            kind: kind.ok_or(end)?,
+            alphabet: alphabet.ok_or(ParseError { message: ERR_MISSING_ALPHABET, ..end })?,
+            states,
+            initial: initial.ok_or(ParseError { message: ERR_MISSING_INITIAL_STATE, ..end })?,
+            finals,
+            transitions,
+        })
+    }
+
+    /// Text representation of this definition, reading it back with
+    /// [`Definition::from_text`] gives the same definition, as long as no
+    /// symbol or state contains whitespace and no state contains parentheses
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for Definition {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(fmt, "{}", match self.kind {
+            Kind::Dfa => "dfa",
+            Kind::Nfa => "nfa",
+            Kind::Buchi => "buchi"
+        })?;
+
+        writeln!(fmt, "{} {}", ALPHABET[0], self.alphabet.join(" "))?;
+
+        let states = self.states.iter()
+            .map(|state| {
+                let (start, end) = match (*state == self.initial, self.finals.contains(state)) {
+                    (false, false) => ("(", ")"),
+                    (true, false) => (">(", ")"),
+                    (false, true) => ("((", "))"),
+                    (true, true) => (">((", "))"),
+                };
+
+                format!("{start}{state}{end}")
+            })
+            .collect::<Vec<_>>();
+
+        writeln!(fmt, "{}", states.join(" "))?;
+
+        for (from, sym, to) in &self.transitions {
+            writeln!(fmt, "{from} -{sym}-> {to}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Definition {
+    type Err = ParseError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::from_text(source)
+    }
+}
+
+// state declaration, i.e. >((A)), as its name and whether it is initial and or final
+fn state(token: &str) -> Option<(String, bool, bool)> {
+    let (is_initial, token) = token.strip_prefix('>').map_or((false, token), |token| (true, token));
+
+    let (is_final, name) = token.strip_prefix("((").and_then(|token| token.strip_suffix("))"))
+        .map(|name| (true, name))
+        .or_else(|| token.strip_prefix('(').and_then(|token| token.strip_suffix(')')).map(|name| (false, name)))?;
+
+    if name.is_empty() || name.contains(['(', ')']) {
+        None
+    } else {
+        Some((name.to_string(), is_initial, is_final))
+    }
+}
+
+// whitespace separated tokens of a line, with their column in characters starting at one
+fn tokenize(line: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+
+    for (column, (idx, chr)) in line.char_indices().enumerate() {
+        match (start, chr.is_whitespace()) {
+            (None, false) => start = Some((column + 1, idx)),
+            (Some((column, begin)), true) => {
+                tokens.push((column, &line[begin..idx]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some((column, begin)) = start {
+        tokens.push((column, &line[begin..]));
+    }
+
+    tokens
+}
@@ -0,0 +1,281 @@
+// minimal xml reader, sufficient for the xml based formats, elements, attributes,
+// text and character references, comments, processing instructions and doctype
+// declarations are skipped, namespaces are not interpreted
+
+use crate::formats::ParseError;
+
+pub const ERR_INVALID_REFERENCE: &str = "Invalid character or entity reference";
+pub const ERR_MALFORMED_XML: &str = "Malformed XML";
+pub const ERR_MISMATCHED_TAG: &str = "Closing tag does not match its opening tag";
+pub const ERR_UNEXPECTED_END: &str = "Unexpected end of input";
+
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Self>,
+    pub text: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Element {
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.iter().find(|(attribute, _)| attribute == name).map(|(_, value)| value.as_str())
+    }
+
+    pub fn child(&self, name: &str) -> Option<&Self> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    pub fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item=&'a Self> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    pub const fn error(&self, message: &'static str) -> ParseError {
+        ParseError { line: self.line, column: self.column, message }
+    }
+}
+
+pub fn parse(source: &str) -> Result<Element, ParseError> {
+    let mut parser = Parser { source: source.chars().collect(), position: 0, line: 1, column: 1 };
+
+    parser.skip_misc()?;
+
+    let root = parser.element()?;
+
+    parser.skip_misc()?;
+
+    if parser.peek().is_some() {
+        return Err(parser.error(ERR_MALFORMED_XML));
+    }
+
+    Ok(root)
+}
+
+pub fn escape(value: &str) -> String {
+    value.chars()
+        .fold(String::new(), |mut escaped, chr| {
+            match chr {
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '&' => escaped.push_str("&amp;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&apos;"),
+                chr => escaped.push(chr)
+            }
+
+            escaped
+        })
+}
+
+struct Parser {
+    source: Vec<char>,
+    position: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Parser {
+    fn element(&mut self) -> Result<Element, ParseError> {
+        let (line, column) = (self.line, self.column);
+
+        self.expect("<")?;
+
+        let name = self.name()?;
+        let mut attributes = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+
+            if self.starts_with("/>") {
+                self.advance(2);
+
+                return Ok(Element { name, attributes, children: Vec::new(), text: String::new(), line, column });
+            } else if self.starts_with(">") {
+                self.advance(1);
+                break;
+            }
+
+            let attribute = self.name()?;
+
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+
+            let quote = self.next().filter(|quote| *quote == '"' || *quote == '\'')
+                .ok_or_else(|| self.error(ERR_MALFORMED_XML))?;
+
+            attributes.push((attribute, self.text(quote)?));
+            self.advance(1);
+        }
+
+        let mut children = Vec::new();
+        let mut text = String::new();
+
+        loop {
+            if self.starts_with("</") {
+                let closing = self.error(ERR_MISMATCHED_TAG);
+
+                self.advance(2);
+
+                if self.name()? != name {
+                    return Err(closing);
+                }
+
+                self.skip_whitespace();
+                self.expect(">")?;
+
+                return Ok(Element { name, attributes, children, text, line, column });
+            } else if self.starts_with("<![CDATA[") {
+                self.advance(9);
+
+                while !self.starts_with("]]>") {
+                    text.push(self.next().ok_or_else(|| self.error(ERR_UNEXPECTED_END))?);
+                }
+
+                self.advance(3);
+            } else if self.starts_with("<!--") || self.starts_with("<?") {
+                self.skip_misc()?;
+            } else if self.starts_with("<") {
+                children.push(self.element()?);
+            } else if self.peek().is_some() {
+                text.push_str(&self.text('<')?);
+            } else {
+                return Err(self.error(ERR_UNEXPECTED_END));
+            }
+        }
+    }
+
+    const fn error(&self, message: &'static str) -> ParseError {
+        ParseError { line: self.line, column: self.column, message }
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), ParseError> {
+        if self.starts_with(expected) {
+            self.advance(expected.chars().count());
+            Ok(())
+        } else if self.peek().is_some() {
+            Err(self.error(ERR_MALFORMED_XML))
+        } else {
+            Err(self.error(ERR_UNEXPECTED_END))
+        }
+    }
+
+    fn advance(&mut self, count: usize) {
+        for _ in 0..count {
+            self.next();
+        }
+    }
+
+    fn name(&mut self) -> Result<String, ParseError> {
+        let mut name = String::new();
+
+        while let Some(chr) = self.peek().filter(|chr| chr.is_alphanumeric() || "_-.:".contains(*chr)) {
+            name.push(chr);
+            self.next();
+        }
+
+        if name.is_empty() {
+            Err(self.error(ERR_MALFORMED_XML))
+        } else {
+            Ok(name)
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let chr = self.peek()?;
+
+        self.position += 1;
+
+        if chr == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+
+        Some(chr)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source.get(self.position).copied()
+    }
+
+    fn reference(&mut self) -> Result<char, ParseError> {
+        let error = self.error(ERR_INVALID_REFERENCE);
+        let mut reference = String::new();
+
+        self.next();
+
+        loop {
+            match self.next() {
+                Some(';') => break,
+                Some(chr) if reference.len() < 8 => reference.push(chr),
+                _ => return Err(error)
+            }
+        }
+
+        match reference.as_str() {
+            "lt" => Ok('<'),
+            "gt" => Ok('>'),
+            "amp" => Ok('&'),
+            "quot" => Ok('"'),
+            "apos" => Ok('\''),
+            reference => reference.strip_prefix("#x")
+                .map(|code| u32::from_str_radix(code, 16))
+                .or_else(|| reference.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32)
+                .ok_or(error)
+        }
+    }
+
+    fn skip_misc(&mut self) -> Result<(), ParseError> {
+        loop {
+            self.skip_whitespace();
+
+            let end = if self.starts_with("<?") {
+                "?>"
+            } else if self.starts_with("<!--") {
+                "-->"
+            } else if self.starts_with("<!") {
+                ">"
+            } else {
+                return Ok(());
+            };
+
+            while !self.starts_with(end) {
+                self.next().ok_or_else(|| self.error(ERR_UNEXPECTED_END))?;
+            }
+
+            self.advance(end.len());
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.next();
+        }
+    }
+
+    fn starts_with(&self, expected: &str) -> bool {
+        expected.chars().enumerate().all(|(idx, chr)| self.source.get(self.position + idx) == Some(&chr))
+    }
+
+    // text up to, not including, the terminator, with references resolved
+    fn text(&mut self, terminator: char) -> Result<String, ParseError> {
+        let mut text = String::new();
+
+        loop {
+            match self.peek() {
+                Some(chr) if chr == terminator => return Ok(text),
+                Some('&') => text.push(self.reference()?),
+                Some(chr) => {
+                    text.push(chr);
+                    self.next();
+                }
+                None => return Err(self.error(ERR_UNEXPECTED_END))
+            }
+        }
+    }
+}
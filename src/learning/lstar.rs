@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+
+use crate::automata::DFA;
+use crate::learning::{accepted, EquivalenceOracle, MembershipOracle};
+use crate::model::Σ;
+
+pub const ERR_INVALID_COUNTEREXAMPLE: &str = "Counterexample must be a word over Σ the hypothesis misclassifies";
+
+/// Angluin's L* learner of the minimal [`DFA`] accepting the language of a system
+/// over the alphabet Σ, known through a [`MembershipOracle`]
+///
+/// The observation table has rows for a prefix closed set of access words and
+/// columns for a suffix closed set of experiments, counterexamples add all their
+/// suffixes as experiments, which keeps the rows of the access words distinct so
+/// the table never needs to be made consistent, membership queries are cached
+#[allow(non_snake_case)]
+pub struct LStar<A, M> {
+    Σ: Σ<A>,
+    membership: M,
+    // answers to membership queries, by the symbol indices of the word
+    answers: HashMap<Vec<usize>, bool>,
+}
+
+impl<A: Clone + Eq + Hash, M: MembershipOracle<A>> LStar<A, M> {
+    /// Learner of a language over Σ, asking the membership oracle
+    #[allow(non_snake_case)]
+    pub fn new(Σ: &Σ<A>, membership: M) -> Self {
+        Self { Σ: Σ.clone(), membership, answers: HashMap::new() }
+    }
+
+    /// Number of distinct membership queries asked so far
+    #[must_use]
+    pub fn queries(&self) -> usize {
+        self.answers.len()
+    }
+
+    /// Learns the minimal [`DFA`] of the language of the system, proposing
+    /// hypotheses until the equivalence oracle finds no counterexample, states
+    /// are tagged by the order their access words were found in, 0 being initial
+    ///
+    /// # Errors
+    pub fn learn(&mut self, equivalence: &mut impl EquivalenceOracle<A>) -> Result<DFA<A, usize>, &'static str> {
+        let mut access = vec![Vec::new()];
+        let mut experiments = vec![Vec::new()];
+
+        loop {
+            let (rows, delta) = self.close(&mut access, &experiments);
+
+            let states = rows.iter().enumerate().map(|(idx, row)| (vec![idx], row[0])).collect();
+            let hypothesis = DFA::from_table(self.Σ.clone(), states, 0, &delta);
+
+            let Some(counterexample) = equivalence.counterexample(&hypothesis) else {
+                return Ok(hypothesis);
+            };
+
+            let word = counterexample.iter()
+                .map(|sym| self.Σ.iter().position(|other| other == sym))
+                .collect::<Option<Vec<_>>>()
+                .ok_or(ERR_INVALID_COUNTEREXAMPLE)?;
+
+            if self.member(&word) == accepted(&hypothesis, &counterexample) {
+                return Err(ERR_INVALID_COUNTEREXAMPLE);
+            }
+
+            for start in 0..word.len() {
+                if !experiments.iter().any(|experiment| *experiment == word[start..]) {
+                    experiments.push(word[start..].to_vec());
+                }
+            }
+        }
+    }
+
+    // extends the access words until the table is closed, the row of each access
+    // word extended by a symbol being the row of an access word, returns the rows
+    // of the access words and the transitions between them, δ[state][symbol]
+    fn close(&mut self, access: &mut Vec<Vec<usize>>, experiments: &[Vec<usize>]) -> (Vec<Vec<bool>>, Vec<Vec<usize>>) {
+        let mut rows = access.iter().map(|word| self.row(word, experiments)).collect::<Vec<_>>();
+        let mut delta: Vec<Vec<usize>> = Vec::new();
+
+        while delta.len() < access.len() {
+            let state = delta.len();
+            let mut next = Vec::with_capacity(self.Σ.len());
+
+            for sym in 0..self.Σ.len() {
+                let mut word = access[state].clone();
+
+                word.push(sym);
+
+                let row = self.row(&word, experiments);
+
+                next.push(rows.iter().position(|other| *other == row).unwrap_or_else(|| {
+                    access.push(word);
+                    rows.push(row);
+                    rows.len() - 1
+                }));
+            }
+
+            delta.push(next);
+        }
+
+        (rows, delta)
+    }
+
+    fn row(&mut self, word: &[usize], experiments: &[Vec<usize>]) -> Vec<bool> {
+        experiments.iter()
+            .map(|experiment| self.member(&[word, experiment].concat()))
+            .collect()
+    }
+
+    fn member(&mut self, word: &[usize]) -> bool {
+        if let Some(answer) = self.answers.get(word) {
+            return *answer;
+        }
+
+        let symbols = word.iter().map(|sym| self.Σ[*sym].clone()).collect::<Vec<_>>();
+        let answer = self.membership.accepts(&symbols);
+
+        self.answers.insert(word.to_vec(), answer);
+
+        answer
+    }
+}
+
+impl<A: Debug, M> Debug for LStar<A, M> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("LStar")
+            .field("Σ", &self.Σ)
+            .field("queries", &self.answers.len())
+            .finish_non_exhaustive()
+    }
+}
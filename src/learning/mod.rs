@@ -0,0 +1,61 @@
+//! Active and passive learning of automata from black box systems and samples
+//!
+//! A learner asks a [`MembershipOracle`] whether words belong to the language of
+//! the system and proposes hypotheses to an [`EquivalenceOracle`], which answers
+//! with a counterexample until the hypothesis is equivalent, [`LStar`] learns the
+//! minimal [`DFA`] of the language this way
+//!
+//! Equivalence is rarely decidable for a black box, so it is approximated by
+//! testing, with random words, [`RandomWordOracle`], or with the W-method,
+//! [`WMethodOracle`], which is exact given a bound on the states of the system
+//!
+//! Passive learning infers an automaton from labelled samples alone,
+//! [`DFA::rpni`] merges the states of the [`DFA::prefix_tree_acceptor`] of the
+//! positive samples as long as it rejects the negative samples
+
+use std::hash::Hash;
+
+pub use lstar::LStar;
+pub use oracle::{RandomWordOracle, WMethodOracle};
+
+use crate::automata::DFA;
+use crate::model::State;
+
+pub(crate) mod lstar;
+pub(crate) mod oracle;
+pub(crate) mod rpni;
+
+/// Oracle answering membership queries, whether a word belongs to the language
+/// of the system being learned
+pub trait MembershipOracle<A> {
+    /// Does the system accept the word
+    fn accepts(&mut self, word: &[A]) -> bool;
+}
+
+impl<A, M: FnMut(&[A]) -> bool> MembershipOracle<A> for M {
+    fn accepts(&mut self, word: &[A]) -> bool {
+        self(word)
+    }
+}
+
+/// Oracle answering equivalence queries, whether a hypothesis accepts the
+/// language of the system being learned
+pub trait EquivalenceOracle<A> {
+    /// Word accepted by exactly one of the hypothesis and the system, `None` if
+    /// no such word is found
+    fn counterexample<S: Eq + Hash>(&mut self, hypothesis: &DFA<A, S>) -> Option<Vec<A>>;
+}
+
+/// A known automaton is an exact equivalence oracle for its own language
+impl<A: Clone + Eq + Hash, T: Eq + Hash> EquivalenceOracle<A> for DFA<A, T> {
+    fn counterexample<S: Eq + Hash>(&mut self, hypothesis: &DFA<A, S>) -> Option<Vec<A>> {
+        self.distinguishing_word(hypothesis)
+    }
+}
+
+// does the hypothesis accept the word, without moving its current state
+fn accepted<A: Clone + Eq + Hash, S: Eq + Hash>(hypothesis: &DFA<A, S>, word: &[A]) -> bool {
+    word.iter()
+        .try_fold(hypothesis.initial(), |state, sym| hypothesis.transition(state, sym))
+        .is_some_and(State::is_final)
+}
@@ -0,0 +1,190 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+
+use crate::automata::DFA;
+use crate::automata::table::{DfaTable, reachable};
+use crate::learning::{accepted, EquivalenceOracle, MembershipOracle};
+use crate::random::{Random, Rng};
+
+/// Equivalence oracle testing the hypothesis on random words
+///
+/// Words have uniformly drawn lengths up to a maximum and uniformly drawn symbols
+/// of the hypothesis alphabet, a counterexample is only found if some test hits one
+pub struct RandomWordOracle<M, R = Random> {
+    membership: M,
+    random: R,
+    tests: usize,
+    max_length: usize,
+}
+
+impl<M, R: Rng> RandomWordOracle<M, R> {
+    /// Oracle asking the membership oracle about the given number of random
+    /// words for each hypothesis, drawn from the generator
+    pub const fn new(membership: M, random: R, tests: usize, max_length: usize) -> Self {
+        Self { membership, random, tests, max_length }
+    }
+}
+
+impl<A: Clone + Eq + Hash, M: MembershipOracle<A>, R: Rng> EquivalenceOracle<A> for RandomWordOracle<M, R> {
+    fn counterexample<S: Eq + Hash>(&mut self, hypothesis: &DFA<A, S>) -> Option<Vec<A>> {
+        let symbols = hypothesis.alphabet();
+
+        (0..self.tests).find_map(|_| {
+            let length = self.random.next_below(self.max_length + 1);
+
+            let word = (0..length)
+                .map(|_| symbols[self.random.next_below(symbols.len())].clone())
+                .collect::<Vec<_>>();
+
+            (self.membership.accepts(&word) != accepted(hypothesis, &word)).then_some(word)
+        })
+    }
+}
+
+impl<M, R> Debug for RandomWordOracle<M, R> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("RandomWordOracle")
+            .field("tests", &self.tests)
+            .field("max_length", &self.max_length)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Equivalence oracle testing the hypothesis with the W-method
+///
+/// Tests are the words p·m·w of its transition cover p, of the words m up to the
+/// number of extra states and of a characterization set w of its states
+///
+/// If the system has at most as many states as the hypothesis plus the extra
+/// states, every counterexample is found, the number of tests grows exponentially
+/// with the number of extra states
+pub struct WMethodOracle<M> {
+    membership: M,
+    extra_states: usize,
+}
+
+impl<M> WMethodOracle<M> {
+    /// Oracle asking the membership oracle, assuming the system has at most the
+    /// given number of states more than the hypothesis
+    pub const fn new(membership: M, extra_states: usize) -> Self {
+        Self { membership, extra_states }
+    }
+}
+
+impl<A: Clone + Eq + Hash, M: MembershipOracle<A>> EquivalenceOracle<A> for WMethodOracle<M> {
+    fn counterexample<S: Eq + Hash>(&mut self, hypothesis: &DFA<A, S>) -> Option<Vec<A>> {
+        let table = hypothesis.table();
+        let symbols = table.symbols.len();
+
+        let mut cover = access_words(&table);
+
+        cover.extend(cover.clone().into_iter().flat_map(|word| (0..symbols).map(move |sym| [&word[..], &[sym]].concat())));
+
+        let mut middles = vec![Vec::new()];
+        let mut layer = vec![Vec::new()];
+
+        for _ in 0..self.extra_states {
+            layer = layer.iter()
+                .flat_map(|word| (0..symbols).map(move |sym| [&word[..], &[sym]].concat()))
+                .collect();
+            middles.extend(layer.iter().cloned());
+        }
+
+        let characterization = characterization_set(&table);
+
+        for prefix in &cover {
+            for middle in &middles {
+                for suffix in &characterization {
+                    let word = [&prefix[..], middle, suffix].concat()
+                        .into_iter()
+                        .map(|sym| table.symbols[sym].clone())
+                        .collect::<Vec<_>>();
+
+                    if self.membership.accepts(&word) != accepted(hypothesis, &word) {
+                        return Some(word);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<M> Debug for WMethodOracle<M> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("WMethodOracle")
+            .field("extra_states", &self.extra_states)
+            .finish_non_exhaustive()
+    }
+}
+
+// shortest word reaching each reachable state, in breadth first order
+fn access_words<A, S>(table: &DfaTable<'_, A, S>) -> Vec<Vec<usize>> {
+    let mut words: Vec<Option<Vec<usize>>> = vec![None; table.delta.len()];
+
+    words[table.initial] = Some(Vec::new());
+
+    let order = reachable(table.initial, &table.delta);
+
+    for state in &order {
+        for (sym, next) in table.delta[*state].iter().enumerate() {
+            if words[*next].is_none() {
+                words[*next] = words[*state].as_ref().map(|word| [&word[..], &[sym]].concat());
+            }
+        }
+    }
+
+    order.iter().filter_map(|state| words[*state].take()).collect()
+}
+
+// shortest words telling apart each pair of states, ε telling apart final from
+// non final states, ε alone for a single state
+fn characterization_set<A: Eq + Hash, S: Eq + Hash>(table: &DfaTable<'_, A, S>) -> Vec<Vec<usize>> {
+    let mut words = vec![Vec::new()];
+
+    for left in 0..table.delta.len() {
+        for right in left + 1..table.delta.len() {
+            if let Some(word) = distinguishing_suffix(table, left, right) {
+                if !words.contains(&word) {
+                    words.push(word);
+                }
+            }
+        }
+    }
+
+    words
+}
+
+fn distinguishing_suffix<A: Eq + Hash, S: Eq + Hash>(table: &DfaTable<'_, A, S>, left: usize, right: usize) -> Option<Vec<usize>> {
+    let mut parents = HashMap::from([((left, right), None)]);
+    let mut queue = VecDeque::from([(left, right)]);
+
+    while let Some(pair @ (lhs, rhs)) = queue.pop_front() {
+        if table.is_final(lhs) != table.is_final(rhs) {
+            let mut word = Vec::new();
+            let mut current = pair;
+
+            while let Some(&Some((previous, sym))) = parents.get(&current) {
+                word.push(sym);
+                current = previous;
+            }
+
+            word.reverse();
+
+            return Some(word);
+        }
+
+        for sym in 0..table.symbols.len() {
+            let next = (table.delta[lhs][sym], table.delta[rhs][sym]);
+
+            if let std::collections::hash_map::Entry::Vacant(entry) = parents.entry(next) {
+                entry.insert(Some((pair, sym)));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
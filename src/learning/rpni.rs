@@ -0,0 +1,176 @@
+use std::hash::Hash;
+
+use crate::automata::DFA;
+use crate::automata::dfa::ERR_UNDEFINED_SYMBOL;
+use crate::model::Σ;
+use crate::UNREACHABLE_ERR;
+
+pub const ERR_INCONSISTENT_SAMPLES: &str = "A word must not be both a positive and a negative sample";
+
+impl<A: Clone + Eq + Hash> DFA<A, usize> {
+    /// Prefix tree acceptor of the positive samples, accepting exactly them, with
+    /// a state per prefix of the samples, tagged in shortlex order of the prefixes,
+    /// and a rejecting dead state after them for the words leaving the tree
+    ///
+    /// # Errors
+    #[allow(non_snake_case)]
+    pub fn prefix_tree_acceptor(Σ: &Σ<A>, positive: &[Vec<A>]) -> Result<Self, &'static str> {
+        let positive = indices(Σ, positive)?;
+
+        Ok(Tree::new(Σ.len(), &positive).to_dfa(Σ))
+    }
+
+    /// Automaton consistent with the samples, accepting the positive and rejecting
+    /// the negative ones, learned passively with RPNI
+    ///
+    /// States of the prefix tree acceptor of the positive samples are visited in
+    /// shortlex order of their prefixes, each is merged with the first of the
+    /// states kept before it whose merge, folded to stay deterministic, still
+    /// rejects every negative sample, or kept if there is none, states are tagged
+    /// in order of their prefixes and words leaving the automaton reach a dead state
+    ///
+    /// # Errors
+    #[allow(non_snake_case)]
+    pub fn rpni(Σ: &Σ<A>, positive: &[Vec<A>], negative: &[Vec<A>]) -> Result<Self, &'static str> {
+        let positive = indices(Σ, positive)?;
+        let negative = indices(Σ, negative)?;
+
+        if negative.iter().any(|word| positive.contains(word)) {
+            return Err(ERR_INCONSISTENT_SAMPLES);
+        }
+
+        let mut tree = Tree::new(Σ.len(), &positive);
+        let mut red = vec![0];
+
+        // blue states, successors of red states which are not red, in canonical order
+        while let Some(blue) = (0..tree.delta.len())
+            .filter(|state| tree.find(*state) == *state && !red.contains(state))
+            .find(|state| red.iter().any(|red| tree.delta[*red].iter().flatten().any(|next| tree.find(*next) == *state)))
+        {
+            let merged = red.iter().find_map(|red| {
+                let mut merged = tree.clone();
+
+                merged.merge(*red, blue);
+
+                negative.iter().all(|word| !merged.accepts(word)).then_some(merged)
+            });
+
+            match merged {
+                Some(merged) => tree = merged,
+                None => red.push(blue),
+            }
+        }
+
+        Ok(tree.to_dfa(Σ))
+    }
+}
+
+// partial deterministic automaton of the prefixes of the samples, numbered in
+// shortlex order, whose states are merged into classes named by their first
+// state, each class holding the transitions and finality of its states
+#[derive(Clone)]
+struct Tree {
+    classes: Vec<usize>,
+    delta: Vec<Vec<Option<usize>>>,
+    finals: Vec<bool>,
+}
+
+impl Tree {
+    fn new(symbols: usize, positive: &[Vec<usize>]) -> Self {
+        let mut prefixes = positive.iter()
+            .flat_map(|word| (0..=word.len()).map(|len| &word[..len]))
+            .chain([&[][..]])
+            .collect::<Vec<_>>();
+
+        prefixes.sort_unstable_by(|lhs, rhs| lhs.len().cmp(&rhs.len()).then_with(|| lhs.cmp(rhs)));
+        prefixes.dedup();
+
+        let mut delta = vec![vec![None; symbols]; prefixes.len()];
+
+        for (state, prefix) in prefixes.iter().enumerate().skip(1) {
+            let (sym, parent) = prefix.split_last().expect(UNREACHABLE_ERR);
+            let parent = prefixes.binary_search_by(|other| other.len().cmp(&parent.len()).then_with(|| (*other).cmp(parent)))
+                .expect(UNREACHABLE_ERR);
+
+            delta[parent][*sym] = Some(state);
+        }
+
+        let finals = prefixes.iter().map(|prefix| positive.iter().any(|word| word == prefix)).collect();
+
+        Self { classes: (0..prefixes.len()).collect(), delta, finals }
+    }
+
+    fn find(&self, state: usize) -> usize {
+        let mut state = state;
+
+        while self.classes[state] != state {
+            state = self.classes[state];
+        }
+
+        state
+    }
+
+    // merges the class of the second state into the class of the first, folding
+    // the classes their transitions lead to so the automaton stays deterministic
+    fn merge(&mut self, into: usize, from: usize) {
+        let mut pending = vec![(into, from)];
+
+        while let Some((into, from)) = pending.pop() {
+            let (into, from) = (self.find(into), self.find(from));
+
+            if into == from {
+                continue;
+            }
+
+            self.classes[from] = into;
+            self.finals[into] |= self.finals[from];
+
+            for sym in 0..self.delta[from].len() {
+                match (self.delta[into][sym], self.delta[from][sym]) {
+                    (Some(lhs), Some(rhs)) => pending.push((lhs, rhs)),
+                    (None, next @ Some(_)) => self.delta[into][sym] = next,
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn accepts(&self, word: &[usize]) -> bool {
+        word.iter()
+            .try_fold(self.find(0), |state, sym| self.delta[state][*sym].map(|next| self.find(next)))
+            .is_some_and(|state| self.finals[state])
+    }
+
+    // automaton of the classes, in order of their first state, completed with a dead state
+    #[allow(non_snake_case)]
+    fn to_dfa<A: Clone + Eq + Hash>(&self, Σ: &Σ<A>) -> DFA<A, usize> {
+        let classes = (0..self.delta.len()).filter(|state| self.find(*state) == *state).collect::<Vec<_>>();
+        let dead = classes.len();
+
+        let delta = classes.iter()
+            .map(|class| self.delta[*class].iter()
+                .map(|next| next.map_or(dead, |next| classes.binary_search(&self.find(next)).expect(UNREACHABLE_ERR)))
+                .collect())
+            .chain([vec![dead; Σ.len()]])
+            .collect::<Vec<_>>();
+
+        let states = classes.iter()
+            .map(|class| self.finals[*class])
+            .chain([false])
+            .enumerate()
+            .map(|(idx, r#final)| (vec![idx], r#final))
+            .collect();
+
+        DFA::from_table(Σ.clone(), states, 0, &delta)
+    }
+}
+
+#[allow(non_snake_case)]
+fn indices<A: Eq>(Σ: &Σ<A>, words: &[Vec<A>]) -> Result<Vec<Vec<usize>>, &'static str> {
+    words.iter()
+        .map(|word| word.iter()
+            .map(|sym| Σ.iter().position(|other| other == sym))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(ERR_UNDEFINED_SYMBOL))
+        .collect()
+}
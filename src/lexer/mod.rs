@@ -0,0 +1,254 @@
+//! Lexers splitting input into tokens by prioritized rules, each rule an [`NFA`],
+//! possibly given by a [`Regex`]
+//!
+//! The rules are compiled into a single [`DFA`] whose final states are tagged
+//! with the rule winning there, the first of the rules matching, and input is
+//! tokenized by maximal munch, reading the longest match at each position
+//!
+//! [`DFA`]: crate::automata::DFA
+//! [`NFA`]: crate::automata::NFA
+
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+use std::ops::Range;
+
+pub use regex::Regex;
+
+use crate::automata::{DFA, NFA};
+use crate::automata::explain::live_states;
+use crate::model::{State, Σ};
+
+pub(crate) mod regex;
+
+pub(crate) const ERR_NO_RULES: &str = "Lexer must have at least one rule";
+
+/// Token read by a [`Lexer`], with the span of its symbols in the input
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Token<K> {
+    /// Longest match at its position, of the first rule matching it
+    Match {
+        /// Kind of the winning rule
+        kind: K,
+
+        /// Positions of the matched symbols
+        span: Range<usize>,
+    },
+
+    /// Symbols no rule matches at, up to the next position a rule matches at
+    Unrecognized {
+        /// Positions of the unrecognized symbols
+        span: Range<usize>,
+    },
+}
+
+impl<K> Token<K> {
+    /// Kind of the rule matching this token, `None` if it is unrecognized
+    #[must_use]
+    pub const fn kind(&self) -> Option<&K> {
+        match self {
+            Self::Match { kind, .. } => Some(kind),
+            Self::Unrecognized { .. } => None,
+        }
+    }
+
+    /// Positions of the symbols of this token in the input
+    #[must_use]
+    pub const fn span(&self) -> &Range<usize> {
+        match self {
+            Self::Match { span, .. } | Self::Unrecognized { span } => span,
+        }
+    }
+}
+
+/// Tokenizer of input by an ordered list of rules, the longest match winning and
+/// the first rule breaking ties, compiled into a single [`DFA`]
+pub struct Lexer<A, K> {
+    dfa: DFA<A, usize>,
+    kinds: Vec<K>,
+    // winning rule, of each state of the dfa by its tag
+    winners: Vec<Option<usize>>,
+    // index based view of the dfa by tag, δ[state][symbol], for scanning
+    symbols: HashMap<A, usize>,
+    delta: Vec<Vec<usize>>,
+    live: Vec<bool>,
+}
+
+impl<A: Clone + Eq + Hash, K> Lexer<A, K> {
+    /// Lexer of the rules in order of priority, over the symbols of all of their
+    /// alphabets, matches must not be empty so a rule accepting ε never matches it
+    ///
+    /// # Errors
+    pub fn new<S: Clone + Eq + Hash>(rules: Vec<(K, NFA<A, S>)>) -> Result<Self, &'static str> {
+        if rules.is_empty() {
+            return Err(ERR_NO_RULES);
+        }
+
+        let (kinds, nfas): (Vec<_>, Vec<_>) = rules.into_iter().unzip();
+        let tables = nfas.iter().map(NFA::table).collect::<Vec<_>>();
+
+        let mut symbols: Vec<A> = Vec::new();
+
+        for sym in tables.iter().flat_map(|table| table.symbols) {
+            if !symbols.contains(sym) {
+                symbols.push(sym.clone());
+            }
+        }
+
+        // symbol of each rule for each symbol of the lexer
+        let rule_symbols = tables.iter()
+            .map(|table| symbols.iter().map(|sym| table.symbols.iter().position(|other| other == sym)).collect())
+            .collect::<Vec<Vec<_>>>();
+
+        // subset construction over the states of all rules, (rule, state)
+        let initial = tables.iter().enumerate().map(|(rule, table)| (rule, table.initial)).collect::<Vec<_>>();
+        let mut subsets = vec![initial.clone()];
+        let mut index = HashMap::from([(initial, 0)]);
+        let mut delta = Vec::new();
+
+        while delta.len() < subsets.len() {
+            let current = delta.len();
+
+            let transitions = (0..symbols.len())
+                .map(|sym| {
+                    let mut next = subsets[current].iter()
+                        .flat_map(|(rule, state)| rule_symbols[*rule][sym]
+                            .into_iter()
+                            .flat_map(|sym| tables[*rule].delta[*state][sym].iter().map(|next| (*rule, *next))))
+                        .collect::<Vec<_>>();
+
+                    next.sort_unstable();
+                    next.dedup();
+
+                    *index.entry(next).or_insert_with_key(|next| {
+                        subsets.push(next.clone());
+                        subsets.len() - 1
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            delta.push(transitions);
+        }
+
+        let winners = subsets.iter()
+            .map(|subset| subset.iter().filter(|(rule, state)| tables[*rule].is_final(*state)).map(|(rule, _)| *rule).min())
+            .collect::<Vec<_>>();
+
+        let states = winners.iter().enumerate().map(|(idx, winner)| (vec![idx], winner.is_some())).collect();
+        let dfa = DFA::from_table(Σ::new(symbols.clone())?, states, 0, &delta);
+        let table = dfa.table();
+        let mut live = vec![false; subsets.len()];
+
+        for (state, is_live) in table.states.iter().zip(live_states(&table)) {
+            live[state[0]] = is_live;
+        }
+
+        Ok(Self {
+            dfa,
+            kinds,
+            winners,
+            symbols: symbols.into_iter().enumerate().map(|(idx, sym)| (sym, idx)).collect(),
+            delta,
+            live,
+        })
+    }
+
+    /// [`DFA`] the rules are compiled into, each state tagged with its number, its
+    /// final states being those where a rule matches, see [`Lexer::rule`]
+    #[must_use]
+    pub const fn dfa(&self) -> &DFA<A, usize> {
+        &self.dfa
+    }
+
+    /// Kind of the rule winning in a state of the compiled [`DFA`], `None` if no
+    /// rule matches there
+    #[must_use]
+    pub fn rule(&self, state: &State<usize>) -> Option<&K> {
+        state.first()
+            .and_then(|idx| self.winners.get(*idx))
+            .copied()
+            .flatten()
+            .map(|rule| &self.kinds[rule])
+    }
+
+    /// Kind and length of the longest match at the start of the input, `None` if
+    /// no rule matches a non empty prefix of it
+    #[must_use]
+    pub fn longest_match(&self, input: &[A]) -> Option<(&K, usize)> {
+        let mut state = 0;
+        let mut longest = None;
+
+        for (idx, sym) in input.iter().enumerate() {
+            let Some(sym) = self.symbols.get(sym) else { break };
+
+            state = self.delta[state][*sym];
+
+            if !self.live[state] {
+                break;
+            } else if let Some(rule) = self.winners[state] {
+                longest = Some((&self.kinds[rule], idx + 1));
+            }
+        }
+
+        longest
+    }
+}
+
+impl<A: Clone + Eq + Hash, K: Clone> Lexer<A, K> {
+    /// Tokens of the input by maximal munch, the symbols where no rule matches
+    /// are skipped one at a time and reported together as unrecognized, so
+    /// tokenizing always reaches the end of the input
+    #[must_use]
+    pub fn tokenize(&self, input: &[A]) -> Vec<Token<K>> {
+        let mut tokens = Vec::new();
+        let mut unrecognized = None;
+        let mut position = 0;
+
+        while position < input.len() {
+            if let Some((kind, length)) = self.longest_match(&input[position..]) {
+                if let Some(start) = unrecognized.take() {
+                    tokens.push(Token::Unrecognized { span: start..position });
+                }
+
+                tokens.push(Token::Match { kind: kind.clone(), span: position..position + length });
+                position += length;
+            } else {
+                unrecognized.get_or_insert(position);
+                position += 1;
+            }
+        }
+
+        if let Some(start) = unrecognized {
+            tokens.push(Token::Unrecognized { span: start..position });
+        }
+
+        tokens
+    }
+}
+
+impl<K: Clone> Lexer<char, K> {
+    /// Tokens of the text by maximal munch, see [`Lexer::tokenize`], with spans
+    /// of byte offsets, so they slice the text
+    #[must_use]
+    pub fn tokenize_str(&self, input: &str) -> Vec<Token<K>> {
+        let (offsets, chars): (Vec<_>, Vec<_>) = input.char_indices().unzip();
+        let offset = |position: usize| offsets.get(position).copied().unwrap_or(input.len());
+
+        self.tokenize(&chars)
+            .into_iter()
+            .map(|token| match token {
+                Token::Match { kind, span } => Token::Match { kind, span: offset(span.start)..offset(span.end) },
+                Token::Unrecognized { span } => Token::Unrecognized { span: offset(span.start)..offset(span.end) },
+            })
+            .collect()
+    }
+}
+
+impl<A: Debug, K: Debug> Debug for Lexer<A, K> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Lexer")
+            .field("rules", &self.kinds)
+            .field("dfa", &self.dfa)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,297 @@
+use std::str::FromStr;
+
+use crate::automata::NFA;
+use crate::formats::ParseError;
+use crate::model::Σ;
+use crate::UNREACHABLE_ERR;
+
+pub const ERR_EXPECTED_CLOSING_BRACKET: &str = "Expected a closing bracket";
+pub const ERR_EXPECTED_CLOSING_PARENTHESIS: &str = "Expected a closing parenthesis";
+pub const ERR_EXPECTED_EXPRESSION: &str = "Expected an expression";
+pub const ERR_INVALID_RANGE: &str = "Range must not end before it starts";
+pub const ERR_TRAILING_ESCAPE: &str = "Expected a character after the escape";
+pub const ERR_UNEXPECTED_CLOSING_PARENTHESIS: &str = "Unexpected closing parenthesis";
+
+/// Regular expression over chars, defining a rule of a [`Lexer`](crate::lexer::Lexer)
+///
+/// Expressions are chars, escaped chars `\*`, `\n`, `\t` and `\r`, the classes
+/// `\d`, `\w` and `\s`, of ASCII digits, word chars and whitespace, bracketed
+/// classes `[a-z_]`, groups `(ab)`, repetitions `*`, `+` and `?`, concatenation
+/// and alternation `|`, from the strongest to the weakest binding, there is no
+/// wildcard, the alphabet of the expression being the chars it mentions
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Regex(Node);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Node {
+    Class(Vec<char>),
+    Concat(Vec<Self>),
+    Alternation(Vec<Self>),
+    Star(Box<Self>),
+    Plus(Box<Self>),
+    Optional(Box<Self>),
+}
+
+impl Regex {
+    /// [`NFA`] accepting the language of this expression, built with the Glushkov
+    /// construction without ε transitions, state 0 being initial and each other
+    /// state the position of a class in the expression, from left to right
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)] // every expression has at least one class
+    pub fn to_nfa(&self) -> NFA<char, usize> {
+        let mut classes = Vec::new();
+        let mut follow = Vec::new();
+        let positions = glushkov(&self.0, &mut classes, &mut follow);
+
+        for positions in &mut follow {
+            positions.sort_unstable();
+            positions.dedup();
+        }
+
+        let mut symbols = classes.iter().flatten().copied().collect::<Vec<_>>();
+
+        symbols.sort_unstable();
+        symbols.dedup();
+
+        let next = |candidates: &[usize]| symbols.iter()
+            .map(|sym| candidates.iter().filter(|position| classes[**position].contains(sym)).map(|position| position + 1).collect())
+            .collect::<Vec<_>>();
+
+        let delta = std::iter::once(next(&positions.first))
+            .chain(follow.iter().map(|positions| next(positions)))
+            .collect::<Vec<_>>();
+
+        let states = std::iter::once((0, positions.nullable))
+            .chain((0..classes.len()).map(|position| (position + 1, positions.last.contains(&position))))
+            .collect();
+
+        NFA::from_table(Σ::new(symbols).expect(UNREACHABLE_ERR), states, 0, &delta)
+    }
+}
+
+impl FromStr for Regex {
+    type Err = ParseError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { chars: source.chars().collect(), position: 0 };
+        let node = parser.alternation()?;
+
+        if parser.position < parser.chars.len() {
+            // only an unmatched closing parenthesis stops an alternation early
+            Err(parser.error(ERR_UNEXPECTED_CLOSING_PARENTHESIS))
+        } else {
+            Ok(Self(node))
+        }
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn alternation(&mut self) -> Result<Node, ParseError> {
+        let mut alternatives = vec![self.concatenation()?];
+
+        while self.accept('|') {
+            alternatives.push(self.concatenation()?);
+        }
+
+        Ok(if alternatives.len() == 1 { alternatives.remove(0) } else { Node::Alternation(alternatives) })
+    }
+
+    fn concatenation(&mut self) -> Result<Node, ParseError> {
+        let mut factors = Vec::new();
+
+        while self.peek().is_some_and(|chr| chr != '|' && chr != ')') {
+            factors.push(self.repetition()?);
+        }
+
+        match factors.len() {
+            0 => Err(self.error(ERR_EXPECTED_EXPRESSION)),
+            1 => Ok(factors.remove(0)),
+            _ => Ok(Node::Concat(factors)),
+        }
+    }
+
+    fn repetition(&mut self) -> Result<Node, ParseError> {
+        let mut node = self.atom()?;
+
+        loop {
+            node = if self.accept('*') {
+                Node::Star(Box::new(node))
+            } else if self.accept('+') {
+                Node::Plus(Box::new(node))
+            } else if self.accept('?') {
+                Node::Optional(Box::new(node))
+            } else {
+                return Ok(node);
+            };
+        }
+    }
+
+    fn atom(&mut self) -> Result<Node, ParseError> {
+        let chr = self.peek().ok_or_else(|| self.error(ERR_EXPECTED_EXPRESSION))?;
+
+        match chr {
+            '(' => {
+                self.position += 1;
+
+                let node = self.alternation()?;
+
+                if self.accept(')') {
+                    Ok(node)
+                } else {
+                    Err(self.error(ERR_EXPECTED_CLOSING_PARENTHESIS))
+                }
+            }
+            '[' => {
+                self.position += 1;
+                self.class()
+            }
+            '*' | '+' | '?' | ']' => Err(self.error(ERR_EXPECTED_EXPRESSION)),
+            _ => Ok(Node::Class(self.member()?)),
+        }
+    }
+
+    // bracketed class, after its opening bracket
+    fn class(&mut self) -> Result<Node, ParseError> {
+        let mut chars = Vec::new();
+
+        while !self.accept(']') {
+            let start = self.position;
+            let first = self.member()?;
+
+            if first.len() == 1 && self.peek() == Some('-') && self.chars.get(self.position + 1).is_some_and(|chr| *chr != ']') {
+                self.position += 1;
+
+                let last = self.member()?;
+
+                if last.len() != 1 || last[0] < first[0] {
+                    self.position = start;
+
+                    return Err(self.error(ERR_INVALID_RANGE));
+                }
+
+                chars.extend(first[0]..=last[0]);
+            } else {
+                chars.extend(first);
+            }
+        }
+
+        chars.sort_unstable();
+        chars.dedup();
+
+        if chars.is_empty() {
+            self.position -= 1;
+
+            return Err(self.error(ERR_EXPECTED_EXPRESSION));
+        }
+
+        Ok(Node::Class(chars))
+    }
+
+    // chars of a single, possibly escaped, char or of a class escape
+    fn member(&mut self) -> Result<Vec<char>, ParseError> {
+        let chr = self.peek().ok_or_else(|| self.error(ERR_EXPECTED_CLOSING_BRACKET))?;
+
+        self.position += 1;
+
+        if chr != '\\' {
+            return Ok(vec![chr]);
+        }
+
+        let escaped = self.peek().ok_or_else(|| self.error(ERR_TRAILING_ESCAPE))?;
+
+        self.position += 1;
+
+        Ok(match escaped {
+            'd' => ('0'..='9').collect(),
+            'w' => ('0'..='9').chain('A'..='Z').chain(['_']).chain('a'..='z').collect(),
+            's' => vec!['\t', '\n', '\r', ' '],
+            'n' => vec!['\n'],
+            't' => vec!['\t'],
+            'r' => vec!['\r'],
+            _ => vec![escaped],
+        })
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn accept(&mut self, chr: char) -> bool {
+        let accepted = self.peek() == Some(chr);
+
+        if accepted {
+            self.position += 1;
+        }
+
+        accepted
+    }
+
+    const fn error(&self, message: &'static str) -> ParseError {
+        ParseError { line: 1, column: self.position + 1, message }
+    }
+}
+
+// nullability and first and last positions of a node
+struct Positions {
+    nullable: bool,
+    first: Vec<usize>,
+    last: Vec<usize>,
+}
+
+// numbers the classes of the node from left to right, collecting the positions
+// that may follow each position
+fn glushkov(node: &Node, classes: &mut Vec<Vec<char>>, follow: &mut Vec<Vec<usize>>) -> Positions {
+    match node {
+        Node::Class(chars) => {
+            classes.push(chars.clone());
+            follow.push(Vec::new());
+
+            let position = classes.len() - 1;
+
+            Positions { nullable: false, first: vec![position], last: vec![position] }
+        }
+        Node::Concat(nodes) => {
+            let mut positions = Positions { nullable: true, first: Vec::new(), last: Vec::new() };
+
+            for node in nodes {
+                let next = glushkov(node, classes, follow);
+
+                for position in &positions.last {
+                    follow[*position].extend(&next.first);
+                }
+
+                if positions.nullable {
+                    positions.first.extend(&next.first);
+                }
+
+                positions.last = if next.nullable { [positions.last, next.last].concat() } else { next.last };
+                positions.nullable &= next.nullable;
+            }
+
+            positions
+        }
+        Node::Alternation(nodes) => nodes.iter()
+            .map(|node| glushkov(node, classes, follow))
+            .reduce(|lhs, rhs| Positions {
+                nullable: lhs.nullable || rhs.nullable,
+                first: [lhs.first, rhs.first].concat(),
+                last: [lhs.last, rhs.last].concat(),
+            })
+            .expect(UNREACHABLE_ERR),
+        Node::Star(inner) | Node::Plus(inner) => {
+            let positions = glushkov(inner, classes, follow);
+
+            for position in &positions.last {
+                follow[*position].extend(&positions.first);
+            }
+
+            Positions { nullable: positions.nullable || matches!(node, Node::Star(_)), ..positions }
+        }
+        Node::Optional(inner) => Positions { nullable: true, ..glushkov(inner, classes, follow) },
+    }
+}
@@ -11,12 +11,25 @@
 // ==============================================================
 #![doc(html_root_url = "https://docs.rs/finite-state-machine/0.1.0")]
 
+//! Library of finite automata implementations
 //!
+//! * [`model`], the components Q, Σ, δ, q0 and F defining an automaton
+//! * [`automata`], deterministic and nondeterministic finite automata
+//! * [`formats`], textual representations used to read, write and export automata
+//! * [`learning`], active and passive learning of automata from black box systems and samples
+//! * [`lexer`], tokenizers by prioritized rules compiled into a single automaton
+//! * [`ltl`], linear temporal logic formulas and their translation to automata
+//! * [`random`], seedable pseudo random numbers used to sample automata
 
 extern crate core;
 
 pub mod automata;
+pub mod formats;
+pub mod learning;
+pub mod lexer;
+pub mod ltl;
 pub mod model;
+pub mod random;
 mod utils;
 
 #[cfg(test)]
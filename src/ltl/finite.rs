@@ -0,0 +1,49 @@
+use std::collections::BTreeSet;
+
+use crate::automata::{DFA, NFA};
+use crate::ltl::{Formula, Valuation};
+use crate::ltl::tableau::{expand, explore, name, Nnf};
+
+impl Formula {
+    /// Nondeterministic finite automaton accepting the finite non empty traces
+    /// satisfying this formula under `LTLf` semantics, over the valuations of its
+    /// propositions
+    ///
+    /// `X φ` requires a next position while its negation, `!X !φ`, holds at the
+    /// last one. States are tagged with the obligations left to the following
+    /// positions, wrapped in either, final when none requires a next position
+    ///
+    /// # Errors
+    /// a formula with more than [`MAX_PROPOSITIONS`](crate::ltl::MAX_PROPOSITIONS) propositions
+    #[allow(non_snake_case)]
+    pub fn to_ltlf_nfa(&self) -> Result<NFA<Valuation, String>, &'static str> {
+        let Σ = self.valuations()?;
+        let initial = BTreeSet::from([Nnf::Next(Box::new(Nnf::of(self, false, true)))]);
+
+        let (nodes, delta) = explore(&Σ, initial, |state| {
+            expand(state, true).into_iter()
+                .map(|branch| {
+                    let next = branch.next.clone();
+
+                    (branch, next)
+                })
+                .collect()
+        });
+
+        let states = nodes.into_iter()
+            .map(|state| (name(state.iter()), state.iter().all(|formula| matches!(formula, Nnf::WeakNext(_)))))
+            .collect();
+
+        Ok(NFA::from_table(Σ, states, 0, &delta))
+    }
+
+    /// Deterministic finite automaton accepting the finite non empty traces
+    /// satisfying this formula under `LTLf` semantics, the subset construction of
+    /// [`Formula::to_ltlf_nfa`]
+    ///
+    /// # Errors
+    /// a formula with more than [`MAX_PROPOSITIONS`](crate::ltl::MAX_PROPOSITIONS) propositions
+    pub fn to_ltlf_dfa(&self) -> Result<DFA<Valuation, String>, &'static str> {
+        Ok(self.to_ltlf_nfa()?.determinize())
+    }
+}
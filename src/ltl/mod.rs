@@ -0,0 +1,200 @@
+//! Linear temporal logic, LTL, formulas over atomic propositions, and their
+//! translation to automata
+//!
+//! A [`Formula`] is read from its notation, atoms, `true`, `false`, `!`, `&&`, `||`,
+//! `X`, `U`, `R`, `F` and `G`, and translated with a tableau to a [`Buchi`] automaton
+//! accepting its infinite models, or under finite trace semantics, `LTLf`, to an
+//! [`NFA`] or a [`DFA`] accepting its finite non empty models
+//!
+//! Automata read [`Valuation`]s, the propositions holding at each position of a
+//! trace, over the propositions of the formula, in ascending order of the bits
+//! of the propositions sorted by name, `{}`, `{a}`, `{b}`, `{a, b}`
+//!
+//! [`Buchi`]: crate::automata::Buchi
+//! [`DFA`]: crate::automata::DFA
+//! [`NFA`]: crate::automata::NFA
+
+use std::collections::BTreeSet;
+use std::fmt::{self, Display, Formatter};
+use std::ops::Deref;
+
+use crate::model::Σ;
+use crate::UNREACHABLE_ERR;
+
+pub(crate) mod finite;
+pub(crate) mod parser;
+pub(crate) mod tableau;
+
+pub(crate) const ERR_TOO_MANY_PROPOSITIONS: &str = "Formula has more atomic propositions than supported";
+
+/// Maximum number of atomic propositions of a translated formula, its alphabet
+/// having a symbol per valuation
+pub const MAX_PROPOSITIONS: usize = 12;
+
+/// LTL formula over atomic propositions
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Formula {
+    /// Holds on every trace
+    True,
+
+    /// Holds on no trace
+    False,
+
+    /// Atomic proposition holding at the first position
+    Atom(String),
+
+    /// Negation, `!φ`
+    Not(Box<Self>),
+
+    /// Conjunction, `φ && ψ`
+    And(Box<Self>, Box<Self>),
+
+    /// Disjunction, `φ || ψ`
+    Or(Box<Self>, Box<Self>),
+
+    /// Next, `X φ`, φ holds at the next position
+    Next(Box<Self>),
+
+    /// Until, `φ U ψ`, ψ eventually holds and φ holds until then
+    Until(Box<Self>, Box<Self>),
+
+    /// Release, `φ R ψ`, ψ holds up to and including the first position where φ holds, if any
+    Release(Box<Self>, Box<Self>),
+
+    /// Eventually, `F φ`, φ holds at some position
+    Eventually(Box<Self>),
+
+    /// Always, `G φ`, φ holds at every position
+    Always(Box<Self>),
+}
+
+impl Formula {
+    /// Atomic propositions of this formula, sorted by name
+    #[must_use]
+    pub fn propositions(&self) -> Vec<&str> {
+        let mut propositions = BTreeSet::new();
+        let mut pending = vec![self];
+
+        while let Some(formula) = pending.pop() {
+            match formula {
+                Self::True | Self::False => {}
+                Self::Atom(name) => {
+                    propositions.insert(name.as_str());
+                }
+                Self::Not(operand) | Self::Next(operand) | Self::Eventually(operand) | Self::Always(operand) =>
+                    pending.push(operand),
+                Self::And(lhs, rhs) | Self::Or(lhs, rhs) | Self::Until(lhs, rhs) | Self::Release(lhs, rhs) =>
+                    pending.extend([lhs.as_ref(), rhs.as_ref()]),
+            }
+        }
+
+        propositions.into_iter().collect()
+    }
+
+    // every valuation of the propositions of this formula, in ascending order
+    fn valuations(&self) -> Result<Σ<Valuation>, &'static str> {
+        let propositions = self.propositions();
+
+        if propositions.len() > MAX_PROPOSITIONS {
+            return Err(ERR_TOO_MANY_PROPOSITIONS);
+        }
+
+        let valuations = (0..1_usize << propositions.len())
+            .map(|bits| Valuation::new(
+                propositions.iter().enumerate().filter(|(idx, _)| bits >> idx & 1 == 1).map(|(_, name)| *name)
+            ))
+            .collect();
+
+        Ok(Σ::new(valuations).expect(UNREACHABLE_ERR))
+    }
+
+    const fn precedence(&self) -> u8 {
+        match self {
+            Self::Or(..) => 1,
+            Self::And(..) => 2,
+            Self::Until(..) | Self::Release(..) => 3,
+            Self::Not(_) | Self::Next(_) | Self::Eventually(_) | Self::Always(_) => 4,
+            Self::True | Self::False | Self::Atom(_) => 5,
+        }
+    }
+}
+
+impl Display for Formula {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        // operands binding weaker than, or as weak as when strict, the operator are parenthesized
+        let binary_operand = |fmt: &mut Formatter<'_>, operand: &Self, strict: bool| {
+            if operand.precedence() < self.precedence() || strict && operand.precedence() == self.precedence() {
+                write!(fmt, "({operand})")
+            } else {
+                write!(fmt, "{operand}")
+            }
+        };
+
+        let (operator, lhs, rhs) = match self {
+            Self::True => return write!(fmt, "true"),
+            Self::False => return write!(fmt, "false"),
+            Self::Atom(name) => return write!(fmt, "{name}"),
+            Self::Not(operand) => return write!(fmt, "!").and_then(|()| unary_operand(fmt, operand)),
+            Self::Next(operand) => return write!(fmt, "X ").and_then(|()| unary_operand(fmt, operand)),
+            Self::Eventually(operand) => return write!(fmt, "F ").and_then(|()| unary_operand(fmt, operand)),
+            Self::Always(operand) => return write!(fmt, "G ").and_then(|()| unary_operand(fmt, operand)),
+            Self::And(lhs, rhs) => ("&&", lhs, rhs),
+            Self::Or(lhs, rhs) => ("||", lhs, rhs),
+            Self::Until(lhs, rhs) => ("U", lhs, rhs),
+            Self::Release(lhs, rhs) => ("R", lhs, rhs),
+        };
+
+        // conjunctions and disjunctions associate to the left, until and release to the right
+        let right = matches!(self, Self::Until(..) | Self::Release(..));
+
+        binary_operand(fmt, lhs, right)?;
+        write!(fmt, " {operator} ")?;
+        binary_operand(fmt, rhs, !right)
+    }
+}
+
+// operand of a unary operator, parenthesized if binary
+fn unary_operand(fmt: &mut Formatter<'_>, operand: &Formula) -> fmt::Result {
+    if operand.precedence() < 4 {
+        write!(fmt, "({operand})")
+    } else {
+        write!(fmt, "{operand}")
+    }
+}
+
+/// Valuation of atomic propositions, the set of propositions holding at a
+/// position of a trace
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Valuation(BTreeSet<String>);
+
+impl Valuation {
+    /// Valuation in which exactly the given propositions hold
+    pub fn new<P: Into<String>>(propositions: impl IntoIterator<Item=P>) -> Self {
+        Self(propositions.into_iter().map(Into::into).collect())
+    }
+
+    /// Does the proposition hold in this valuation
+    #[must_use]
+    pub fn holds(&self, proposition: &str) -> bool {
+        self.0.contains(proposition)
+    }
+}
+
+impl Deref for Valuation {
+    type Target = BTreeSet<String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+// holding propositions joined by &, or {} if none holds, as symbols are named in HOA
+impl Display for Valuation {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            write!(fmt, "{{}}")
+        } else {
+            write!(fmt, "{}", self.0.iter().map(String::as_str).collect::<Vec<_>>().join("&"))
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use std::str::FromStr;
+
+use crate::formats::ParseError;
+use crate::ltl::Formula;
+
+pub const ERR_EXPECTED_CLOSING_PARENTHESIS: &str = "Expected a closing parenthesis";
+pub const ERR_EXPECTED_FORMULA: &str = "Expected a formula";
+pub const ERR_UNEXPECTED_CHARACTER: &str = "Unexpected character";
+pub const ERR_UNEXPECTED_TOKEN: &str = "Unexpected token after the formula";
+
+impl FromStr for Formula {
+    type Err = ParseError;
+
+    /// Reads a formula, binary operators binding weaker than unary ones, from the
+    /// weakest, `||` and `&&`, associating to the left, then `U` and `R`, associating
+    /// to the right
+    ///
+    /// Propositions are words of letters, digits and underscores other than the
+    /// operators `X`, `U`, `R`, `F`, `G` and the constants `true` and `false`, so
+    /// operators are separated from their operands, `F a` or `F(a)`
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { lexemes: tokenize(source)?, position: 0, end: end(source) };
+        let formula = parser.disjunction()?;
+
+        if parser.position < parser.lexemes.len() {
+            Err(parser.error(ERR_UNEXPECTED_TOKEN))
+        } else {
+            Ok(formula)
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+enum Token {
+    And,
+    Close,
+    Not,
+    Open,
+    Or,
+    Word(String),
+}
+
+struct Lexeme {
+    token: Token,
+    line: usize,
+    column: usize,
+}
+
+impl Lexeme {
+    const fn error(&self, message: &'static str) -> ParseError {
+        ParseError { line: self.line, column: self.column, message }
+    }
+}
+
+struct Parser {
+    lexemes: Vec<Lexeme>,
+    position: usize,
+    end: (usize, usize),
+}
+
+impl Parser {
+    fn disjunction(&mut self) -> Result<Formula, ParseError> {
+        let mut formula = self.conjunction()?;
+
+        while self.accept(&Token::Or) {
+            formula = Formula::Or(Box::new(formula), Box::new(self.conjunction()?));
+        }
+
+        Ok(formula)
+    }
+
+    fn conjunction(&mut self) -> Result<Formula, ParseError> {
+        let mut formula = self.binary()?;
+
+        while self.accept(&Token::And) {
+            formula = Formula::And(Box::new(formula), Box::new(self.binary()?));
+        }
+
+        Ok(formula)
+    }
+
+    fn binary(&mut self) -> Result<Formula, ParseError> {
+        let lhs = self.unary()?;
+
+        if self.accept_word("U") {
+            Ok(Formula::Until(Box::new(lhs), Box::new(self.binary()?)))
+        } else if self.accept_word("R") {
+            Ok(Formula::Release(Box::new(lhs), Box::new(self.binary()?)))
+        } else {
+            Ok(lhs)
+        }
+    }
+
+    fn unary(&mut self) -> Result<Formula, ParseError> {
+        let lexeme = self.lexemes.get(self.position).ok_or_else(|| self.error(ERR_EXPECTED_FORMULA))?;
+        let (line, column) = (lexeme.line, lexeme.column);
+
+        self.position += 1;
+
+        let operator: fn(Box<Formula>) -> Formula = match &self.lexemes[self.position - 1].token {
+            Token::Not => Formula::Not,
+            Token::Open => {
+                let formula = self.disjunction()?;
+
+                if !self.accept(&Token::Close) {
+                    return Err(self.error(ERR_EXPECTED_CLOSING_PARENTHESIS));
+                }
+
+                return Ok(formula);
+            }
+            Token::Word(word) => match word.as_str() {
+                "X" => Formula::Next,
+                "F" => Formula::Eventually,
+                "G" => Formula::Always,
+                "true" => return Ok(Formula::True),
+                "false" => return Ok(Formula::False),
+                "U" | "R" => return Err(ParseError { line, column, message: ERR_EXPECTED_FORMULA }),
+                _ => return Ok(Formula::Atom(word.clone())),
+            },
+            Token::And | Token::Close | Token::Or => return Err(ParseError { line, column, message: ERR_EXPECTED_FORMULA }),
+        };
+
+        Ok(operator(Box::new(self.unary()?)))
+    }
+
+    fn accept(&mut self, token: &Token) -> bool {
+        let accepted = self.lexemes.get(self.position).is_some_and(|lexeme| lexeme.token == *token);
+
+        if accepted {
+            self.position += 1;
+        }
+
+        accepted
+    }
+
+    fn accept_word(&mut self, word: &str) -> bool {
+        self.accept(&Token::Word(word.to_string()))
+    }
+
+    // error at the current lexeme, or at the end of the source
+    fn error(&self, message: &'static str) -> ParseError {
+        self.lexemes.get(self.position).map_or_else(
+            || ParseError { line: self.end.0, column: self.end.1, message },
+            |lexeme| lexeme.error(message),
+        )
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Lexeme>, ParseError> {
+    let mut lexemes = Vec::new();
+
+    for (line, text) in source.lines().enumerate() {
+        let chars = text.chars().collect::<Vec<_>>();
+        let mut position = 0;
+
+        while position < chars.len() {
+            let chr = chars[position];
+            let column = position + 1;
+
+            let token = match chr {
+                _ if chr.is_whitespace() => {
+                    position += 1;
+                    continue;
+                }
+                '!' => Token::Not,
+                '(' => Token::Open,
+                ')' => Token::Close,
+                '&' | '|' if chars.get(position + 1) == Some(&chr) => {
+                    position += 1;
+
+                    if chr == '&' { Token::And } else { Token::Or }
+                }
+                _ if chr.is_alphanumeric() || chr == '_' => {
+                    let length = chars[position..].iter().take_while(|chr| chr.is_alphanumeric() || **chr == '_').count();
+                    let word = chars[position..position + length].iter().collect();
+
+                    position += length - 1;
+
+                    Token::Word(word)
+                }
+                _ => return Err(ParseError { line: line + 1, column, message: ERR_UNEXPECTED_CHARACTER }),
+            };
+
+            lexemes.push(Lexeme { token, line: line + 1, column });
+            position += 1;
+        }
+    }
+
+    Ok(lexemes)
+}
+
+// position following the last character of the source
+fn end(source: &str) -> (usize, usize) {
+    source.lines().enumerate().last().map_or((1, 1), |(line, text)| (line + 1, text.chars().count() + 1))
+}
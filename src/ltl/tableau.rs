@@ -0,0 +1,306 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+
+use crate::automata::Buchi;
+use crate::ltl::{Formula, Valuation};
+use crate::model::Σ;
+
+// formula in negation normal form, negations only applying to propositions,
+// with weak next, holding at the last position of a finite trace
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Nnf {
+    True,
+    False,
+    Literal(String, bool),
+    And(Box<Self>, Box<Self>),
+    Or(Box<Self>, Box<Self>),
+    Next(Box<Self>),
+    WeakNext(Box<Self>),
+    Until(Box<Self>, Box<Self>),
+    Release(Box<Self>, Box<Self>),
+    Eventually(Box<Self>),
+    Always(Box<Self>),
+}
+
+impl Nnf {
+    // the formula, negated if given, the negation of next being weak next on
+    // finite traces and next on infinite ones
+    pub fn of(formula: &Formula, negated: bool, finite: bool) -> Self {
+        let of = |formula: &Formula| Box::new(Self::of(formula, negated, finite));
+
+        match (formula, negated) {
+            (Formula::True, false) | (Formula::False, true) => Self::True,
+            (Formula::True, true) | (Formula::False, false) => Self::False,
+            (Formula::Atom(name), _) => Self::Literal(name.clone(), !negated),
+            (Formula::Not(operand), _) => Self::of(operand, !negated, finite),
+            (Formula::And(lhs, rhs), false) | (Formula::Or(lhs, rhs), true) => Self::And(of(lhs), of(rhs)),
+            (Formula::Or(lhs, rhs), false) | (Formula::And(lhs, rhs), true) => Self::Or(of(lhs), of(rhs)),
+            (Formula::Next(operand), true) if finite => Self::WeakNext(of(operand)),
+            (Formula::Next(operand), _) => Self::Next(of(operand)),
+            (Formula::Until(lhs, rhs), false) | (Formula::Release(lhs, rhs), true) => Self::Until(of(lhs), of(rhs)),
+            (Formula::Release(lhs, rhs), false) | (Formula::Until(lhs, rhs), true) => Self::Release(of(lhs), of(rhs)),
+            (Formula::Eventually(operand), false) | (Formula::Always(operand), true) => Self::Eventually(of(operand)),
+            (Formula::Always(operand), false) | (Formula::Eventually(operand), true) => Self::Always(of(operand)),
+        }
+    }
+
+    // until and eventually subformulas, which a run must not postpone forever
+    fn eventualities(&self) -> BTreeSet<&Self> {
+        let mut eventualities = BTreeSet::new();
+        let mut pending = vec![self];
+
+        while let Some(formula) = pending.pop() {
+            match formula {
+                Self::True | Self::False | Self::Literal(..) => {}
+                Self::Next(operand) | Self::WeakNext(operand) | Self::Always(operand) => pending.push(operand),
+                Self::Eventually(operand) => {
+                    eventualities.insert(formula);
+                    pending.push(operand);
+                }
+                Self::Until(lhs, rhs) => {
+                    eventualities.insert(formula);
+                    pending.extend([lhs.as_ref(), rhs.as_ref()]);
+                }
+                Self::And(lhs, rhs) | Self::Or(lhs, rhs) | Self::Release(lhs, rhs) =>
+                    pending.extend([lhs.as_ref(), rhs.as_ref()]),
+            }
+        }
+
+        eventualities
+    }
+
+    // equivalent formula, weak next being the negation of the next of the negation
+    fn formula(&self) -> Formula {
+        let formula = |nnf: &Self| Box::new(nnf.formula());
+
+        match self {
+            Self::True => Formula::True,
+            Self::False => Formula::False,
+            Self::Literal(name, true) => Formula::Atom(name.clone()),
+            Self::Literal(name, false) => Formula::Not(Box::new(Formula::Atom(name.clone()))),
+            Self::And(lhs, rhs) => Formula::And(formula(lhs), formula(rhs)),
+            Self::Or(lhs, rhs) => Formula::Or(formula(lhs), formula(rhs)),
+            Self::Next(operand) => Formula::Next(formula(operand)),
+            Self::WeakNext(operand) => Formula::Not(Box::new(Formula::Next(formula(&operand.negation())))),
+            Self::Until(lhs, rhs) => Formula::Until(formula(lhs), formula(rhs)),
+            Self::Release(lhs, rhs) => Formula::Release(formula(lhs), formula(rhs)),
+            Self::Eventually(operand) => Formula::Eventually(formula(operand)),
+            Self::Always(operand) => Formula::Always(formula(operand)),
+        }
+    }
+
+    fn negation(&self) -> Self {
+        let negation = |nnf: &Self| Box::new(nnf.negation());
+
+        match self {
+            Self::True => Self::False,
+            Self::False => Self::True,
+            Self::Literal(name, value) => Self::Literal(name.clone(), !value),
+            Self::And(lhs, rhs) => Self::Or(negation(lhs), negation(rhs)),
+            Self::Or(lhs, rhs) => Self::And(negation(lhs), negation(rhs)),
+            Self::Next(operand) => Self::WeakNext(negation(operand)),
+            Self::WeakNext(operand) => Self::Next(negation(operand)),
+            Self::Until(lhs, rhs) => Self::Release(negation(lhs), negation(rhs)),
+            Self::Release(lhs, rhs) => Self::Until(negation(lhs), negation(rhs)),
+            Self::Eventually(operand) => Self::Always(negation(operand)),
+            Self::Always(operand) => Self::Eventually(negation(operand)),
+        }
+    }
+}
+
+impl Display for Nnf {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}", self.formula())
+    }
+}
+
+// a way of satisfying obligations at a position of a trace, by the literals holding
+// there, leaving obligations, wrapped in next or weak next, to the following
+// positions, and postponing eventualities to them
+#[derive(Clone, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Branch {
+    literals: BTreeSet<(String, bool)>,
+    pub next: BTreeSet<Nnf>,
+    pub postponed: BTreeSet<Nnf>,
+}
+
+impl Branch {
+    pub fn allows(&self, valuation: &Valuation) -> bool {
+        self.literals.iter().all(|(name, value)| valuation.holds(name) == *value)
+    }
+}
+
+// branches satisfying the obligations of a state, its wrapped formulas, release
+// and always passing themselves on with weak next on finite traces
+pub fn expand(state: &BTreeSet<Nnf>, finite: bool) -> Vec<Branch> {
+    let pending = state.iter()
+        .map(|formula| match formula {
+            Nnf::Next(operand) | Nnf::WeakNext(operand) => operand.as_ref().clone(),
+            formula => formula.clone(),
+        })
+        .collect();
+
+    let mut branches = Vec::new();
+
+    expand_branch(pending, Branch::default(), BTreeSet::new(), finite, &mut branches);
+
+    branches.sort_unstable();
+    branches.dedup();
+
+    branches
+}
+
+fn expand_branch(
+    mut pending: Vec<Nnf>, mut branch: Branch, mut expanded: BTreeSet<Nnf>, finite: bool, branches: &mut Vec<Branch>,
+) {
+    // explores the alternative of the given formula on a copy of the branch
+    let mut alternative = |pending: &Vec<Nnf>, branch: &Branch, expanded: &BTreeSet<Nnf>, formulas: Vec<Nnf>| {
+        let mut pending = pending.clone();
+
+        pending.extend(formulas);
+
+        expand_branch(pending, branch.clone(), expanded.clone(), finite, branches);
+    };
+
+    while let Some(formula) = pending.pop() {
+        if !expanded.insert(formula.clone()) {
+            continue;
+        }
+
+        let wrapped = Box::new(formula.clone());
+
+        match formula {
+            Nnf::True => {}
+            Nnf::False => return,
+            Nnf::Literal(name, value) => {
+                if branch.literals.contains(&(name.clone(), !value)) {
+                    return;
+                }
+
+                branch.literals.insert((name, value));
+            }
+            Nnf::And(lhs, rhs) => pending.extend([*lhs, *rhs]),
+            Nnf::Or(lhs, rhs) => {
+                alternative(&pending, &branch, &expanded, vec![*rhs]);
+                pending.push(*lhs);
+            }
+            Nnf::Next(_) | Nnf::WeakNext(_) => {
+                branch.next.insert(*wrapped);
+            }
+            Nnf::Until(lhs, rhs) => {
+                alternative(&pending, &branch, &expanded, vec![*rhs]);
+                pending.push(*lhs);
+                branch.postponed.insert(wrapped.as_ref().clone());
+                branch.next.insert(Nnf::Next(wrapped));
+            }
+            Nnf::Eventually(operand) => {
+                alternative(&pending, &branch, &expanded, vec![*operand]);
+                branch.postponed.insert(wrapped.as_ref().clone());
+                branch.next.insert(Nnf::Next(wrapped));
+            }
+            Nnf::Release(lhs, rhs) => {
+                alternative(&pending, &branch, &expanded, vec![lhs.as_ref().clone(), rhs.as_ref().clone()]);
+                pending.push(*rhs);
+                branch.next.insert(if finite { Nnf::WeakNext(wrapped) } else { Nnf::Next(wrapped) });
+            }
+            Nnf::Always(operand) => {
+                pending.push(*operand);
+                branch.next.insert(if finite { Nnf::WeakNext(wrapped) } else { Nnf::Next(wrapped) });
+            }
+        }
+    }
+
+    branches.push(branch);
+}
+
+// states reachable from the initial state, in breadth first order, and their
+// transitions δ[state][symbol] -> {states}, to each successor of a state on the
+// valuations its branch allows
+#[allow(non_snake_case)]
+pub fn explore<N: Clone + Eq + Hash>(
+    Σ: &Σ<Valuation>, initial: N, successors: impl Fn(&N) -> Vec<(Branch, N)>,
+) -> (Vec<N>, Vec<Vec<Vec<usize>>>) {
+    let mut nodes = vec![initial.clone()];
+    let mut index = HashMap::from([(initial, 0)]);
+    let mut delta = Vec::new();
+    let mut current = 0;
+
+    while current < nodes.len() {
+        let mut transitions = vec![Vec::new(); Σ.len()];
+
+        for (branch, node) in successors(&nodes[current]) {
+            let next = *index.entry(node).or_insert_with_key(|node| {
+                nodes.push(node.clone());
+                nodes.len() - 1
+            });
+
+            for (sym, valuation) in Σ.iter().enumerate() {
+                if branch.allows(valuation) && !transitions[sym].contains(&next) {
+                    transitions[sym].push(next);
+                }
+            }
+        }
+
+        delta.push(transitions);
+        current += 1;
+    }
+
+    (nodes, delta)
+}
+
+// name of a state, its formulas in braces
+pub fn name<'a>(formulas: impl Iterator<Item=&'a Nnf>) -> String {
+    format!("{{{}}}", formulas.map(ToString::to_string).collect::<Vec<_>>().join(", "))
+}
+
+impl Formula {
+    /// Büchi automaton accepting the infinite traces satisfying this formula, over
+    /// the valuations of its propositions, using a tableau expanding the
+    /// obligations of each position into the ways of satisfying them
+    ///
+    /// States are tagged with the obligations left to the following positions and,
+    /// when the formula has eventualities, `U` or `F`, the index of the next one a
+    /// run waits to see fulfilled rather than postponed, runs having seen each of
+    /// them in turn being in final states
+    ///
+    /// # Errors
+    /// a formula with more than [`MAX_PROPOSITIONS`](crate::ltl::MAX_PROPOSITIONS) propositions
+    #[allow(non_snake_case)]
+    pub fn to_buchi(&self) -> Result<Buchi<Valuation, String>, &'static str> {
+        let Σ = self.valuations()?;
+        let formula = Nnf::of(self, false, false);
+        let eventualities = formula.eventualities().into_iter().cloned().collect::<Vec<_>>();
+        let count = eventualities.len();
+
+        let (nodes, delta) = explore(&Σ, (BTreeSet::from([Nnf::Next(Box::new(formula))]), 0), |(state, waiting)| {
+            let start = if *waiting == count { 0 } else { *waiting };
+
+            expand(state, false).into_iter()
+                .map(|branch| {
+                    let waiting = start + eventualities[start..].iter()
+                        .take_while(|eventuality| !branch.postponed.contains(eventuality))
+                        .count();
+                    let next = (branch.next.clone(), waiting);
+
+                    (branch, next)
+                })
+                .collect()
+        });
+
+        let states = nodes.into_iter()
+            .map(|(state, waiting)| {
+                let obligations = name(state.iter().map(|formula| match formula {
+                    Nnf::Next(operand) | Nnf::WeakNext(operand) => operand.as_ref(),
+                    formula => formula,
+                }));
+
+                let tag = if count == 0 { obligations } else { format!("{obligations} {waiting}") };
+
+                (tag, waiting == count)
+            })
+            .collect();
+
+        Ok(Buchi::from_table(Σ, states, 0, delta))
+    }
+}
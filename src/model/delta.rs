@@ -2,6 +2,7 @@ use std::ops::Deref;
 
 use crate::utils::duped::Duped;
 
+pub const ERR_DUPLICATE_DEFAULT_TRANSITIONS: &str = "A state transition must define at most one default transition";
 pub const ERR_DUPLICATE_DELTA_STATES: &str = "Transition functions must be a unique collection of state transitions";
 pub const ERR_DUPLICATE_INPUT_TRANSITIONS: &str = "A state transition must must be a unique collection of input transitions";
 pub const ERR_UNDEFINED_STATE_TRANSITION: &str = "Input transition state does not correspond to a state transition";
@@ -10,37 +11,65 @@ pub const ERR_UNDEFINED_STATE_TRANSITION: &str = "Input transition state does no
 type delta<A, S> = (S, Vec<(A, S)>);
 type Delta<A, S> = Vec<delta<A, S>>;
 
-/// Transition function Q X Σ -> Q
+/// Transition function Q X Σ -> Q, with an optional default transition per state
 #[allow(non_camel_case_types)]
-pub struct δ<A, S>(Delta<A, S>);
+pub struct δ<A, S>(Delta<A, S>, Vec<(S, S)>);
 
 impl<A: Eq, S: Eq> δ<A, S> {
     /// # Errors
     pub fn new(delta: Delta<A, S>) -> Result<Self, &'static str> {
+        Self::with_defaults(delta, Vec::new())
+    }
+
+    /// Transition function whose states may have a default transition, taken on
+    /// any symbol of Σ they do not define an input transition for
+    ///
+    /// # Errors
+    pub fn with_defaults(delta: Delta<A, S>, defaults: Vec<(S, S)>) -> Result<Self, &'static str> {
         let states = delta.iter().map(|(state, _)| state);
 
         if states.has_dupes() {
             Err(ERR_DUPLICATE_DELTA_STATES)
         } else if delta.iter().any(|(_, transitions)| transitions.iter().has_dupes()) {
             Err(ERR_DUPLICATE_INPUT_TRANSITIONS)
+        } else if defaults.iter().map(|(state, _)| state).has_dupes() {
+            Err(ERR_DUPLICATE_DEFAULT_TRANSITIONS)
         } else {
-            let state_transitions = delta.iter()
-                .flat_map(|(_, transitions)|
-                    transitions.iter().map(|(_, state)| state)
-                );
-
-            if state_transitions.clone().all(|transition|
-                states.clone().any(|state| transition == state)
-            ) {
-                Ok(Self(delta))
+            let defined = {
+                let state_transitions = delta.iter()
+                    .flat_map(|(_, transitions)|
+                        transitions.iter().map(|(_, state)| state)
+                    )
+                    .chain(defaults.iter().flat_map(|(state, next)| [state, next]));
+
+                state_transitions.clone().all(|transition|
+                    states.clone().any(|state| transition == state)
+                )
+            };
+
+            if defined {
+                Ok(Self(delta, defaults))
             } else {
                 Err(ERR_UNDEFINED_STATE_TRANSITION)
             }
         }
     }
 
+    /// Default transition of the state, `None` if it has none
+    pub fn default_of(&self, state: &S) -> Option<&S> {
+        self.1.iter().find(|(from, _)| from == state).map(|(_, next)| next)
+    }
+
     pub(crate) fn states_transitioned(&self) -> impl Iterator<Item=&S> {
-        self.0.iter().flat_map(|itm| itm.1.iter().map(|itm| &itm.1))
+        self.0.iter()
+            .flat_map(|itm| itm.1.iter().map(|itm| &itm.1))
+            .chain(self.1.iter().map(|(_, next)| next))
+    }
+
+    // input transitions of each state, with the default transition of each state
+    // that has one
+    pub(crate) fn into_parts(self) -> (Delta<A, S>, Vec<(S, S)>) {
+        (self.0, self.1)
     }
 }
 
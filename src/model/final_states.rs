@@ -21,6 +21,13 @@ impl<S: Eq + Hash> F<S> {
             Ok(Self(final_states.into_iter().map(|s| vec![s]).collect::<HashSet<_>>()))
         }
     }
+
+    /// Empty set of final states, for automata which may accept nothing, such as
+    /// a Büchi automaton whose runs never need to visit a final state
+    #[must_use]
+    pub fn empty() -> Self {
+        Self(HashSet::new())
+    }
 }
 
 impl<S: Hash> Deref for F<S> {
@@ -0,0 +1,166 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Formatter, Write};
+use std::ops::{Deref, RangeInclusive};
+
+use crate::automata::{BooleanAlgebra, Discrete, Ranges};
+use crate::model::sigma::ERR_EMPTY_ALPHABET;
+use crate::UNREACHABLE_ERR;
+
+pub const ERR_EMPTY_RANGE: &str = "Range must contain at least one symbol";
+
+/// Alphabet of ranges of symbols, split into disjoint classes of symbols that
+/// are not told apart, an alternative to Σ for large alphabets like `char`
+///
+/// Overlapping ranges are split into the classes of symbols belonging to the same
+/// ranges, e.g. `'a'..='m'` and `'f'..='z'` into `'a'..='e'`, `'f'..='m'` and
+/// `'n'..='z'`, so splitting needs the successor and predecessor of a symbol
+pub struct Intervals<A> {
+    classes: Vec<RangeInclusive<A>>,
+    // binary search of the class of a symbol, the size and n-th symbol of a class
+    // and the splitting of ranges into classes, captured where `A: Discrete` is known
+    find: fn(&[RangeInclusive<A>], &A) -> Option<usize>,
+    size: fn(&RangeInclusive<A>) -> u128,
+    nth: fn(&RangeInclusive<A>, u128) -> Option<A>,
+    #[allow(clippy::type_complexity)]
+    split: fn(Vec<RangeInclusive<A>>, &[RangeInclusive<A>]) -> Self,
+}
+
+impl<A: Discrete> Intervals<A> {
+    /// # Errors
+    pub fn new(ranges: Vec<RangeInclusive<A>>) -> Result<Self, &'static str> {
+        if ranges.is_empty() {
+            Err(ERR_EMPTY_ALPHABET)
+        } else if ranges.iter().any(RangeInclusive::is_empty) {
+            Err(ERR_EMPTY_RANGE)
+        } else {
+            Ok(Self::split(ranges, &[]))
+        }
+    }
+
+    /// Are the ranges all within this alphabet, together covering every symbol of it
+    #[must_use]
+    pub fn is_covered_by(&self, ranges: &[RangeInclusive<A>]) -> bool {
+        let alphabet = Ranges::new(self.classes.iter().cloned());
+
+        Ranges::new(ranges.iter().cloned()) == alphabet
+    }
+
+    /// Is every symbol of the range within this alphabet
+    #[must_use]
+    pub fn contains(&self, range: &RangeInclusive<A>) -> bool {
+        let range = Ranges::new([range.clone()]);
+
+        range.and(&Ranges::new(self.classes.iter().cloned())) == range
+    }
+
+    /// Alphabet of the same symbols whose classes are further split so that no
+    /// class straddles a boundary of the ranges, the ranges being within this alphabet
+    #[must_use]
+    pub fn refine<'a>(&'a self, ranges: impl IntoIterator<Item=&'a RangeInclusive<A>>) -> Self {
+        Self::split(self.classes.clone(), &ranges.into_iter().cloned().collect::<Vec<_>>())
+    }
+
+    // classes of the symbols of the ranges, cut before the start and after the
+    // end of each of the ranges and of the further boundaries
+    fn split(ranges: Vec<RangeInclusive<A>>, boundaries: &[RangeInclusive<A>]) -> Self {
+        let mut cuts = ranges.iter()
+            .chain(boundaries)
+            .flat_map(|range| [Some(*range.start()), range.end().successor()])
+            .flatten()
+            .collect::<Vec<_>>();
+
+        cuts.sort_unstable();
+        cuts.dedup();
+
+        let mut classes = Vec::new();
+
+        for range in Ranges::new(ranges).ranges() {
+            let mut start = *range.start();
+
+            for cut in cuts.iter().filter(|cut| range.start() < *cut && *cut <= range.end()) {
+                classes.push(start..=cut.predecessor().expect(UNREACHABLE_ERR));
+                start = *cut;
+            }
+
+            classes.push(start..=*range.end());
+        }
+
+        Self { classes, find: find::<A>, size: size::<A>, nth: nth::<A>, split: Self::split }
+    }
+}
+
+impl<A> Intervals<A> {
+    /// Index of the class containing the symbol, `None` if it is not within this
+    /// alphabet, found by binary search
+    pub fn class(&self, symbol: &A) -> Option<usize> {
+        (self.find)(&self.classes, symbol)
+    }
+
+    /// Number of symbols of the class
+    #[must_use]
+    pub fn size(&self, class: usize) -> u128 {
+        (self.size)(&self.classes[class])
+    }
+
+    /// Symbol of the class preceded by `n` symbols of it, `None` past its end
+    #[must_use]
+    pub fn nth(&self, class: usize, n: u128) -> Option<A> {
+        (self.nth)(&self.classes[class], n)
+    }
+}
+
+impl<A: Clone> Intervals<A> {
+    /// Alphabet of the symbols of both alphabets, split into the classes of
+    /// symbols that neither of them tells apart
+    #[must_use]
+    pub fn common(&self, other: &Self) -> Self {
+        (self.split)(self.classes.iter().chain(&other.classes).cloned().collect(), &[])
+    }
+}
+
+impl<A: Clone> Clone for Intervals<A> {
+    fn clone(&self) -> Self {
+        Self { classes: self.classes.clone(), find: self.find, size: self.size, nth: self.nth, split: self.split }
+    }
+}
+
+impl<A> Deref for Intervals<A> {
+    type Target = [RangeInclusive<A>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.classes
+    }
+}
+
+impl<A: Debug> Debug for Intervals<A> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_char('[')?;
+        for itm in self.classes.iter().take(1) {
+            fmt.write_fmt(format_args!("{itm:?}"))?;
+        }
+        for itm in self.classes.iter().skip(1) {
+            fmt.write_fmt(format_args!(", {itm:?}"))?;
+        }
+        fmt.write_char(']')
+    }
+}
+
+fn size<A: Discrete>(class: &RangeInclusive<A>) -> u128 {
+    class.end().ordinal() - class.start().ordinal() + 1
+}
+
+fn nth<A: Discrete>(class: &RangeInclusive<A>, n: u128) -> Option<A> {
+    class.start().ordinal().checked_add(n).and_then(A::from_ordinal).filter(|symbol| symbol <= class.end())
+}
+
+fn find<A: Ord>(classes: &[RangeInclusive<A>], symbol: &A) -> Option<usize> {
+    classes.binary_search_by(|class| {
+        if class.end() < symbol {
+            Ordering::Less
+        } else if class.start() > symbol {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }).ok()
+}
@@ -0,0 +1,52 @@
+use std::ops::Deref;
+
+use crate::model::delta::{
+    ERR_DUPLICATE_DELTA_STATES,
+    ERR_DUPLICATE_INPUT_TRANSITIONS,
+    ERR_UNDEFINED_STATE_TRANSITION,
+};
+use crate::utils::duped::Duped;
+
+#[allow(non_camel_case_types)]
+type lambda<A, S, O> = (S, Vec<(A, S, O)>);
+type Lambda<A, S, O> = Vec<lambda<A, S, O>>;
+
+/// Transition and output function Q X Σ -> Q X Γ
+#[allow(non_camel_case_types)]
+pub struct λ<A, S, O>(Lambda<A, S, O>);
+
+impl<A: Eq, S: Eq, O> λ<A, S, O> {
+    /// # Errors
+    pub fn new(lambda: Lambda<A, S, O>) -> Result<Self, &'static str> {
+        let states = lambda.iter().map(|(state, _)| state);
+
+        if states.has_dupes() {
+            Err(ERR_DUPLICATE_DELTA_STATES)
+        } else if lambda.iter().any(|(_, transitions)| transitions.iter().map(|(sym, _, _)| sym).has_dupes()) {
+            Err(ERR_DUPLICATE_INPUT_TRANSITIONS)
+        } else if lambda.iter()
+            .flat_map(|(_, transitions)| transitions.iter().map(|(_, state, _)| state))
+            .all(|transition| states.clone().any(|state| transition == state)) {
+            Ok(Self(lambda))
+        } else {
+            Err(ERR_UNDEFINED_STATE_TRANSITION)
+        }
+    }
+}
+
+impl<A, S, O> Deref for λ<A, S, O> {
+    type Target = Lambda<A, S, O>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<A, S, O> IntoIterator for λ<A, S, O> {
+    type Item = lambda<A, S, O>;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
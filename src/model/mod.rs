@@ -1,11 +1,16 @@
-//!
+//! Components used to define finite automata, Q, Σ, δ, q0 and F, and transducers, λ, with interval
+//! alphabets of ranges of symbols as an alternative to Σ
 
 pub use delta::δ;
 pub use final_states::F;
+pub use intervals::Intervals;
+pub use lambda::λ;
 pub use sigma::Σ;
-pub use state::{Q, State};
+pub use state::{Phase, Q, State};
 
 pub(crate) mod delta;
 pub(crate) mod final_states;
+pub(crate) mod intervals;
+pub(crate) mod lambda;
 pub(crate) mod sigma;
 pub(crate) mod state;
@@ -1,5 +1,6 @@
 use std::fmt::{self, Debug, Display, Formatter, Write};
 use std::ops::Deref;
+use std::sync::Arc;
 
 use crate::utils::duped::Duped;
 
@@ -7,7 +8,7 @@ pub const ERR_DUPLICATE_ALPHABET: &str = "Alphabet must be a unique collection o
 pub const ERR_EMPTY_ALPHABET: &str = "Alphabet must contain at least one symbol";
 
 /// Alphabet of a set of all possible inputs
-pub struct Σ<T>(Vec<T>);
+pub struct Σ<T>(Arc<[T]>);
 
 impl<T: Eq> Σ<T> {
     /// # Errors
@@ -17,11 +18,18 @@ impl<T: Eq> Σ<T> {
         } else if symbols.iter().has_dupes() {
             Err(ERR_DUPLICATE_ALPHABET)
         } else {
-            Ok(Self(symbols))
+            Ok(Self(symbols.into()))
         }
     }
 }
 
+// symbols are shared, so alphabets of any symbols can be cloned
+impl<T> Clone for Σ<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
 impl<T> Deref for Σ<T> {
     type Target = [T];
 
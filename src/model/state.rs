@@ -12,25 +12,37 @@ pub const ERR_DUPLICATE_TAGS: &str = "State must be a unique collection of tags"
 pub const ERR_EMPTY_STATES: &str = "States must contain at least one state";
 pub const ERR_EMPTY_TAGS: &str = "State must contain at least one tag";
 
-///
+/// Role a state plays in an automaton
 #[repr(u8)]
 #[derive(Copy, Clone)]
 #[cfg_attr(test, derive(Debug))]
 pub enum Phase {
-    ///
+    /// Initial state q0
     Initial,
 
-    ///
+    /// Neither initial nor final
     Interim,
 
-    ///
+    /// Member of the final states F
     Final,
 
-    ///
+    /// Initial state q0 which is also a final state
     Both,
 }
 
-///
+impl Phase {
+    #[inline]
+    pub(crate) const fn of(initial: bool, r#final: bool) -> Self {
+        match (initial, r#final) {
+            (false, false) => Self::Interim,
+            (true, false) => Self::Initial,
+            (false, true) => Self::Final,
+            (true, true) => Self::Both,
+        }
+    }
+}
+
+/// A state of an automaton, identified by one or more tags
 pub struct State<S> {
     tags: Rc<Vec<S>>,
     phase: Phase,
@@ -87,16 +99,39 @@ impl<S: Eq> State<S> {
             })
         }
     }
+}
+
+impl<S> State<S> {
+    // used for states derived from validated automata, i.e. subsets of states
+    // or merged states, which may legitimately be empty, i.e. a dead state
+    #[inline]
+    pub(crate) fn from_tags(tags: Vec<S>, phase: Phase) -> Self {
+        Self {
+            tags: Rc::new(tags),
+            phase,
+        }
+    }
 
+    /// Is this state a member of the final states F
     #[inline]
-    pub(crate) const fn is_final(&self) -> bool {
+    #[must_use]
+    pub const fn is_final(&self) -> bool {
         matches!(self.phase, Phase::Final | Phase::Both)
     }
 
+    /// Is this the initial state q0
     #[inline]
-    pub(crate) const fn is_initial(&self) -> bool {
+    #[must_use]
+    pub const fn is_initial(&self) -> bool {
         matches!(self.phase, Phase::Initial | Phase::Both)
     }
+
+    /// Role this state plays in its automaton
+    #[inline]
+    #[must_use]
+    pub const fn phase(&self) -> Phase {
+        self.phase
+    }
 }
 
 impl<S: Debug> Debug for State<S> {
@@ -0,0 +1,90 @@
+//! Seedable pseudo random numbers, used to sample automata reproducibly
+
+use crate::UNREACHABLE_ERR;
+
+/// Seedable source of pseudo random numbers the automata sample with, so that
+/// sampling is reproducible, given a generator of uniform 64 bit numbers
+pub trait Rng {
+    /// Generator seeded with the given seed, producing the same sequence for the
+    /// same seed
+    fn from_seed(seed: u64) -> Self where Self: Sized;
+
+    /// Next uniformly distributed 64 bit number
+    fn next_u64(&mut self) -> u64;
+
+    /// Next uniformly distributed number in [0, 1)
+    #[allow(clippy::cast_precision_loss)] // 53 bits fit the mantissa exactly
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+
+    /// Next uniformly distributed index in [0, bound), 0 for a bound of 0
+    #[allow(clippy::missing_panics_doc)] // the high half of the product is below bound
+    fn next_below(&mut self, bound: usize) -> usize {
+        usize::try_from((u128::from(self.next_u64()) * bound as u128) >> 64).expect(UNREACHABLE_ERR)
+    }
+}
+
+/// Pseudo random number generator, xoshiro256**, producing the same sequence
+/// for the same seed, not suited for cryptography
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Random {
+    state: [u64; 4],
+}
+
+impl Random {
+    /// Generator seeded with the given seed, expanded to its state with splitmix64
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        let mut seed = seed;
+        let mut state = [0; 4];
+
+        for word in &mut state {
+            seed = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+            let mut mixed = seed;
+
+            mixed = (mixed ^ (mixed >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+            *word = mixed ^ (mixed >> 31);
+        }
+
+        Self { state }
+    }
+
+    /// Next uniformly distributed 64 bit number
+    pub const fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let shifted = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= shifted;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+
+    /// Next uniformly distributed number in [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        Rng::next_f64(self)
+    }
+
+    /// Next uniformly distributed index in [0, bound), 0 for a bound of 0
+    pub fn next_below(&mut self, bound: usize) -> usize {
+        Rng::next_below(self, bound)
+    }
+}
+
+impl Rng for Random {
+    fn from_seed(seed: u64) -> Self {
+        Self::new(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        Self::next_u64(self)
+    }
+}
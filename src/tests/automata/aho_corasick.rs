@@ -0,0 +1,83 @@
+#![allow(non_snake_case)]
+
+use crate::automata::{AhoCorasick, Occurrence};
+use crate::automata::aho_corasick::{ERR_EMPTY_PATTERN, ERR_NO_PATTERNS};
+use crate::automata::dfa::ERR_UNDEFINED_SYMBOL;
+use crate::model::Σ;
+use crate::tests::{assert_err, STEPS_NO_ERRORS, VALID_SIGMA};
+
+const VALID_AHO_CORASICK: &str = "valid aho-corasick automaton";
+
+fn word(text: &str) -> Vec<char> {
+    text.chars().collect()
+}
+
+fn sut() -> AhoCorasick<char> {
+    let Σ = Σ::new(word("ehirsux")).expect(VALID_SIGMA);
+
+    AhoCorasick::new(&Σ, &[word("he"), word("she"), word("his"), word("hers")]).expect(VALID_AHO_CORASICK)
+}
+
+#[test]
+fn given_a_text_all_overlapping_occurrences_should_be_found_in_order_of_their_end() {
+    let actual = sut().find(&word("ushers"));
+
+    assert_eq!(vec![
+        Occurrence { pattern: 1, span: 1..4 },
+        Occurrence { pattern: 0, span: 2..4 },
+        Occurrence { pattern: 3, span: 2..6 },
+    ], actual);
+}
+
+#[test]
+fn given_symbols_outside_the_alphabet_they_should_break_occurrences() {
+    let actual = sut().find(&word("shze his"));
+
+    assert_eq!(vec![Occurrence { pattern: 2, span: 5..8 }], actual);
+}
+
+#[test]
+fn given_repeated_patterns_each_should_be_reported() {
+    let Σ = Σ::new(word("ab")).expect(VALID_SIGMA);
+    let sut = AhoCorasick::new(&Σ, &[word("ab"), word("ab")]).expect(VALID_AHO_CORASICK);
+
+    let actual = sut.find(&word("aab"));
+
+    assert_eq!(vec![Occurrence { pattern: 0, span: 1..3 }, Occurrence { pattern: 1, span: 1..3 }], actual);
+}
+
+#[test]
+fn given_the_dfa_it_should_accept_words_ending_with_a_pattern() {
+    let sut = sut();
+    let mut dfa = sut.dfa().minimize();
+
+    assert!(dfa.steps(&word("xushe")).expect(STEPS_NO_ERRORS).is_final());
+    dfa.reset();
+    assert!(!dfa.steps(&word("hisx")).expect(STEPS_NO_ERRORS).is_final());
+    assert!(dfa.equivalent(sut.dfa()));
+    assert_eq!(10, sut.dfa().states().count());
+}
+
+#[test]
+fn given_a_state_of_the_dfa_the_patterns_ending_there_should_be_longest_first() {
+    let sut = sut();
+    let dfa = sut.dfa();
+
+    let state = word("ush").iter()
+        .chain(&['e'])
+        .try_fold(dfa.initial(), |state, sym| dfa.transition(state, sym))
+        .expect(STEPS_NO_ERRORS);
+
+    assert_eq!(vec![1, 0], sut.patterns(state).collect::<Vec<_>>());
+    assert_eq!(0, sut.patterns(dfa.initial()).count());
+    assert_eq!(&vec![0], &**dfa.initial());
+}
+
+#[test]
+fn given_invalid_patterns_construction_should_fail() {
+    let Σ = Σ::new(word("ab")).expect(VALID_SIGMA);
+
+    assert_err(ERR_NO_PATTERNS, &AhoCorasick::new(&Σ, &[]));
+    assert_err(ERR_EMPTY_PATTERN, &AhoCorasick::new(&Σ, &[word("a"), word("")]));
+    assert_err(ERR_UNDEFINED_SYMBOL, &AhoCorasick::new(&Σ, &[word("abc")]));
+}
@@ -0,0 +1,60 @@
+use crate::automata::{BooleanAlgebra, Discrete, Ranges};
+
+#[test]
+fn given_overlapping_and_adjacent_ranges_they_should_be_merged_in_order() {
+    let sut = Ranges::new(['x'..='z', 'a'..='f', 'c'..='m', 'n'..='p', 'q'..='a']);
+
+    assert_eq!(vec!['a'..='p', 'x'..='z'], sut.ranges().collect::<Vec<_>>());
+    assert_eq!(sut, Ranges::new(['a'..='p', 'x'..='z']));
+}
+
+#[test]
+fn given_ranges_their_conjunction_disjunction_and_negation_should_follow_set_semantics() {
+    let lhs = Ranges::new([0_u8..=10, 20..=30]);
+    let rhs = Ranges::new([5_u8..=25]);
+
+    assert_eq!(Ranges::new([5..=10, 20..=25]), lhs.and(&rhs));
+    assert_eq!(Ranges::new([0..=30]), lhs.or(&rhs));
+    assert_eq!(Ranges::new([11..=19, 31..=u8::MAX]), lhs.not());
+    assert_eq!(Ranges::top(), Ranges::<u8>::bottom().not());
+    assert_eq!(Ranges::bottom(), Ranges::<u8>::top().not());
+    assert_eq!(lhs, lhs.not().not());
+}
+
+#[test]
+fn given_ranges_of_chars_their_negation_should_skip_the_surrogates() {
+    let sut = Ranges::new(['\0'..='\u{D7FF}']).not();
+
+    assert_eq!(vec!['\u{E000}'..=char::MAX], sut.ranges().collect::<Vec<_>>());
+    assert_eq!(Some('\u{E000}'), '\u{D7FF}'.successor());
+    assert_eq!(Some('\u{D7FF}'), '\u{E000}'.predecessor());
+}
+
+#[test]
+fn given_chars_their_ordinals_should_skip_the_surrogates() {
+    assert_eq!(0xD7FF, '\u{D7FF}'.ordinal());
+    assert_eq!(0xD800, '\u{E000}'.ordinal());
+    assert_eq!(Some('\u{E000}'), char::from_ordinal(0xD800));
+    assert_eq!(Some(char::MAX), char::from_ordinal(char::MAX.ordinal()));
+    assert_eq!(None, char::from_ordinal(char::MAX.ordinal() + 1));
+    assert_eq!(None, char::from_ordinal(u128::MAX));
+}
+
+#[test]
+fn given_ranges_they_should_hold_for_the_symbols_they_contain() {
+    let sut = Ranges::new(['0'..='9', 'a'..='z']);
+
+    assert!(sut.holds(&'5'));
+    assert!(sut.holds(&'z'));
+    assert!(!sut.holds(&'A'));
+    assert!(sut.is_satisfiable());
+    assert_eq!(Some('0'), sut.witness());
+    assert!(!sut.and(&Ranges::symbol('_')).is_satisfiable());
+    assert_eq!(None, sut.and(&Ranges::symbol('_')).witness());
+}
+
+#[test]
+fn given_ranges_they_should_display_single_symbols_and_intervals() {
+    assert_eq!("['_', 'a'..='z']", Ranges::new(['a'..='z', '_'..='_']).to_string());
+    assert_eq!("[]", Ranges::<char>::bottom().to_string());
+}
@@ -0,0 +1,126 @@
+#![allow(non_snake_case)]
+
+use crate::automata::{Buchi, ERR_INVALID_INPUT};
+use crate::automata::buchi::ERR_EMPTY_CYCLE;
+use crate::model::{F, Q, δ, Σ};
+use crate::tests::{assert_err, VALID_DELTA, VALID_FINAL_STATES, VALID_SIGMA, VALID_STATES};
+
+const VALID_BUCHI: &str = "valid büchi automaton";
+
+#[test]
+fn given_a_büchi_automaton_it_should_accept_lassos_visiting_a_final_state_infinitely_often() {
+    let sut = infinitely_many_a();
+
+    assert_eq!(Ok(true), sut.accepts(&['b'], &['a']));
+    assert_eq!(Ok(true), sut.accepts(&[], &['a', 'b']));
+    assert_eq!(Ok(false), sut.accepts(&['a', 'a'], &['b']));
+}
+
+#[test]
+fn given_a_nondeterministic_büchi_automaton_it_should_accept_a_lasso_with_an_accepting_run() {
+    let sut = finitely_many('b', 'a');
+
+    assert_eq!(Ok(true), sut.accepts(&['b', 'a', 'b'], &['a']));
+    assert_eq!(Ok(true), sut.accepts(&['b'], &['a', 'a']));
+    assert_eq!(Ok(false), sut.accepts(&['a'], &['a', 'b']));
+}
+
+#[test]
+fn given_an_empty_cycle_accepts_should_return_an_err() {
+    assert_err(ERR_EMPTY_CYCLE, &infinitely_many_a().accepts(&['a'], &[]));
+}
+
+#[test]
+fn given_an_undefined_input_symbol_accepts_should_return_an_err() {
+    assert_err(ERR_INVALID_INPUT, &infinitely_many_a().accepts(&['c'], &['a']));
+    assert_err(ERR_INVALID_INPUT, &infinitely_many_a().accepts(&['a'], &['c']));
+}
+
+#[test]
+fn given_a_non_empty_büchi_automaton_it_should_return_an_accepting_lasso() {
+    let sut = infinitely_many_a();
+
+    let (prefix, cycle) = sut.accepting_lasso().expect("accepting lasso");
+
+    assert_eq!((vec!['a'], vec!['a']), (prefix.clone(), cycle.clone()));
+    assert_eq!(Ok(true), sut.accepts(&prefix, &cycle));
+    assert!(!sut.is_empty());
+}
+
+#[test]
+fn given_a_büchi_automaton_without_a_final_state_on_a_cycle_it_should_be_empty() {
+    let Σ = Σ::new(vec!['a']).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B', 'C']).expect(VALID_STATES);
+    let F = F::new(vec!['B']).expect(VALID_FINAL_STATES);
+    let δ = δ::new(vec![
+        ('A', vec![('a', 'B')]),
+        ('B', vec![('a', 'C')]),
+        ('C', vec![('a', 'C')]),
+    ]).expect(VALID_DELTA);
+
+    let sut = Buchi::new(Q, Σ, δ, 'A', &F).expect(VALID_BUCHI);
+
+    assert!(sut.is_empty());
+    assert!(sut.accepting_lasso().is_none());
+}
+
+#[test]
+fn given_a_büchi_automaton_without_final_states_it_should_be_empty() {
+    let Σ = Σ::new(vec!['a']).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A']).expect(VALID_STATES);
+    let δ = δ::new(vec![('A', vec![('a', 'A')])]).expect(VALID_DELTA);
+
+    let sut = Buchi::new(Q, Σ, δ, 'A', &F::empty()).expect(VALID_BUCHI);
+
+    assert!(sut.is_empty());
+    assert_eq!(Ok(false), sut.accepts(&[], &['a']));
+}
+
+#[test]
+fn given_two_büchi_automata_their_intersection_should_accept_lassos_accepted_by_both() {
+    let sut = infinitely_many_a().intersection(&finitely_many('a', 'b'));
+
+    assert!(sut.is_empty());
+
+    let sut = infinitely_many_a().intersection(&infinitely_many_a());
+
+    assert_eq!(Ok(true), sut.accepts(&[], &['a', 'b']));
+    assert_eq!(Ok(false), sut.accepts(&['a'], &['b']));
+}
+
+#[test]
+fn given_two_büchi_automata_their_intersection_should_return_a_lasso_accepted_by_both() {
+    let lhs = infinitely_many_a();
+    let rhs = finitely_many('b', 'a');
+
+    let (prefix, cycle) = lhs.intersection(&rhs).accepting_lasso().expect("accepting lasso");
+
+    assert_eq!(Ok(true), lhs.accepts(&prefix, &cycle));
+    assert_eq!(Ok(true), rhs.accepts(&prefix, &cycle));
+}
+
+// words with infinitely many a, N after a b and Y after an a
+pub fn infinitely_many_a() -> Buchi<char, char> {
+    let Σ = Σ::new(vec!['a', 'b']).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['N', 'Y']).expect(VALID_STATES);
+    let F = F::new(vec!['Y']).expect(VALID_FINAL_STATES);
+    let δ = δ::new(vec![
+        ('N', vec![('a', 'Y'), ('b', 'N')]),
+        ('Y', vec![('a', 'Y'), ('b', 'N')]),
+    ]).expect(VALID_DELTA);
+
+    Buchi::new(Q, Σ, δ, 'N', &F).expect(VALID_BUCHI)
+}
+
+// words with finitely many of the symbol, guessing in P when only the other symbol is left
+fn finitely_many(symbol: char, other: char) -> Buchi<char, char> {
+    let Σ = Σ::new(vec!['a', 'b']).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['P', 'Q']).expect(VALID_STATES);
+    let F = F::new(vec!['Q']).expect(VALID_FINAL_STATES);
+    let δ = δ::new(vec![
+        ('P', vec![(symbol, 'P'), (other, 'P'), (other, 'Q')]),
+        ('Q', vec![(other, 'Q')]),
+    ]).expect(VALID_DELTA);
+
+    Buchi::new(Q, Σ, δ, 'P', &F).expect(VALID_BUCHI)
+}
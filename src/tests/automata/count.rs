@@ -0,0 +1,118 @@
+#![allow(non_snake_case)]
+
+use crate::automata::{Dawg, DFA, GeneratingFunction, Levenshtein};
+use crate::automata::count::ERR_COUNT_OVERFLOW;
+use crate::model::{F, Q, δ, Σ};
+use crate::tests::{assert_err, VALID_DELTA, VALID_FINAL_STATES, VALID_SIGMA, VALID_STATES};
+use crate::tests::automata::determinize::ends_with_zero_one;
+use crate::tests::automata::intervals::lowercase;
+use crate::tests::automata::VALID_DFA;
+
+const VALID_COUNT: &str = "valid count";
+const VALID_DAWG: &str = "valid dawg";
+
+#[test]
+fn given_a_length_the_dfa_should_count_its_words_of_that_length() {
+    let sut = no_consecutive_ones();
+
+    assert_eq!(vec![1, 2, 3, 5, 8, 13], sut.word_counts(5).expect(VALID_COUNT));
+    assert_eq!(Ok(10_946), sut.count_words(19));
+}
+
+#[test]
+fn given_too_many_words_counting_them_should_overflow() {
+    let sut = ends_with_zero_one().determinize();
+
+    assert_eq!(Ok(1 << 127), sut.count_words(129));
+    assert_err(ERR_COUNT_OVERFLOW, &sut.count_words(130));
+}
+
+#[test]
+fn given_a_dfa_its_generating_function_should_expand_to_its_word_counts() {
+    let sut = no_consecutive_ones();
+
+    let actual = sut.generating_function().expect(VALID_COUNT);
+
+    assert_eq!(GeneratingFunction { numerator: vec![1, 1], denominator: vec![1, -1, -1] }, actual);
+    assert_eq!("(1 + z) / (1 - z - z^2)", actual.to_string());
+    assert_eq!(vec![1, 2, 3, 5, 8, 13, 21], actual.expand(6).expect(VALID_COUNT));
+}
+
+#[test]
+fn given_a_finite_language_its_generating_function_should_be_a_polynomial() {
+    let words = [vec!['a'], vec!['a', 'b'], vec!['b', 'a'], vec!['b', 'b', 'b']];
+    let sut = Dawg::new(&words).expect(VALID_DAWG).to_dfa().expect(VALID_DFA);
+
+    let actual = sut.generating_function().expect(VALID_COUNT);
+
+    assert_eq!("z + 2z^2 + z^3", actual.to_string());
+    assert!(sut.growth_rate().abs() < f64::EPSILON);
+    assert!(sut.entropy().abs() < f64::EPSILON);
+}
+
+#[test]
+fn given_an_empty_language_its_generating_function_should_be_zero() {
+    let Σ = Σ::new(vec!['c']).expect(VALID_SIGMA);
+    let sut = Levenshtein::new(&['a', 'b'], 0).to_dfa(&Σ);
+
+    assert_eq!(vec![0, 0, 0], sut.word_counts(2).expect(VALID_COUNT));
+    assert_eq!("0", sut.generating_function().expect(VALID_COUNT).to_string());
+    assert!(sut.growth_rate().abs() < f64::EPSILON);
+}
+
+#[test]
+fn given_an_exponential_language_its_growth_rate_should_be_its_base() {
+    let sut = ends_with_zero_one().determinize();
+
+    assert!((sut.growth_rate() - 2.0).abs() < 1e-9);
+    assert!((sut.entropy() - 1.0).abs() < 1e-9);
+    assert!((no_consecutive_ones().growth_rate() - f64::midpoint(1.0, 5.0_f64.sqrt())).abs() < 1e-9);
+}
+
+#[test]
+fn given_a_polynomial_language_its_growth_rate_should_be_one() {
+    let sut = a_star_b_star();
+
+    assert_eq!(vec![1, 2, 3, 4, 5], sut.word_counts(4).expect(VALID_COUNT));
+    assert_eq!("1 / (1 - 2z + z^2)", sut.generating_function().expect(VALID_COUNT).to_string());
+    assert!((sut.growth_rate() - 1.0).abs() < 1e-9);
+    assert!(sut.entropy().abs() < 1e-9);
+}
+
+#[test]
+fn given_a_dfa_over_intervals_it_should_count_each_symbol_of_a_class() {
+    let sut = lowercase(vec!['a'..='m', 'n'..='z']);
+
+    assert_eq!(Ok(26), sut.count_words(1));
+    assert_eq!(vec![1, 26, 676], sut.word_counts(2).expect(VALID_COUNT));
+    assert_eq!("1 / (1 - 26z)", sut.generating_function().expect(VALID_COUNT).to_string());
+    assert!((sut.growth_rate() - 26.0).abs() < 1e-9);
+}
+
+fn no_consecutive_ones() -> DFA<u8, char> {
+    let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B', 'C']).expect(VALID_STATES);
+    let F = F::new(vec!['A', 'B']).expect(VALID_FINAL_STATES);
+
+    let δ = δ::new(vec![
+        ('A', vec![(0, 'A'), (1, 'B')]),
+        ('B', vec![(0, 'A'), (1, 'C')]),
+        ('C', vec![(0, 'C'), (1, 'C')]),
+    ]).expect(VALID_DELTA);
+
+    DFA::new(Q, &Σ, δ, 'A', &F).expect(VALID_DFA)
+}
+
+fn a_star_b_star() -> DFA<char, char> {
+    let Σ = Σ::new(vec!['a', 'b']).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B', 'C']).expect(VALID_STATES);
+    let F = F::new(vec!['A', 'B']).expect(VALID_FINAL_STATES);
+
+    let δ = δ::new(vec![
+        ('A', vec![('a', 'A'), ('b', 'B')]),
+        ('B', vec![('a', 'C'), ('b', 'B')]),
+        ('C', vec![('a', 'C'), ('b', 'C')]),
+    ]).expect(VALID_DELTA);
+
+    DFA::new(Q, &Σ, δ, 'A', &F).expect(VALID_DFA)
+}
@@ -0,0 +1,112 @@
+use crate::automata::Dawg;
+use crate::automata::dawg::{ERR_INVALID_BYTES, ERR_UNSORTED_WORDS};
+use crate::tests::{assert_err, STEPS_NO_ERRORS};
+use crate::tests::automata::words;
+
+const VALID_DAWG: &str = "valid dawg";
+
+fn sut() -> Dawg<char> {
+    Dawg::new(&words(&["tap", "taps", "top", "tops"])).expect(VALID_DAWG)
+}
+
+#[test]
+fn given_sorted_words_the_dawg_should_share_prefixes_and_suffixes() {
+    let sut = sut();
+
+    assert_eq!(5, sut.node_count());
+    assert_eq!(4, sut.len());
+    assert!(sut.contains(&['t', 'o', 'p', 's']));
+    assert!(!sut.contains(&['t', 'o']));
+    assert!(!sut.contains(&['t', 'i', 'p']));
+}
+
+#[test]
+fn given_a_dawg_its_words_should_be_enumerated_in_lexicographic_order() {
+    let words = words(&["", "a", "ab", "abc", "b", "ba", "bc", "c"]);
+    let sut = Dawg::new(&words).expect(VALID_DAWG);
+
+    assert_eq!(words, sut.words().collect::<Vec<_>>());
+    assert!(sut.contains(&[]));
+}
+
+#[test]
+fn given_a_prefix_the_dawg_should_iterate_the_words_starting_with_it() {
+    let sut = sut();
+
+    assert_eq!(words(&["tap", "taps"]), sut.words_with_prefix(&['t', 'a']).collect::<Vec<_>>());
+    assert_eq!(words(&["tops"]), sut.words_with_prefix(&['t', 'o', 'p', 's']).collect::<Vec<_>>());
+    assert_eq!(0, sut.words_with_prefix(&['x']).count());
+}
+
+#[test]
+fn given_a_dawg_its_dfa_should_be_minimal() {
+    let words = (0..1 << 6)
+        .flat_map(|bits: u32| (1..=6).map(move |len| (0..len).map(|bit| if bits >> bit & 1 == 1 { 'b' } else { 'a' }).collect::<Vec<_>>()))
+        .filter(|word| word.iter().filter(|sym| **sym == 'b').count() % 2 == 0)
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let sut = Dawg::new(&words).expect(VALID_DAWG);
+    let mut dfa = sut.to_dfa().expect(VALID_DAWG);
+
+    assert_eq!(words.len(), sut.len());
+    assert_eq!(sut.node_count() + 1, dfa.states().count());
+    assert_eq!(dfa.states().count(), dfa.minimize().states().count());
+    assert!(dfa.steps(&['a', 'b', 'b']).expect(STEPS_NO_ERRORS).is_final());
+}
+
+#[test]
+fn given_a_dawg_its_compact_form_should_read_back_the_same_words() {
+    let sut = sut();
+    let bytes = sut.to_bytes();
+
+    let actual = Dawg::from_bytes(&bytes).expect(VALID_DAWG);
+
+    assert_eq!(sut.words().collect::<Vec<_>>(), actual.words().collect::<Vec<_>>());
+    assert_eq!(sut.len(), actual.len());
+    assert_eq!(sut.node_count(), actual.node_count());
+}
+
+#[test]
+fn given_corrupted_bytes_reading_should_fail() {
+    let bytes = sut().to_bytes();
+
+    assert_err(ERR_INVALID_BYTES, &Dawg::from_bytes(&bytes[..bytes.len() - 1]));
+    assert_err(ERR_INVALID_BYTES, &Dawg::from_bytes(&[bytes.as_slice(), &[0]].concat()));
+    assert_err(ERR_INVALID_BYTES, &Dawg::from_bytes(&[0, 1, 2, 0, 0]));
+    assert_err(ERR_INVALID_BYTES, &Dawg::from_bytes(&[]));
+}
+
+#[test]
+fn given_bytes_of_more_words_than_can_be_counted_reading_should_fail() {
+    // a chain of nodes each leading to the next on both a and b, 2ⁿ words
+    let chain = |len: u8| {
+        let mut bytes = vec![2, b'a', b'b', len + 1];
+
+        for next in 1..=len {
+            bytes.extend([4, 0, next, 1, next]);
+        }
+
+        bytes.push(1);
+        bytes
+    };
+
+    assert_eq!(1 << 63, Dawg::from_bytes(&chain(63)).expect(VALID_DAWG).len());
+    assert_err(ERR_INVALID_BYTES, &Dawg::from_bytes(&chain(64)));
+}
+
+#[test]
+fn given_unsorted_or_repeated_words_construction_should_fail() {
+    assert_err(ERR_UNSORTED_WORDS, &Dawg::new(&words(&["b", "a"])));
+    assert_err(ERR_UNSORTED_WORDS, &Dawg::new(&words(&["a", "a"])));
+}
+
+#[test]
+fn given_no_words_the_dawg_should_be_empty() {
+    let sut = Dawg::<char>::new(&[]).expect(VALID_DAWG);
+
+    assert!(sut.is_empty());
+    assert_eq!(0, sut.words().count());
+    assert!(sut.to_dfa().is_err());
+}
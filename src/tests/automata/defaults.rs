@@ -0,0 +1,175 @@
+#![allow(non_snake_case)]
+
+use crate::automata::{BooleanAlgebra, Buchi, DFA, ERR_INVALID_INPUT, Moore, NFA, Ranges, SymbolicAutomaton};
+use crate::automata::dfa::ERR_INCOMPLETE_INPUT_TRANSITIONS;
+use crate::formats::Definition;
+use crate::model::{F, Intervals, Q, δ, Σ};
+use crate::tests::{assert_err, STEPS_NO_ERRORS, VALID_DELTA, VALID_FINAL_STATES, VALID_SIGMA, VALID_STATES};
+use crate::tests::automata::{VALID_DFA, VALID_MOORE};
+
+#[test]
+fn given_a_dfa_with_default_transitions_it_should_step_on_the_symbols_it_does_not_list() {
+    let mut sut = contains_abc();
+
+    sut.steps(&['x', 'a', 'a', 'b', 'c', 'z']).expect(STEPS_NO_ERRORS);
+    assert!(sut.matches());
+
+    sut.reset();
+    sut.steps(&['a', 'b', 'x', 'c']).expect(STEPS_NO_ERRORS);
+    assert!(!sut.matches());
+
+    assert_err(ERR_INVALID_INPUT, &sut.step(&'y'));
+}
+
+#[test]
+fn given_a_dfa_with_default_transitions_a_state_without_one_should_still_be_complete() {
+    let Q = Q::new(vec!['A', 'B']).expect(VALID_STATES);
+    let Σ = Σ::new(vec!['a', 'b']).expect(VALID_SIGMA);
+    let F = F::new(vec!['B']).expect(VALID_FINAL_STATES);
+    let δ = δ::with_defaults(vec![
+        ('A', vec![('a', 'B')]),
+        ('B', vec![('a', 'B')]),
+    ], vec![('A', 'A')]).expect(VALID_DELTA);
+
+    assert_err(ERR_INCOMPLETE_INPUT_TRANSITIONS, &DFA::new(Q, &Σ, δ, 'A', &F));
+}
+
+#[test]
+fn given_a_dfa_with_default_transitions_minimize_and_equivalence_should_follow_them() {
+    let sut = contains_abc();
+    let minimal = sut.minimize();
+
+    assert_eq!(4, minimal.states().count());
+    assert!(sut.equivalent(&minimal));
+    assert_eq!(None, sut.distinguishing_word(&minimal));
+    assert_eq!(Some(vec!['a', 'b']), sut.distinguishing_word(&contains_ab()));
+}
+
+#[test]
+fn given_a_dfa_with_default_transitions_its_definition_should_list_every_transition() {
+    let sut = Definition::from_dfa(&contains_abc());
+
+    assert_eq!(20, sut.transitions.len());
+    assert!(sut.transitions.contains(&("B".to_string(), "x".to_string(), "A".to_string())));
+    assert!(sut.to_dfa().expect(VALID_DFA).equivalent(&sut.to_dfa().expect(VALID_DFA).minimize()));
+}
+
+#[test]
+fn given_an_nfa_with_a_default_transition_it_should_take_it_on_the_symbols_it_does_not_list() {
+    let Q = Q::new(vec!['A', 'B']).expect(VALID_STATES);
+    let Σ = Σ::new(vec!['a', 'b', 'c']).expect(VALID_SIGMA);
+    let F = F::new(vec!['B']).expect(VALID_FINAL_STATES);
+    let δ = δ::with_defaults(vec![
+        ('A', vec![('a', 'A'), ('a', 'B')]),
+        ('B', vec![]),
+    ], vec![('A', 'A')]).expect(VALID_DELTA);
+
+    let mut sut = NFA::new(Q, Σ, δ, 'A', &F).expect("valid nfa");
+
+    sut.steps(&['b', 'c', 'a']).expect(STEPS_NO_ERRORS);
+    assert!(sut.matches());
+
+    sut.step(&'b').expect(STEPS_NO_ERRORS);
+    assert!(!sut.matches());
+
+    let mut dfa = sut.determinize();
+
+    dfa.steps(&['c', 'a']).expect(STEPS_NO_ERRORS);
+    assert!(dfa.matches());
+}
+
+#[test]
+fn given_a_moore_machine_with_default_transitions_it_should_take_them() {
+    let Q = Q::new(vec!['A', 'B']).expect(VALID_STATES);
+    let Σ = Σ::new(vec!['a', 'b', 'c']).expect(VALID_SIGMA);
+    let δ = δ::with_defaults(vec![
+        ('A', vec![('a', 'B')]),
+        ('B', vec![]),
+    ], vec![('A', 'A'), ('B', 'A')]).expect(VALID_DELTA);
+
+    let mut sut = Moore::new(Q, &Σ, δ, 'A', vec![('A', 0), ('B', 1)]).expect(VALID_MOORE);
+
+    assert_eq!(Ok(&0), sut.step(&'c'));
+    assert_eq!(Ok(&1), sut.step(&'a'));
+    assert_eq!(Ok(&0), sut.step(&'b'));
+}
+
+#[test]
+fn given_a_büchi_automaton_with_a_default_transition_it_should_take_it() {
+    let Q = Q::new(vec!['N', 'Y']).expect(VALID_STATES);
+    let Σ = Σ::new(vec!['a', 'b', 'c']).expect(VALID_SIGMA);
+    let F = F::new(vec!['Y']).expect(VALID_FINAL_STATES);
+    let δ = δ::with_defaults(vec![
+        ('N', vec![('a', 'Y')]),
+        ('Y', vec![('a', 'Y')]),
+    ], vec![('N', 'N'), ('Y', 'N')]).expect(VALID_DELTA);
+
+    let sut = Buchi::new(Q, Σ, δ, 'N', &F).expect("valid büchi automaton");
+
+    assert_eq!(Ok(true), sut.accepts(&['c'], &['b', 'a']));
+    assert_eq!(Ok(false), sut.accepts(&['a'], &['c']));
+}
+
+#[test]
+fn given_a_symbolic_automaton_with_a_default_transition_it_should_take_it_on_the_symbols_no_predicate_holds_for() {
+    let Q = Q::new(vec!['A', 'B']).expect(VALID_STATES);
+    let F = F::new(vec!['B']).expect(VALID_FINAL_STATES);
+    let δ = δ::with_defaults(vec![
+        ('A', vec![(Ranges::new(['0'..='9']), 'B')]),
+        ('B', vec![(Ranges::<char>::top(), 'B')]),
+    ], vec![('A', 'A')]).expect(VALID_DELTA);
+
+    let sut = SymbolicAutomaton::new(Q, δ, 'A', &F).expect("valid symbolic automaton");
+
+    assert!(sut.accepts(&['x', 'ä', '1']));
+    assert!(!sut.accepts(&['x']));
+    assert!(sut.is_deterministic());
+}
+
+#[test]
+fn given_a_dfa_over_intervals_with_a_default_transition_it_should_take_it_outside_its_ranges() {
+    let Σ = Intervals::new(vec!['\0'..=char::MAX]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B']).expect(VALID_STATES);
+    let F = F::new(vec!['B']).expect(VALID_FINAL_STATES);
+    let δ = δ::with_defaults(vec![
+        ('A', vec![('0'..='9', 'B')]),
+        ('B', vec![('0'..='9', 'B')]),
+    ], vec![('A', 'A'), ('B', 'A')]).expect(VALID_DELTA);
+
+    let mut sut = DFA::from_intervals(Q, &Σ, δ, 'A', &F).expect(VALID_DFA);
+
+    sut.steps(&['ß', '4', '2']).expect(STEPS_NO_ERRORS);
+    assert!(sut.matches());
+
+    sut.step(&'z').expect(STEPS_NO_ERRORS);
+    assert!(!sut.matches());
+}
+
+// inputs containing abc, every other symbol going back to A, or staying in D
+fn contains_abc() -> DFA<char, char> {
+    let Q = Q::new(vec!['A', 'B', 'C', 'D']).expect(VALID_STATES);
+    let Σ = Σ::new(vec!['a', 'b', 'c', 'x', 'z']).expect(VALID_SIGMA);
+    let F = F::new(vec!['D']).expect(VALID_FINAL_STATES);
+    let δ = δ::with_defaults(vec![
+        ('A', vec![('a', 'B')]),
+        ('B', vec![('a', 'B'), ('b', 'C')]),
+        ('C', vec![('a', 'B'), ('c', 'D')]),
+        ('D', vec![]),
+    ], vec![('A', 'A'), ('B', 'A'), ('C', 'A'), ('D', 'D')]).expect(VALID_DELTA);
+
+    DFA::new(Q, &Σ, δ, 'A', &F).expect(VALID_DFA)
+}
+
+// inputs containing ab
+fn contains_ab() -> DFA<char, char> {
+    let Q = Q::new(vec!['A', 'B', 'C']).expect(VALID_STATES);
+    let Σ = Σ::new(vec!['a', 'b', 'c', 'x', 'z']).expect(VALID_SIGMA);
+    let F = F::new(vec!['C']).expect(VALID_FINAL_STATES);
+    let δ = δ::with_defaults(vec![
+        ('A', vec![('a', 'B')]),
+        ('B', vec![('a', 'B'), ('b', 'C')]),
+        ('C', vec![]),
+    ], vec![('A', 'A'), ('B', 'A'), ('C', 'C')]).expect(VALID_DELTA);
+
+    DFA::new(Q, &Σ, δ, 'A', &F).expect(VALID_DFA)
+}
@@ -0,0 +1,67 @@
+#![allow(non_snake_case)]
+
+use crate::automata::NFA;
+use crate::model::{F, Q, δ, Σ};
+use crate::tests::{STEPS_NO_ERRORS, VALID_DELTA, VALID_FINAL_STATES, VALID_SIGMA, VALID_STATES};
+use crate::tests::automata::nfa::VALID_NFA;
+
+#[test]
+fn given_an_nfa_its_determinized_dfa_should_accept_the_same_inputs() {
+    let nfa = ends_with_zero_one();
+    let mut sut = nfa.determinize();
+
+    for (inputs, expected) in [
+        (&[0, 1][..], true),
+        (&[1, 1, 0, 1], true),
+        (&[0, 1, 1], false),
+        (&[0], false),
+        (&[], false),
+    ] {
+        sut.reset();
+        sut.steps(inputs).expect(STEPS_NO_ERRORS);
+
+        assert_eq!(expected, sut.matches(), "{inputs:?}");
+    }
+}
+
+#[test]
+fn given_an_nfa_its_determinized_dfa_states_should_be_tagged_with_subsets() {
+    let sut = ends_with_zero_one().determinize();
+
+    let actual = sut.states().map(|state| format!("{state:?}")).collect::<Vec<_>>();
+
+    assert_eq!(vec![">({'A'})", "({'A','B'})", "(({'A','C'}))"], actual);
+}
+
+#[test]
+fn given_an_nfa_with_missing_transitions_its_determinized_dfa_should_have_a_dead_state() {
+    let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B']).expect(VALID_STATES);
+    let F = F::new(vec!['B']).expect(VALID_FINAL_STATES);
+    let δ = δ::new(vec![('A', vec![(0, 'B')]), ('B', vec![])]).expect(VALID_DELTA);
+    let nfa = NFA::new(Q, Σ, δ, 'A', &F).expect(VALID_NFA);
+
+    let mut sut = nfa.determinize();
+
+    assert_eq!(3, sut.states().count());
+    assert!(sut.states().any(|state| state.is_empty()));
+
+    sut.steps(&[0, 0]).expect(STEPS_NO_ERRORS);
+
+    assert!(!sut.matches());
+    assert!(sut.current().is_empty());
+}
+
+pub fn ends_with_zero_one() -> NFA<u8, char> {
+    let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B', 'C']).expect(VALID_STATES);
+    let F = F::new(vec!['C']).expect(VALID_FINAL_STATES);
+
+    let δ = δ::new(vec![
+        ('A', vec![(0, 'A'), (1, 'A'), (0, 'B')]),
+        ('B', vec![(1, 'C')]),
+        ('C', vec![]),
+    ]).expect(VALID_DELTA);
+
+    NFA::new(Q, Σ, δ, 'A', &F).expect(VALID_NFA)
+}
@@ -119,6 +119,33 @@ fn given_a_valid_dfa_you_should_be_able_to_reset_and_restart_steps() {
     });
 }
 
+#[test]
+fn given_symbols_that_cannot_be_cloned_a_dfa_should_step_on_them() {
+    let σ = Σ::new(vec![Bit::Zero, Bit::One]).expect(VALID_SIGMA);
+    let q = Q::new(vec!['A', 'B']).expect(VALID_STATES);
+    let r#final = F::new(vec!['B']).expect(VALID_FINAL_STATES);
+
+    let δ = δ::new(vec![
+        ('A', vec![(Bit::Zero, 'A'), (Bit::One, 'B')]),
+        ('B', vec![(Bit::Zero, 'A'), (Bit::One, 'B')]),
+    ]).expect(VALID_DELTA);
+
+    let mut sut = DFA::new(q, &σ, δ, 'A', &r#final).expect(VALID_DFA);
+
+    sut.steps(&[Bit::Zero, Bit::One]).expect(STEPS_NO_ERRORS);
+    assert!(sut.matches());
+
+    sut.reset();
+    assert!(!sut.matches());
+}
+
+// symbols without Clone
+#[derive(Debug, Eq, Hash, PartialEq)]
+enum Bit {
+    Zero,
+    One,
+}
+
 fn assert_steps_case(
     case: Vec<(char, Vec<(u8, char)>)>,
     expected: bool,
@@ -27,6 +27,19 @@ fn given_a_collection_of_transitions_with_dangling_states_we_should_get_an_err()
     assert_dfa_configuration(states, δ, ERR_DANGLING_STATE);
 }
 
+#[test]
+fn given_a_collection_of_transitions_with_an_unreachable_state_we_should_get_an_err() {
+    let states = vec!['A', 'C', 'B'];
+
+    let δ = vec![
+        ('A', vec![(0, 'A'), (1, 'B')]),
+        ('C', vec![(0, 'B'), (1, 'B')]), // no transitions to (C), not even from itself
+        ('B', vec![(0, 'B'), (1, 'A')]),
+    ];
+
+    assert_dfa_configuration(states, δ, ERR_DANGLING_STATE);
+}
+
 #[test]
 fn given_a_collection_of_transitions_with_no_transitions_to_initial_state_should_give_you_a_transition_table() {
     let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
@@ -0,0 +1,78 @@
+#![allow(non_snake_case)]
+
+use crate::automata::DFA;
+use crate::model::{F, Intervals, Q, δ, Σ};
+use crate::tests::{VALID_DELTA, VALID_FINAL_STATES, VALID_SIGMA, VALID_STATES};
+use crate::tests::automata::determinize::ends_with_zero_one;
+use crate::tests::automata::intervals::lowercase;
+use crate::tests::automata::VALID_DFA;
+
+#[test]
+fn given_equivalent_automata_there_should_be_no_distinguishing_word() {
+    let sut = ends_with_zero_one().determinize();
+
+    assert!(sut.equivalent(&ends_with_zero_one().determinize().minimize()));
+    assert_eq!(None, sut.distinguishing_word(&sut));
+}
+
+#[test]
+fn given_different_automata_we_should_get_the_shortest_distinguishing_word() {
+    let sut = ends_with_zero_one().determinize();
+
+    let actual = sut.distinguishing_word(&ends_with_one());
+
+    assert_eq!(Some(vec![1]), actual);
+}
+
+#[test]
+fn given_automata_with_different_alphabets_undefined_symbols_should_be_rejected() {
+    let Σ = Σ::new(vec![1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B']).expect(VALID_STATES);
+    let F = F::new(vec!['B']).expect(VALID_FINAL_STATES);
+    let δ = δ::new(vec![('A', vec![(1, 'B')]), ('B', vec![(1, 'B')])]).expect(VALID_DELTA);
+    let sut = DFA::new(Q, &Σ, δ, 'A', &F).expect(VALID_DFA);
+
+    let actual = sut.distinguishing_word(&ends_with_one());
+
+    assert_eq!(Some(vec![0, 1]), actual);
+}
+
+#[test]
+fn given_dfas_over_intervals_they_should_be_compared_over_their_common_classes() {
+    let sut = lowercase(vec!['a'..='z']);
+
+    assert!(sut.equivalent(&lowercase(vec!['a'..='m', 'n'..='z'])));
+    assert_eq!(None, sut.distinguishing_word(&lowercase(vec!['a'..='m', 'n'..='z'])));
+
+    let Σ = Intervals::new(vec!['a'..='z']).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'D']).expect(VALID_STATES);
+    let F = F::new(vec!['A']).expect(VALID_FINAL_STATES);
+    let δ = δ::new(vec![('A', vec![('a'..='m', 'A'), ('n'..='z', 'D')]), ('D', vec![('a'..='z', 'D')])]).expect(VALID_DELTA);
+    let first_half = DFA::from_intervals(Q, &Σ, δ, 'A', &F).expect(VALID_DFA);
+
+    assert_eq!(Some(vec!['n']), sut.distinguishing_word(&first_half));
+}
+
+#[test]
+fn given_a_dfa_over_intervals_and_one_over_symbols_the_other_symbols_of_a_class_should_be_compared() {
+    let Σ = Σ::new(vec!['a', 'b']).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A']).expect(VALID_STATES);
+    let F = F::new(vec!['A']).expect(VALID_FINAL_STATES);
+    let δ = δ::new(vec![('A', vec![('a', 'A'), ('b', 'A')])]).expect(VALID_DELTA);
+    let sut = DFA::new(Q, &Σ, δ, 'A', &F).expect(VALID_DFA);
+
+    assert_eq!(Some(vec!['c']), sut.distinguishing_word(&lowercase(vec!['a'..='z'])));
+}
+
+fn ends_with_one() -> DFA<u8, char> {
+    let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B']).expect(VALID_STATES);
+    let F = F::new(vec!['B']).expect(VALID_FINAL_STATES);
+
+    let δ = δ::new(vec![
+        ('A', vec![(0, 'A'), (1, 'B')]),
+        ('B', vec![(0, 'A'), (1, 'B')]),
+    ]).expect(VALID_DELTA);
+
+    DFA::new(Q, &Σ, δ, 'A', &F).expect(VALID_DFA)
+}
@@ -0,0 +1,78 @@
+#![allow(non_snake_case)]
+
+use crate::automata::{DFA, Explanation};
+use crate::model::{F, Q, δ, Σ};
+use crate::tests::{STEPS_NO_ERRORS, VALID_DELTA, VALID_FINAL_STATES, VALID_SIGMA, VALID_STATES};
+use crate::tests::automata::intervals::lowercase;
+use crate::tests::automata::VALID_DFA;
+
+#[test]
+fn given_an_accepted_input_explain_should_say_so() {
+    let sut = starts_with_one_ends_with_zero();
+
+    let actual = sut.explain(&[1, 1, 0]);
+
+    assert!(matches!(actual, Explanation::Accepted));
+}
+
+#[test]
+fn given_an_undefined_symbol_explain_should_have_its_position() {
+    let sut = starts_with_one_ends_with_zero();
+
+    let actual = sut.explain(&[1, 0, 7, 0]);
+
+    assert!(matches!(actual, Explanation::UndefinedSymbol { position: 2, ref state } if state[0] == 'C'));
+}
+
+#[test]
+fn given_a_dfa_over_intervals_explain_should_read_any_symbol_of_a_class() {
+    let sut = lowercase(vec!['a'..='z']);
+
+    assert!(matches!(sut.explain(&['b']), Explanation::Accepted));
+    assert!(matches!(sut.explain(&['q', 'B']), Explanation::UndefinedSymbol { position: 1, .. }));
+}
+
+#[test]
+fn given_an_input_entering_a_dead_state_explain_should_have_the_position_it_was_entered() {
+    let sut = starts_with_one_ends_with_zero();
+
+    let actual = sut.explain(&[0, 1, 0]);
+
+    assert!(matches!(actual, Explanation::DeadState { position: Some(0), ref state } if state[0] == 'D'));
+    assert_eq!("symbol at position 0 leads to state (D), from which no final state is reachable", actual.to_string());
+}
+
+#[test]
+fn given_an_input_ending_in_a_non_final_state_explain_should_have_the_shortest_accepting_suffix() {
+    let sut = starts_with_one_ends_with_zero();
+
+    let actual = sut.explain(&[1, 0, 1]);
+
+    assert!(matches!(actual, Explanation::NotFinal { ref state, ref suffix } if state[0] == 'B' && *suffix == [0]));
+}
+
+#[test]
+fn given_an_input_explain_should_not_change_the_current_state() {
+    let mut sut = starts_with_one_ends_with_zero();
+
+    sut.steps(&[1]).expect(STEPS_NO_ERRORS);
+
+    let _ = sut.explain(&[0]);
+
+    assert_eq!('B', sut.current()[0]);
+}
+
+fn starts_with_one_ends_with_zero() -> DFA<u8, char> {
+    let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B', 'C', 'D']).expect(VALID_STATES);
+    let F = F::new(vec!['C']).expect(VALID_FINAL_STATES);
+
+    let δ = δ::new(vec![
+        ('A', vec![(0, 'D'), (1, 'B')]),
+        ('B', vec![(0, 'C'), (1, 'B')]),
+        ('C', vec![(0, 'C'), (1, 'B')]),
+        ('D', vec![(0, 'D'), (1, 'D')]),
+    ]).expect(VALID_DELTA);
+
+    DFA::new(Q, &Σ, δ, 'A', &F).expect(VALID_DFA)
+}
@@ -0,0 +1,94 @@
+#![allow(non_snake_case)]
+
+use std::ops::RangeInclusive;
+
+use crate::automata::DFA;
+use crate::automata::dfa::{ERR_DUPLICATE_INPUT_TRANSITION, ERR_INCOMPLETE_INPUT_TRANSITIONS, ERR_UNDEFINED_SYMBOL};
+use crate::model::{F, Intervals, Q, δ};
+use crate::tests::{assert_err, STEPS_NO_ERRORS, VALID_DELTA, VALID_FINAL_STATES, VALID_SIGMA, VALID_STATES};
+use crate::tests::automata::VALID_DFA;
+
+#[test]
+fn given_a_dfa_over_intervals_it_should_step_on_any_symbol_of_its_ranges() {
+    let mut sut = identifier(vec![
+        ('S', vec![('a'..='z', 'I'), ('\0'..='`', 'E'), ('{'..=char::MAX, 'E')]),
+        ('I', vec![('a'..='z', 'I'), ('0'..='9', 'I'), ('\0'..='/', 'E'), (':'..='`', 'E'), ('{'..=char::MAX, 'E')]),
+        ('E', vec![('\0'..=char::MAX, 'E')]),
+    ]).expect(VALID_DFA);
+
+    sut.steps(&['x', '9', 'q']).expect(STEPS_NO_ERRORS);
+    assert!(sut.matches());
+
+    sut.step(&'ß').expect(STEPS_NO_ERRORS);
+    assert!(!sut.matches());
+
+    sut.reset();
+    sut.step(&'7').expect(STEPS_NO_ERRORS);
+    assert!(!sut.matches());
+
+    let intervals = sut.intervals().expect("intervals");
+
+    assert_eq!(sut.alphabet().len(), intervals.len());
+    assert_eq!(Some(&'0'), intervals.get(intervals.class(&'5').expect("class")).map(RangeInclusive::start));
+}
+
+#[test]
+fn given_a_dfa_over_intervals_minimize_should_keep_its_intervals() {
+    let mut sut = identifier(vec![
+        ('S', vec![('a'..='z', 'I'), ('\0'..='`', 'E'), ('{'..=char::MAX, 'E')]),
+        ('I', vec![('a'..='m', 'I'), ('n'..='z', 'I'), ('\0'..='`', 'E'), ('{'..=char::MAX, 'E')]),
+        ('E', vec![('\0'..=char::MAX, 'E')]),
+    ]).expect(VALID_DFA).minimize();
+
+    sut.steps(&['k', 'x']).expect(STEPS_NO_ERRORS);
+
+    assert!(sut.matches());
+    assert!(sut.intervals().is_some());
+}
+
+#[test]
+fn given_ranges_outside_overlapping_or_not_covering_the_intervals_a_dfa_should_return_an_err() {
+    let lowercase = Intervals::new(vec!['a'..='z']).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['S', 'I']).expect(VALID_STATES);
+    let F = F::new(vec!['I']).expect(VALID_FINAL_STATES);
+    let δ = δ::new(vec![
+        ('S', vec![('a'..='z', 'I')]),
+        ('I', vec![('0'..='z', 'I')]),
+    ]).expect(VALID_DELTA);
+
+    assert_err(ERR_UNDEFINED_SYMBOL, &DFA::from_intervals(Q, &lowercase, δ, 'S', &F));
+
+    assert_err(ERR_DUPLICATE_INPUT_TRANSITION, &identifier(vec![
+        ('S', vec![('\0'..='z', 'I'), ('a'..=char::MAX, 'E')]),
+        ('I', vec![('\0'..=char::MAX, 'I')]),
+        ('E', vec![('\0'..=char::MAX, 'E')]),
+    ]).map(|_| ()));
+
+    assert_err(ERR_INCOMPLETE_INPUT_TRANSITIONS, &identifier(vec![
+        ('S', vec![('a'..='z', 'I')]),
+        ('I', vec![('\0'..=char::MAX, 'I')]),
+        ('E', vec![('\0'..=char::MAX, 'E')]),
+    ]).map(|_| ()));
+}
+
+// words of lowercase letters, over the lowercase letters split by the ranges
+pub fn lowercase(ranges: Vec<RangeInclusive<char>>) -> DFA<char, char> {
+    let Σ = Intervals::new(vec!['a'..='z']).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A']).expect(VALID_STATES);
+    let F = F::new(vec!['A']).expect(VALID_FINAL_STATES);
+    let δ = δ::new(vec![('A', ranges.into_iter().map(|range| (range, 'A')).collect())]).expect(VALID_DELTA);
+
+    DFA::from_intervals(Q, &Σ, δ, 'A', &F).expect(VALID_DFA)
+}
+
+type RangeTransitions = (char, Vec<(RangeInclusive<char>, char)>);
+
+// identifiers of lowercase letters and digits, over every char
+fn identifier(δ: Vec<RangeTransitions>) -> Result<DFA<char, char>, &'static str> {
+    let Σ = Intervals::new(vec!['\0'..=char::MAX]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['S', 'I', 'E']).expect(VALID_STATES);
+    let F = F::new(vec!['I']).expect(VALID_FINAL_STATES);
+    let δ = δ::new(δ).expect(VALID_DELTA);
+
+    DFA::from_intervals(Q, &Σ, δ, 'S', &F)
+}
@@ -0,0 +1,115 @@
+#![allow(non_snake_case)]
+
+use crate::automata::{Dawg, Levenshtein};
+use crate::model::Σ;
+use crate::tests::{STEPS_NO_ERRORS, VALID_SIGMA};
+use crate::tests::automata::intervals::lowercase;
+use crate::tests::automata::{chars, VALID_DFA};
+
+const VALID_DAWG: &str = "valid dawg";
+
+#[test]
+fn given_two_words_the_automaton_should_compute_their_edit_distance() {
+    let sut = Levenshtein::new(&chars("kitten"), 3);
+
+    assert_eq!(Some(0), sut.distance(&chars("kitten")));
+    assert_eq!(Some(1), sut.distance(&chars("kitte")));
+    assert_eq!(Some(3), sut.distance(&chars("sitting")));
+    assert_eq!(None, sut.distance(&chars("sit")));
+    assert_eq!(Some(2), sut.distance(&chars("iktten")));
+}
+
+#[test]
+fn given_transpositions_swapping_adjacent_symbols_should_count_as_one_edit() {
+    let sut = Levenshtein::damerau(&chars("kitten"), 1);
+
+    assert_eq!(Some(1), sut.distance(&chars("iktten")));
+    assert_eq!(Some(1), sut.distance(&chars("kittne")));
+    assert_eq!(None, sut.distance(&chars("itkten")));
+    // substrings are edited at most once, unlike the unrestricted distance of 2
+    assert_eq!(Some(3), Levenshtein::damerau(&chars("ca"), 3).distance(&chars("abc")));
+}
+
+#[test]
+fn given_an_alphabet_the_dfa_should_accept_exactly_the_words_within_the_distance() {
+    let Σ = Σ::new(vec!['a', 'b']).expect(VALID_SIGMA);
+    let sut = Levenshtein::new(&chars("ab"), 1);
+    let mut dfa = sut.to_dfa(&Σ);
+
+    for word in ["", "a", "b", "aa", "ab", "ba", "bb", "aab", "abb", "bab", "aba", "bba", "abab"] {
+        dfa.reset();
+
+        let accepted = dfa.steps(&chars(word)).expect(STEPS_NO_ERRORS).is_final();
+
+        assert_eq!(sut.distance(&chars(word)).is_some(), accepted, "{word}");
+    }
+}
+
+#[test]
+fn given_transpositions_the_dfa_should_accept_exactly_the_words_within_the_distance() {
+    let Σ = Σ::new(vec!['a', 'b']).expect(VALID_SIGMA);
+
+    assert_eq!(vec![chars("ab")], Levenshtein::damerau(&chars("ab"), 0).to_dfa(&Σ).words().collect::<Vec<_>>());
+
+    let sut = Levenshtein::damerau(&chars("abb"), 1);
+    let mut dfa = sut.to_dfa(&Σ);
+
+    for word in ["", "ab", "ba", "abb", "bab", "bba", "aabb", "abab", "baba", "bbbb"] {
+        dfa.reset();
+
+        let accepted = dfa.steps(&chars(word)).expect(STEPS_NO_ERRORS).is_final();
+
+        assert_eq!(sut.distance(&chars(word)).is_some(), accepted, "{word}");
+    }
+}
+
+#[test]
+fn given_a_dictionary_the_fuzzy_matches_should_be_the_words_within_the_distance() {
+    let dictionary = ["bat", "bath", "cart", "cat", "cats", "coat", "dog", "scat"].map(chars);
+    let dfa = Dawg::new(&dictionary).expect(VALID_DAWG).to_dfa().expect(VALID_DFA);
+
+    let matches = dfa.fuzzy_matches(&Levenshtein::new(&chars("cat"), 1));
+
+    assert_eq!(
+        vec![(chars("bat"), 1), (chars("cart"), 1), (chars("cat"), 0), (chars("cats"), 1), (chars("coat"), 1), (chars("scat"), 1)],
+        matches,
+    );
+}
+
+#[test]
+fn given_transpositions_the_fuzzy_matches_should_include_swapped_words() {
+    let dictionary = ["act", "cat", "tac"].map(chars);
+    let dfa = Dawg::new(&dictionary).expect(VALID_DAWG).to_dfa().expect(VALID_DFA);
+
+    assert_eq!(vec![(chars("cat"), 0)], dfa.fuzzy_matches(&Levenshtein::new(&chars("cat"), 1)));
+    assert_eq!(
+        vec![(chars("act"), 1), (chars("cat"), 0)],
+        dfa.fuzzy_matches(&Levenshtein::damerau(&chars("cat"), 1)),
+    );
+}
+
+#[test]
+fn given_a_cyclic_dfa_the_fuzzy_matches_should_be_finite() {
+    let Σ = Σ::new(vec!['a', 'b']).expect(VALID_SIGMA);
+    let dfa = Levenshtein::new(&chars("ab"), 2).to_dfa(&Σ);
+
+    let matches = dfa.fuzzy_matches(&Levenshtein::new(&chars("a"), 1));
+
+    assert_eq!(vec![(chars(""), 1), (chars("a"), 0), (chars("aa"), 1), (chars("ab"), 1), (chars("b"), 1), (chars("ba"), 1)], matches);
+}
+
+#[test]
+fn given_a_dfa_over_intervals_the_fuzzy_matches_should_have_every_symbol_of_a_class() {
+    let dfa = lowercase(vec!['a'..='z']);
+
+    assert_eq!(vec![(chars("b"), 0)], dfa.fuzzy_matches(&Levenshtein::new(&chars("b"), 0)));
+
+    let matches = dfa.fuzzy_matches(&Levenshtein::new(&chars("b"), 1));
+
+    // the empty word, every letter, and every two letters with a b
+    assert_eq!(1 + 26 + 51, matches.len());
+    assert_eq!(
+        vec![(chars(""), 1), (chars("a"), 1), (chars("ab"), 1), (chars("b"), 0), (chars("ba"), 1)],
+        matches[..5],
+    );
+}
@@ -0,0 +1,161 @@
+#![allow(non_snake_case)]
+
+use crate::automata::{ERR_DANGLING_STATE, ERR_INVALID_INPUT, ERR_UNDEFINED_INITIAL_STATE, ERR_UNREFERENCED_STATE_Q, Mealy};
+use crate::automata::dfa::{ERR_INCOMPLETE_INPUT_TRANSITIONS, ERR_UNDEFINED_SYMBOL};
+use crate::automata::mealy::ERR_UNDEFINED_STATE;
+use crate::model::{Q, λ, Σ};
+use crate::tests::{assert_err, STEPS_NO_ERRORS, VALID_LAMBDA, VALID_SIGMA, VALID_STATES};
+use crate::tests::automata::VALID_MEALY;
+
+#[test]
+fn given_a_mealy_machine_step_should_return_the_output_of_the_transition() {
+    let mut sut = rising_edge();
+
+    assert_eq!(Ok(&0), sut.step(&0));
+    assert_eq!(Ok(&1), sut.step(&1));
+    assert_eq!(Ok(&0), sut.step(&1));
+    assert_eq!("(B)", format!("{}", sut.current()));
+}
+
+#[test]
+fn given_a_mealy_machine_transduce_should_return_an_output_for_each_input() {
+    let mut sut = rising_edge();
+
+    let actual = sut.transduce(&[1, 1, 0, 1, 0, 0, 1]).expect(STEPS_NO_ERRORS);
+
+    assert_eq!(vec![1, 0, 0, 1, 0, 0, 1], actual);
+}
+
+#[test]
+fn given_a_mealy_machine_reset_should_return_to_the_initial_state() {
+    let mut sut = rising_edge();
+
+    sut.transduce(&[0, 1]).expect(STEPS_NO_ERRORS);
+    sut.reset();
+
+    assert_eq!(sut.initial(), sut.current());
+}
+
+#[test]
+fn given_a_mealy_machine_transition_should_return_the_next_state_and_output() {
+    let sut = rising_edge();
+
+    let (next, output) = sut.transition(sut.initial(), &1).expect("defined transition");
+
+    assert_eq!(("(B)".to_string(), 1), (format!("{next}"), *output));
+    assert!(sut.transition(sut.initial(), &2).is_none());
+}
+
+#[test]
+fn given_an_undefined_input_symbol_step_should_return_an_err() {
+    let mut sut = rising_edge();
+
+    assert_err(ERR_INVALID_INPUT, &sut.transduce(&[0, 2]));
+}
+
+#[test]
+fn given_a_mealy_machine_with_equivalent_states_minimize_should_merge_them() {
+    let sut = redundant().minimize();
+
+    let actual = sut.states().map(|state| format!("{state}")).collect::<Vec<_>>();
+
+    assert_eq!(vec![">(A)", "(B)"], actual);
+}
+
+#[test]
+fn given_a_mealy_machine_its_minimized_machine_should_produce_the_same_outputs() {
+    let inputs = [1, 0, 0, 1, 1, 0, 1, 0];
+    let mut expected = redundant();
+    let mut sut = expected.minimize();
+
+    assert_eq!(
+        expected.transduce(&inputs).expect(STEPS_NO_ERRORS),
+        sut.transduce(&inputs).expect(STEPS_NO_ERRORS),
+    );
+}
+
+#[test]
+fn given_a_state_without_transitions_we_should_get_an_err() {
+    let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B']).expect(VALID_STATES);
+    let λ = λ::new(vec![('A', vec![(0, 'A', 0), (1, 'A', 1)])]).expect(VALID_LAMBDA);
+
+    assert_err(ERR_UNREFERENCED_STATE_Q, &Mealy::new(Q, &Σ, λ, 'A'));
+}
+
+#[test]
+fn given_transitions_of_a_state_not_in_Q_we_should_get_an_err() {
+    let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A']).expect(VALID_STATES);
+    let λ = λ::new(vec![
+        ('A', vec![(0, 'A', 0), (1, 'A', 1)]),
+        ('B', vec![(0, 'A', 0), (1, 'A', 1)]), // (B) is not in Q
+    ]).expect(VALID_LAMBDA);
+
+    assert_err(ERR_UNDEFINED_STATE, &Mealy::new(Q, &Σ, λ, 'A'));
+}
+
+#[test]
+fn given_an_undefined_initial_state_we_should_get_an_err() {
+    let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A']).expect(VALID_STATES);
+    let λ = λ::new(vec![('A', vec![(0, 'A', 0), (1, 'A', 1)])]).expect(VALID_LAMBDA);
+
+    assert_err(ERR_UNDEFINED_INITIAL_STATE, &Mealy::new(Q, &Σ, λ, 'B'));
+}
+
+#[test]
+fn given_incomplete_input_transitions_we_should_get_an_err() {
+    let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A']).expect(VALID_STATES);
+    let λ = λ::new(vec![('A', vec![(0, 'A', 0)])]).expect(VALID_LAMBDA); // no transition on 1
+
+    assert_err(ERR_INCOMPLETE_INPUT_TRANSITIONS, &Mealy::new(Q, &Σ, λ, 'A'));
+}
+
+#[test]
+fn given_a_transition_on_a_symbol_not_in_Σ_we_should_get_an_err() {
+    let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A']).expect(VALID_STATES);
+    let λ = λ::new(vec![('A', vec![(0, 'A', 0), (1, 'A', 1), (2, 'A', 0)])]).expect(VALID_LAMBDA);
+
+    assert_err(ERR_UNDEFINED_SYMBOL, &Mealy::new(Q, &Σ, λ, 'A'));
+}
+
+#[test]
+fn given_a_state_without_incoming_transitions_we_should_get_an_err() {
+    let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B']).expect(VALID_STATES);
+    let λ = λ::new(vec![
+        ('A', vec![(0, 'A', 0), (1, 'A', 1)]),
+        ('B', vec![(0, 'A', 0), (1, 'B', 1)]), // nothing transitions to (B)
+    ]).expect(VALID_LAMBDA);
+
+    assert_err(ERR_DANGLING_STATE, &Mealy::new(Q, &Σ, λ, 'A'));
+}
+
+// outputs 1 when the input rises from 0 to 1, A after a 0, B after a 1
+pub fn rising_edge() -> Mealy<u8, u8, char> {
+    let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B']).expect(VALID_STATES);
+    let λ = λ::new(vec![
+        ('A', vec![(0, 'A', 0), (1, 'B', 1)]),
+        ('B', vec![(0, 'A', 0), (1, 'B', 0)]),
+    ]).expect(VALID_LAMBDA);
+
+    Mealy::new(Q, &Σ, λ, 'A').expect(VALID_MEALY)
+}
+
+// rising edge, C and D duplicate A and B
+fn redundant() -> Mealy<u8, u8, char> {
+    let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B', 'C', 'D']).expect(VALID_STATES);
+    let λ = λ::new(vec![
+        ('A', vec![(0, 'C', 0), (1, 'B', 1)]),
+        ('B', vec![(0, 'A', 0), (1, 'D', 0)]),
+        ('C', vec![(0, 'A', 0), (1, 'D', 1)]),
+        ('D', vec![(0, 'C', 0), (1, 'B', 0)]),
+    ]).expect(VALID_LAMBDA);
+
+    Mealy::new(Q, &Σ, λ, 'A').expect(VALID_MEALY)
+}
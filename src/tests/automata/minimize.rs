@@ -0,0 +1,50 @@
+#![allow(non_snake_case)]
+
+use crate::automata::DFA;
+use crate::model::{F, Q, δ, Σ};
+use crate::tests::{STEPS_NO_ERRORS, VALID_DELTA, VALID_FINAL_STATES, VALID_SIGMA, VALID_STATES};
+use crate::tests::automata::VALID_DFA;
+
+#[test]
+fn given_a_dfa_with_equivalent_states_minimize_should_merge_them() {
+    let sut = redundant().minimize();
+
+    let actual = sut.states().map(|state| format!("{state}")).collect::<Vec<_>>();
+
+    assert_eq!(vec![">(A)", "((B))"], actual);
+}
+
+#[test]
+fn given_a_dfa_its_minimized_dfa_should_accept_the_same_inputs() {
+    let dfa = redundant();
+    let mut sut = dfa.minimize();
+
+    assert!(sut.equivalent(&dfa));
+
+    sut.steps(&[0, 1, 1, 1]).expect(STEPS_NO_ERRORS);
+
+    assert!(sut.matches());
+}
+
+#[test]
+fn given_a_minimal_dfa_minimize_should_keep_all_states() {
+    let sut = redundant().minimize();
+
+    assert_eq!(sut.states().count(), sut.minimize().states().count());
+}
+
+// odd number of 1s, C and D duplicate A and B
+fn redundant() -> DFA<u8, char> {
+    let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B', 'C', 'D']).expect(VALID_STATES);
+    let F = F::new(vec!['B', 'D']).expect(VALID_FINAL_STATES);
+
+    let δ = δ::new(vec![
+        ('A', vec![(0, 'C'), (1, 'B')]),
+        ('B', vec![(0, 'D'), (1, 'C')]),
+        ('C', vec![(0, 'A'), (1, 'D')]),
+        ('D', vec![(0, 'B'), (1, 'A')]),
+    ]).expect(VALID_DELTA);
+
+    DFA::new(Q, &Σ, δ, 'A', &F).expect(VALID_DFA)
+}
@@ -1,5 +1,38 @@
+mod aho_corasick;
+mod algebra;
+pub mod buchi;
+mod count;
+mod dawg;
+mod defaults;
+pub mod determinize;
 mod dfa;
 mod dfa_configuration;
+mod equivalence;
+mod explain;
+pub mod intervals;
+mod levenshtein;
+pub mod mealy;
+mod minimize;
+mod moore;
 mod nfa;
+mod pda;
+mod probabilistic;
+mod sample;
+mod semiring;
+mod suffix;
+mod symbolic;
+mod trace;
+mod weighted;
+mod words;
 
 const VALID_DFA: &str = "valid dfa";
+const VALID_MEALY: &str = "valid mealy machine";
+const VALID_MOORE: &str = "valid moore machine";
+
+fn chars(word: &str) -> Vec<char> {
+    word.chars().collect()
+}
+
+fn words(words: &[&str]) -> Vec<Vec<char>> {
+    words.iter().map(|word| chars(word)).collect()
+}
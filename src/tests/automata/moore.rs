@@ -0,0 +1,120 @@
+#![allow(non_snake_case)]
+
+use crate::automata::{ERR_INVALID_INPUT, Moore};
+use crate::automata::moore::{ERR_DUPLICATE_STATE_OUTPUT, ERR_MISSING_STATE_OUTPUT, ERR_UNDEFINED_OUTPUT_STATE};
+use crate::model::{Q, δ, Σ};
+use crate::tests::{assert_err, STEPS_NO_ERRORS, VALID_DELTA, VALID_SIGMA, VALID_STATES};
+use crate::tests::automata::mealy::rising_edge;
+use crate::tests::automata::VALID_MOORE;
+
+#[test]
+fn given_a_moore_machine_output_should_return_the_output_of_the_current_state() {
+    let mut sut = parity();
+
+    assert_eq!(&0, sut.output());
+
+    sut.step(&1).expect(STEPS_NO_ERRORS);
+
+    assert_eq!(&1, sut.output());
+    assert_eq!(Some(&0), sut.output_of(sut.initial()));
+}
+
+#[test]
+fn given_a_moore_machine_transduce_should_return_the_output_of_each_state_entered() {
+    let mut sut = parity();
+
+    let actual = sut.transduce(&[1, 0, 1, 1, 0]).expect(STEPS_NO_ERRORS);
+
+    assert_eq!(vec![1, 1, 0, 1, 1], actual);
+    assert_eq!("(O)", format!("{}", sut.current()));
+}
+
+#[test]
+fn given_a_moore_machine_reset_should_return_to_the_initial_state() {
+    let mut sut = parity();
+
+    sut.transduce(&[1]).expect(STEPS_NO_ERRORS);
+    sut.reset();
+
+    assert_eq!(sut.initial(), sut.current());
+    assert_eq!(&0, sut.output());
+}
+
+#[test]
+fn given_an_undefined_input_symbol_step_should_return_an_err() {
+    let mut sut = parity();
+
+    assert_err(ERR_INVALID_INPUT, &sut.transduce(&[1, 2]));
+}
+
+#[test]
+fn given_a_moore_machine_its_mealy_machine_should_produce_the_same_outputs() {
+    let inputs = [1, 1, 0, 1, 0, 0, 1];
+    let mut expected = parity();
+    let mut sut = expected.to_mealy();
+
+    assert_eq!(
+        expected.transduce(&inputs).expect(STEPS_NO_ERRORS),
+        sut.transduce(&inputs).expect(STEPS_NO_ERRORS),
+    );
+}
+
+#[test]
+fn given_a_mealy_machine_its_moore_machine_should_split_states_by_output() {
+    let sut = rising_edge().to_moore(0);
+
+    let actual = sut.states().map(|state| format!("{state:?}")).collect::<Vec<_>>();
+
+    assert_eq!(vec![">({('A', 0)})", "({('B', 1)})", "({('B', 0)})"], actual);
+}
+
+#[test]
+fn given_a_mealy_machine_its_moore_machine_should_produce_the_same_outputs() {
+    let inputs = [1, 1, 0, 1, 0, 0, 1];
+    let mut expected = rising_edge();
+    let mut sut = expected.to_moore(0);
+
+    assert_eq!(
+        expected.transduce(&inputs).expect(STEPS_NO_ERRORS),
+        sut.transduce(&inputs).expect(STEPS_NO_ERRORS),
+    );
+}
+
+#[test]
+fn given_a_state_with_more_than_one_output_we_should_get_an_err() {
+    let (Q, Σ, δ) = parity_definition();
+
+    assert_err(ERR_DUPLICATE_STATE_OUTPUT, &Moore::new(Q, &Σ, δ, 'E', vec![('E', 0), ('O', 1), ('E', 1)]));
+}
+
+#[test]
+fn given_a_state_without_an_output_we_should_get_an_err() {
+    let (Q, Σ, δ) = parity_definition();
+
+    assert_err(ERR_MISSING_STATE_OUTPUT, &Moore::new(Q, &Σ, δ, 'E', vec![('E', 0)]));
+}
+
+#[test]
+fn given_an_output_of_a_state_not_in_Q_we_should_get_an_err() {
+    let (Q, Σ, δ) = parity_definition();
+
+    assert_err(ERR_UNDEFINED_OUTPUT_STATE, &Moore::new(Q, &Σ, δ, 'E', vec![('E', 0), ('O', 1), ('X', 1)]));
+}
+
+// outputs the parity of the number of 1s read, E even and O odd
+fn parity() -> Moore<u8, u8, char> {
+    let (Q, Σ, δ) = parity_definition();
+
+    Moore::new(Q, &Σ, δ, 'E', vec![('E', 0), ('O', 1)]).expect(VALID_MOORE)
+}
+
+fn parity_definition() -> (Q<char>, Σ<u8>, δ<u8, char>) {
+    let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['E', 'O']).expect(VALID_STATES);
+    let δ = δ::new(vec![
+        ('E', vec![(0, 'E'), (1, 'O')]),
+        ('O', vec![(0, 'O'), (1, 'E')]),
+    ]).expect(VALID_DELTA);
+
+    (Q, Σ, δ)
+}
@@ -9,7 +9,7 @@ mod nfa;
 mod nfa_configuration;
 mod nfa_with_deterministic_detlas;
 
-const VALID_NFA: &str = "valid nfa";
+pub const VALID_NFA: &str = "valid nfa";
 
 fn assert_steps_case(
     tags: Vec<char>,
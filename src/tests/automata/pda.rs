@@ -0,0 +1,253 @@
+#![allow(non_snake_case)]
+
+use crate::automata::{Acceptance, DPDA, ERR_INVALID_INPUT, Move, PDA};
+use crate::automata::dfa::ERR_UNDEFINED_SYMBOL;
+use crate::automata::pda::{
+    ERR_CONFIGURATION_LIMIT,
+    ERR_DUPLICATE_MOVE,
+    ERR_NONDETERMINISTIC_MOVES,
+    ERR_STACK_LIMIT,
+    ERR_UNDEFINED_INITIAL_STACK_SYMBOL,
+    ERR_UNDEFINED_MOVE_STATE,
+    ERR_UNDEFINED_STACK_SYMBOL,
+};
+use crate::model::{F, Q, Σ};
+use crate::tests::{assert_err, STEPS_NO_ERRORS, VALID_FINAL_STATES, VALID_SIGMA, VALID_STATES};
+
+const VALID_PDA: &str = "valid pda";
+
+#[test]
+fn given_a_pda_it_should_accept_even_palindromes() {
+    let mut sut = palindromes();
+
+    sut.steps(&['a', 'b', 'b', 'a']).expect(STEPS_NO_ERRORS);
+
+    assert!(sut.matches());
+}
+
+#[test]
+fn given_a_pda_it_should_reject_other_inputs() {
+    let mut sut = palindromes();
+
+    sut.steps(&['a', 'b', 'a', 'b']).expect(STEPS_NO_ERRORS);
+
+    assert!(!sut.matches());
+}
+
+#[test]
+fn given_a_pda_its_initial_configurations_should_include_ε_moves() {
+    let sut = palindromes();
+
+    let actual = sut.configurations().map(|configuration| format!("{configuration}")).collect::<Vec<_>>();
+
+    assert_eq!(vec![">(P) [Z]", "(R) [Z]", "((F)) [Z]"], actual);
+    assert!(sut.matches());
+}
+
+#[test]
+fn given_a_pda_step_should_return_the_configurations_after_the_input() {
+    let mut sut = palindromes();
+
+    let actual = sut.step(&'a').expect(STEPS_NO_ERRORS)
+        .iter()
+        .map(|configuration| format!("{configuration}"))
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec![">(P) [a Z]", "(R) [a Z]"], actual);
+}
+
+#[test]
+fn given_a_pda_reset_should_return_to_the_initial_configurations() {
+    let mut sut = palindromes();
+
+    sut.steps(&['a', 'b']).expect(STEPS_NO_ERRORS);
+    sut.reset();
+
+    assert_eq!(3, sut.configurations().count());
+    assert!(sut.configurations().all(|configuration| configuration.stack == ['Z']));
+}
+
+#[test]
+fn given_a_pda_accepting_by_empty_stack_it_should_accept_balanced_brackets() {
+    let mut sut = PDA::new(
+        Q::new(vec!['q']).expect(VALID_STATES),
+        Σ::new(vec!['(', ')']).expect(VALID_SIGMA),
+        Σ::new(vec!['X', 'Z']).expect(VALID_SIGMA),
+        vec![
+            Move::new('q', Some('('), None, 'q', vec!['X']),
+            Move::new('q', Some(')'), Some('X'), 'q', vec![]),
+            Move::new('q', None, Some('Z'), 'q', vec![]),
+        ],
+        'q',
+        'Z',
+        Acceptance::EmptyStack,
+    ).expect(VALID_PDA);
+
+    sut.steps(&['(', '(', ')', ')']).expect(STEPS_NO_ERRORS);
+
+    assert!(sut.matches());
+
+    sut.reset();
+    sut.steps(&['(', ')', ')']).expect(STEPS_NO_ERRORS);
+
+    assert!(!sut.matches());
+    assert_eq!(0, sut.configurations().count());
+}
+
+#[test]
+fn given_an_undefined_input_symbol_step_should_return_an_err() {
+    let mut sut = palindromes();
+
+    assert_err(ERR_INVALID_INPUT, &sut.steps(&['a', 'c']).map(|_| ()));
+}
+
+#[test]
+fn given_ε_moves_pushing_without_bound_we_should_get_an_err() {
+    let sut = pda(vec![Move::new('P', None, None, 'P', vec!['a'])]);
+
+    assert_err(ERR_STACK_LIMIT, &sut);
+}
+
+#[test]
+fn given_ε_moves_branching_without_bound_we_should_get_an_err() {
+    let sut = pda(vec![
+        Move::new('P', None, None, 'P', vec!['a']),
+        Move::new('P', None, None, 'P', vec!['b']),
+    ]);
+
+    assert_err(ERR_CONFIGURATION_LIMIT, &sut);
+}
+
+#[test]
+fn given_duplicate_moves_we_should_get_an_err() {
+    let sut = pda(vec![
+        Move::new('P', Some('a'), None, 'P', vec!['a']),
+        Move::new('P', Some('a'), None, 'P', vec!['a']),
+    ]);
+
+    assert_err(ERR_DUPLICATE_MOVE, &sut);
+}
+
+#[test]
+fn given_a_move_to_a_state_not_in_Q_we_should_get_an_err() {
+    let sut = pda(vec![Move::new('P', Some('a'), None, 'X', vec!['a'])]);
+
+    assert_err(ERR_UNDEFINED_MOVE_STATE, &sut);
+}
+
+#[test]
+fn given_a_move_on_a_symbol_not_in_Σ_we_should_get_an_err() {
+    let sut = pda(vec![Move::new('P', Some('c'), None, 'P', vec!['a'])]);
+
+    assert_err(ERR_UNDEFINED_SYMBOL, &sut);
+}
+
+#[test]
+fn given_a_move_pushing_a_symbol_not_in_Γ_we_should_get_an_err() {
+    let sut = pda(vec![Move::new('P', Some('a'), None, 'P', vec!['c'])]);
+
+    assert_err(ERR_UNDEFINED_STACK_SYMBOL, &sut);
+}
+
+#[test]
+fn given_an_initial_stack_symbol_not_in_Γ_we_should_get_an_err() {
+    let sut = PDA::new(
+        Q::new(vec!['P']).expect(VALID_STATES),
+        Σ::new(vec!['a']).expect(VALID_SIGMA),
+        Σ::new(vec!['a']).expect(VALID_SIGMA),
+        vec![Move::new('P', Some('a'), None, 'P', vec!['a'])],
+        'P',
+        'Z',
+        Acceptance::EmptyStack,
+    );
+
+    assert_err(ERR_UNDEFINED_INITIAL_STACK_SYMBOL, &sut);
+}
+
+#[test]
+fn given_a_dpda_it_should_accept_balanced_brackets() {
+    let mut sut = brackets();
+
+    let actual = sut.steps(&['(', '(', ')']).expect(STEPS_NO_ERRORS).map(|configuration| format!("{configuration}"));
+
+    assert_eq!(Some("(q) [X Z]".to_string()), actual);
+    assert!(!sut.matches());
+
+    sut.step(&')').expect(STEPS_NO_ERRORS);
+
+    assert!(sut.matches());
+}
+
+#[test]
+fn given_a_dpda_without_an_applicable_move_it_should_have_no_configuration() {
+    let mut sut = brackets();
+
+    assert!(sut.step(&')').expect(STEPS_NO_ERRORS).is_none());
+    assert!(!sut.matches());
+
+    sut.reset();
+
+    assert_eq!(">((s)) [Z]", format!("{}", sut.configuration().expect("initial configuration")));
+}
+
+#[test]
+fn given_nondeterministic_moves_a_dpda_should_return_an_err() {
+    let sut = DPDA::new(
+        Q::new(vec!['P', 'R', 'F']).expect(VALID_STATES),
+        Σ::new(vec!['a', 'b']).expect(VALID_SIGMA),
+        Σ::new(vec!['a', 'b', 'Z']).expect(VALID_SIGMA),
+        palindrome_moves(),
+        'P',
+        'Z',
+        Acceptance::FinalState(F::new(vec!['F']).expect(VALID_FINAL_STATES)),
+    );
+
+    assert_err(ERR_NONDETERMINISTIC_MOVES, &sut);
+}
+
+// balanced brackets, s when balanced, q within brackets and p after a closing bracket
+fn brackets() -> DPDA<char, char, char> {
+    DPDA::new(
+        Q::new(vec!['s', 'q', 'p']).expect(VALID_STATES),
+        Σ::new(vec!['(', ')']).expect(VALID_SIGMA),
+        Σ::new(vec!['X', 'Z']).expect(VALID_SIGMA),
+        vec![
+            Move::new('s', Some('('), Some('Z'), 'q', vec!['X', 'Z']),
+            Move::new('q', Some('('), Some('X'), 'q', vec!['X', 'X']),
+            Move::new('q', Some(')'), Some('X'), 'p', vec![]),
+            Move::new('p', None, Some('X'), 'q', vec!['X']),
+            Move::new('p', None, Some('Z'), 's', vec!['Z']),
+        ],
+        's',
+        'Z',
+        Acceptance::FinalState(F::new(vec!['s']).expect(VALID_FINAL_STATES)),
+    ).expect(VALID_PDA)
+}
+
+// even length palindromes, P pushes the first half and R pops the reversed half
+fn palindromes() -> PDA<char, char, char> {
+    pda(palindrome_moves()).expect(VALID_PDA)
+}
+
+fn palindrome_moves() -> Vec<Move<char, char, char>> {
+    vec![
+        Move::new('P', Some('a'), None, 'P', vec!['a']),
+        Move::new('P', Some('b'), None, 'P', vec!['b']),
+        Move::new('P', None, None, 'R', vec![]),
+        Move::new('R', Some('a'), Some('a'), 'R', vec![]),
+        Move::new('R', Some('b'), Some('b'), 'R', vec![]),
+        Move::new('R', None, Some('Z'), 'F', vec!['Z']),
+    ]
+}
+
+fn pda(δ: Vec<Move<char, char, char>>) -> Result<PDA<char, char, char>, &'static str> {
+    PDA::new(
+        Q::new(vec!['P', 'R', 'F']).expect(VALID_STATES),
+        Σ::new(vec!['a', 'b']).expect(VALID_SIGMA),
+        Σ::new(vec!['a', 'b', 'Z']).expect(VALID_SIGMA),
+        δ,
+        'P',
+        'Z',
+        Acceptance::FinalState(F::new(vec!['F']).expect(VALID_FINAL_STATES)),
+    )
+}
@@ -0,0 +1,140 @@
+#![allow(non_snake_case)]
+
+use crate::automata::{ERR_INVALID_INPUT, ProbabilisticAutomaton};
+use crate::automata::probabilistic::{ERR_INVALID_PROBABILITY, ERR_NOT_STOCHASTIC};
+use crate::model::{Q, Σ};
+use crate::random::Random;
+use crate::tests::{assert_err, VALID_SIGMA, VALID_STATES};
+
+const VALID_PROBABILISTIC: &str = "valid probabilistic automaton";
+
+#[test]
+fn given_a_probabilistic_automaton_the_likelihood_of_an_input_should_sum_its_paths() {
+    let sut = weather();
+
+    assert_close(0.25, sut.likelihood(&[]));
+    assert_close(0.13, sut.likelihood(&['n']));
+    assert_close(0.042, sut.likelihood(&['n', 'u']));
+}
+
+#[test]
+fn given_a_long_input_its_log_likelihood_should_not_underflow() {
+    let sut = weather();
+
+    let actual = sut.log_likelihood(&['n'; 2000]).expect("valid input");
+
+    assert!(actual.is_finite() && actual < -1000.0, "{actual}");
+}
+
+#[test]
+fn given_a_probabilistic_automaton_the_most_likely_path_should_be_found_with_its_probability() {
+    let sut = weather();
+
+    let (path, probability) = sut.most_likely_path(&['n', 'u']).expect("valid input").expect("likely path");
+
+    assert_eq!(vec![">((S))", ">((S))", "((R))"], path.iter().map(|state| format!("{state}")).collect::<Vec<_>>());
+    assert_close(0.03, Ok(probability));
+}
+
+#[test]
+fn given_an_input_that_cannot_be_generated_its_likelihood_should_be_zero() {
+    let sut = ProbabilisticAutomaton::new(
+        Q::new(vec!['A', 'B']).expect(VALID_STATES),
+        Σ::new(vec!['a', 'b']).expect(VALID_SIGMA),
+        vec![('A', 'a', 'B', 0.5)],
+        'A',
+        vec![('A', 0.5), ('B', 1.0)],
+    ).expect(VALID_PROBABILISTIC);
+
+    assert_eq!(Ok(0.0), sut.likelihood(&['b']));
+    assert_eq!(Ok(f64::NEG_INFINITY), sut.log_likelihood(&['a', 'a']));
+    assert_eq!(Ok(None), sut.most_likely_path(&['a', 'a']));
+}
+
+#[test]
+fn given_an_undefined_input_symbol_we_should_get_an_err() {
+    assert_err(ERR_INVALID_INPUT, &weather().likelihood(&['x']));
+    assert_err(ERR_INVALID_INPUT, &weather().most_likely_path(&['x']));
+}
+
+#[test]
+fn given_a_seed_sampling_should_reproduce_the_same_words() {
+    let sut = weather();
+    let (mut random, mut other) = (Random::new(3), Random::new(3));
+
+    for _ in 0..20 {
+        assert_eq!(sut.sample(&mut other, 100), sut.sample(&mut random, 100));
+    }
+}
+
+#[test]
+fn given_many_samples_their_frequencies_should_follow_the_distribution() {
+    let sut = weather();
+    let mut random = Random::new(11);
+    let samples = 20_000;
+
+    let words = (0..samples).map(|_| sut.sample(&mut random, 1000).expect("complete word")).collect::<Vec<_>>();
+    let frequency = |word: &[char]| f64::from(u32::try_from(words.iter().filter(|sample| sample.as_slice() == word).count()).expect("count")) / f64::from(samples);
+
+    assert!((frequency(&[]) - 0.25).abs() < 0.01);
+    assert!((frequency(&['n']) - 0.13).abs() < 0.01);
+}
+
+#[test]
+fn given_a_limit_sampling_should_return_none_for_longer_words() {
+    let mut random = Random::new(5);
+
+    assert!((0..50).filter_map(|_| weather().sample(&mut random, 0)).all(|word| word.is_empty()));
+}
+
+#[test]
+fn given_probabilities_of_a_state_not_summing_to_one_we_should_get_an_err() {
+    let sut = ProbabilisticAutomaton::new(
+        Q::new(vec!['A']).expect(VALID_STATES),
+        Σ::new(vec!['a']).expect(VALID_SIGMA),
+        vec![('A', 'a', 'A', 0.5)],
+        'A',
+        vec![('A', 0.4)],
+    );
+
+    assert_err(ERR_NOT_STOCHASTIC, &sut);
+}
+
+#[test]
+fn given_a_probability_outside_of_the_unit_interval_we_should_get_an_err() {
+    let sut = ProbabilisticAutomaton::new(
+        Q::new(vec!['A']).expect(VALID_STATES),
+        Σ::new(vec!['a']).expect(VALID_SIGMA),
+        vec![('A', 'a', 'A', 1.5)],
+        'A',
+        vec![('A', -0.5)],
+    );
+
+    assert_err(ERR_INVALID_PROBABILITY, &sut);
+}
+
+fn assert_close(expected: f64, actual: Result<f64, &str>) {
+    let actual = actual.expect("valid input");
+
+    assert!((expected - actual).abs() < 1e-12, "expected {expected}, got {actual}");
+}
+
+// umbrella, u, or none, n, seen on rainy, R, and sunny, S, days
+fn weather() -> ProbabilisticAutomaton<char, char> {
+    let Σ = Σ::new(vec!['u', 'n']).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['R', 'S']).expect(VALID_STATES);
+
+    ProbabilisticAutomaton::new(
+        Q,
+        Σ,
+        vec![
+            ('R', 'u', 'R', 0.4),
+            ('R', 'n', 'S', 0.3),
+            ('S', 'n', 'S', 0.4),
+            ('S', 'n', 'R', 0.1),
+            ('S', 'u', 'R', 0.25),
+        ],
+        'S',
+        vec![('R', 0.3), ('S', 0.25)],
+    ).expect(VALID_PROBABILISTIC)
+}
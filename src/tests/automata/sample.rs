@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use crate::automata::count::ERR_COUNT_OVERFLOW;
+use crate::random::{Random, Rng};
+use crate::tests::{assert_err, STEPS_NO_ERRORS};
+use crate::tests::automata::determinize::ends_with_zero_one;
+use crate::tests::automata::intervals::lowercase;
+
+const VALID_SAMPLE: &str = "valid sample";
+
+#[test]
+fn given_a_length_the_sample_should_be_an_accepted_word_of_that_length() {
+    let mut sut = ends_with_zero_one().determinize();
+    let mut random = Random::new(3);
+
+    for len in 2..10 {
+        let word = sut.sample(len, &mut random).expect(VALID_SAMPLE).expect(VALID_SAMPLE);
+
+        sut.reset();
+
+        assert_eq!(len, word.len());
+        assert!(sut.steps(&word).expect(STEPS_NO_ERRORS).is_final());
+    }
+}
+
+#[test]
+fn given_no_accepted_word_of_a_length_the_sample_should_be_none() {
+    let sut = ends_with_zero_one().determinize();
+
+    assert_eq!(Ok(None), sut.sample(1, &mut Random::new(3)));
+    assert_eq!(None, ends_with_zero_one().random_walk(0, &mut Random::new(3)));
+}
+
+#[test]
+fn given_many_samples_the_words_should_be_drawn_uniformly() {
+    let sut = ends_with_zero_one().determinize();
+    let mut random = Random::new(17);
+    let mut draws = HashMap::new();
+
+    for _ in 0..8000 {
+        *draws.entry(sut.sample(5, &mut random).expect(VALID_SAMPLE)).or_insert(0) += 1;
+    }
+
+    // the 8 words of 5 symbols ending with 01
+    assert_eq!(8, draws.len());
+    assert!(draws.values().all(|count| (900..1100).contains(count)));
+}
+
+#[test]
+fn given_a_dfa_over_intervals_the_samples_should_be_drawn_among_every_symbol_of_a_class() {
+    let sut = lowercase(vec!['a'..='m', 'n'..='z']);
+    let mut random = Random::new(23);
+    let mut draws = HashMap::new();
+
+    for _ in 0..5200 {
+        *draws.entry(sut.sample(1, &mut random).expect(VALID_SAMPLE)).or_insert(0) += 1;
+    }
+
+    assert_eq!(26, draws.len());
+    assert!(draws.values().all(|count| (140..260).contains(count)));
+}
+
+#[test]
+fn given_the_same_seed_the_samples_should_be_the_same() {
+    let sut = ends_with_zero_one().determinize();
+    let (mut random, mut other) = (Random::from_seed(9), Random::from_seed(9));
+
+    for _ in 0..10 {
+        assert_eq!(sut.sample(12, &mut random), sut.sample(12, &mut other));
+    }
+}
+
+#[test]
+fn given_too_many_words_sampling_them_should_overflow() {
+    let sut = ends_with_zero_one().determinize();
+
+    assert!(sut.sample(128, &mut Random::new(1)).is_ok());
+    assert_err(ERR_COUNT_OVERFLOW, &sut.sample(200, &mut Random::new(1)));
+}
+
+#[test]
+fn given_an_nfa_the_random_walk_should_be_an_accepted_word_of_that_length() {
+    let mut sut = ends_with_zero_one();
+    let mut random = Random::new(5);
+
+    for len in 2..10 {
+        let word = sut.random_walk(len, &mut random).expect(VALID_SAMPLE);
+
+        sut.reset();
+
+        assert_eq!(len, word.len());
+        assert_eq!([0, 1], word[len - 2..]);
+        sut.steps(&word).expect(STEPS_NO_ERRORS);
+
+        assert!(sut.matches());
+    }
+}
@@ -0,0 +1,36 @@
+use crate::automata::{Boolean, Counting, Probability, Semiring, Tropical};
+
+#[test]
+fn given_a_semiring_zero_and_one_should_be_the_identities_of_plus_and_times() {
+    fn assert_identities<W: Semiring>(weight: &W) {
+        assert_eq!(*weight, weight.plus(&W::zero()));
+        assert_eq!(*weight, weight.times(&W::one()));
+        assert_eq!(W::zero(), weight.times(&W::zero()));
+    }
+
+    assert_identities(&Boolean(true));
+    assert_identities(&Tropical(2.5));
+    assert_identities(&Probability(0.25));
+    assert_identities(&Counting(3));
+}
+
+#[test]
+fn given_a_tropical_weight_plus_should_take_the_cheapest_and_times_should_add() {
+    assert_eq!(Tropical(2.0), Tropical(2.0).plus(&Tropical(3.0)));
+    assert_eq!(Tropical(5.0), Tropical(2.0).times(&Tropical(3.0)));
+}
+
+#[test]
+fn given_a_weight_its_star_should_be_the_weight_of_any_number_of_turns_of_a_cycle() {
+    assert_eq!(Some(Boolean(true)), Boolean(false).star());
+    assert_eq!(Some(Tropical(0.0)), Tropical(1.5).star());
+    assert_eq!(Some(Probability(2.0)), Probability(0.5).star());
+    assert_eq!(Some(Counting(1)), Counting(0).star());
+}
+
+#[test]
+fn given_a_diverging_cycle_its_star_should_be_none() {
+    assert_eq!(None, Tropical(-1.0).star());
+    assert_eq!(None, Probability(1.0).star());
+    assert_eq!(None, Counting(1).star());
+}
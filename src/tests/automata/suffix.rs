@@ -0,0 +1,73 @@
+use crate::automata::{ERR_INVALID_INPUT, SuffixAutomaton};
+use crate::model::sigma::ERR_EMPTY_ALPHABET;
+use crate::tests::{assert_err, STEPS_NO_ERRORS};
+use crate::tests::automata::{chars, VALID_DFA};
+
+#[test]
+fn given_a_text_the_automaton_should_contain_exactly_its_substrings() {
+    let text = chars("abcbc");
+    let sut = SuffixAutomaton::new(&text);
+
+    for start in 0..=text.len() {
+        for end in start..=text.len() {
+            assert!(sut.contains(&text[start..end]));
+        }
+    }
+
+    assert!(!sut.contains(&chars("ca")));
+    assert!(!sut.contains(&chars("abcbcb")));
+    assert!(!sut.contains(&chars("d")));
+    assert_eq!(5, sut.len());
+}
+
+#[test]
+fn given_a_text_the_automaton_should_have_fewer_than_twice_as_many_states() {
+    let sut = SuffixAutomaton::new(&chars("abbb"));
+
+    assert_eq!(7, sut.state_count());
+    assert!(SuffixAutomaton::new(&chars("abcbcabcabbcabcb")).state_count() < 32);
+}
+
+#[test]
+fn given_a_text_the_automaton_should_count_its_distinct_substrings() {
+    assert_eq!(3, SuffixAutomaton::new(&chars("aaa")).distinct_substrings());
+    assert_eq!(12, SuffixAutomaton::new(&chars("abcbc")).distinct_substrings());
+    assert_eq!(0, SuffixAutomaton::<char>::new(&[]).distinct_substrings());
+}
+
+#[test]
+fn given_a_pattern_the_automaton_should_count_its_occurrences() {
+    let sut = SuffixAutomaton::new(&chars("abababa"));
+
+    assert_eq!(3, sut.occurrences(&chars("aba")));
+    assert_eq!(4, sut.occurrences(&chars("a")));
+    assert_eq!(1, sut.occurrences(&chars("abababa")));
+    assert_eq!(0, sut.occurrences(&chars("bb")));
+    assert_eq!(8, sut.occurrences(&[]));
+}
+
+#[test]
+fn given_two_texts_the_automaton_should_find_their_longest_common_substring() {
+    let sut = SuffixAutomaton::new(&chars("xabcdey"));
+
+    assert_eq!(chars("bcde"), sut.longest_common_substring(&chars("zzbcdezabc")));
+    assert_eq!(chars("ab"), sut.longest_common_substring(&chars("abqcd")));
+    assert!(sut.longest_common_substring(&chars("qrs")).is_empty());
+}
+
+#[test]
+fn given_a_suffix_automaton_its_dfa_should_accept_exactly_the_substrings() {
+    let mut dfa = SuffixAutomaton::new(&[0, 1, 1]).to_dfa().expect(VALID_DFA);
+
+    for (word, substring) in [(vec![], true), (vec![1, 1], true), (vec![0, 1, 1], true), (vec![1, 0], false), (vec![1, 1, 1], false)] {
+        dfa.reset();
+        assert_eq!(substring, dfa.steps(&word).expect(STEPS_NO_ERRORS).is_final());
+    }
+
+    assert_err(ERR_INVALID_INPUT, &dfa.steps(&[2]));
+}
+
+#[test]
+fn given_an_empty_text_its_dfa_should_have_no_alphabet() {
+    assert_err(ERR_EMPTY_ALPHABET, &SuffixAutomaton::<char>::new(&[]).to_dfa());
+}
@@ -0,0 +1,176 @@
+#![allow(non_snake_case)]
+
+use crate::automata::{BooleanAlgebra, Ranges, SymbolicAutomaton};
+use crate::automata::mealy::ERR_UNDEFINED_STATE;
+use crate::automata::ERR_UNREFERENCED_STATE_Q;
+use crate::model::{F, Q, δ};
+use crate::tests::{assert_err, STEPS_NO_ERRORS, VALID_DELTA, VALID_FINAL_STATES, VALID_STATES};
+
+const VALID_SYMBOLIC: &str = "valid symbolic automaton";
+
+#[test]
+fn given_a_symbolic_automaton_it_should_accept_inputs_on_the_predicates_of_its_transitions() {
+    let sut = identifier();
+
+    assert!(sut.accepts(&['x']));
+    assert!(sut.accepts(&['_', 'ß', '9']));
+    assert!(!sut.accepts(&['9', 'x']));
+    assert!(!sut.accepts(&[]));
+    assert!(sut.is_deterministic());
+}
+
+#[test]
+fn given_a_state_of_δ_not_in_q_new_should_return_an_err() {
+    let Q = Q::new(vec!['A']).expect(VALID_STATES);
+    let F = F::new(vec!['A']).expect(VALID_FINAL_STATES);
+    let δ = δ::new(vec![
+        ('A', vec![(Ranges::<char>::top(), 'A')]),
+        ('B', vec![(Ranges::top(), 'A')]),
+    ]).expect(VALID_DELTA);
+
+    assert_err(ERR_UNDEFINED_STATE, &SymbolicAutomaton::new(Q, δ, 'A', &F));
+}
+
+#[test]
+fn given_a_state_of_q_without_transitions_new_should_return_an_err() {
+    let Q = Q::new(vec!['A', 'B']).expect(VALID_STATES);
+    let F = F::new(vec!['A']).expect(VALID_FINAL_STATES);
+    let δ = δ::new(vec![('A', vec![(Ranges::<char>::top(), 'A')])]).expect(VALID_DELTA);
+
+    assert_err(ERR_UNREFERENCED_STATE_Q, &SymbolicAutomaton::new(Q, δ, 'A', &F));
+}
+
+#[test]
+fn given_a_nondeterministic_symbolic_automaton_determinize_should_accept_the_same_inputs() {
+    let sut = ends_with_digit();
+    let dfa = sut.determinize();
+
+    assert!(!sut.is_deterministic());
+    assert!(dfa.is_deterministic());
+
+    for input in [&['a', '1'][..], &['1', 'a'], &['1'], &[], &['ä', '7', '7']] {
+        assert_eq!(sut.accepts(input), dfa.accepts(input));
+    }
+
+    assert_eq!(2, dfa.states().count());
+    assert_eq!("[>({'A'}), (({'A','B'}))]", format!("{:?}", dfa.states().collect::<Vec<_>>()));
+}
+
+#[test]
+fn given_a_deterministic_automaton_its_states_should_have_disjoint_transitions_covering_every_symbol() {
+    let sut = identifier().determinize();
+
+    for state in sut.states() {
+        let covered = sut.transitions(state).fold(Ranges::bottom(), |covered, (predicate, _)| {
+            assert!(!covered.and(predicate).is_satisfiable());
+
+            covered.or(predicate)
+        });
+
+        assert_eq!(Ranges::top(), covered);
+    }
+}
+
+#[test]
+fn given_a_symbolic_automaton_with_equivalent_states_minimize_should_merge_them() {
+    let Q = Q::new(vec!['A', 'B', 'C']).expect(VALID_STATES);
+    let F = F::new(vec!['B', 'C']).expect(VALID_FINAL_STATES);
+    let δ = δ::new(vec![
+        ('A', vec![(Ranges::new(['a'..='m']), 'B'), (Ranges::new(['n'..='z']), 'C')]),
+        ('B', vec![(Ranges::new(['a'..='z']), 'B')]),
+        ('C', vec![(Ranges::new(['a'..='z']), 'C')]),
+    ]).expect(VALID_DELTA);
+
+    let sut = SymbolicAutomaton::new(Q, δ, 'A', &F).expect(VALID_SYMBOLIC).minimize();
+
+    assert_eq!(3, sut.states().count());
+    assert!(sut.accepts(&['a', 'z']));
+    assert!(sut.accepts(&['q']));
+    assert!(!sut.accepts(&['a', '0']));
+
+    let initial = sut.initial();
+    let (predicate, _) = sut.transitions(initial)
+        .find(|(_, next)| next.is_final())
+        .expect("transition to a final state");
+
+    assert_eq!(&Ranges::new(['a'..='z']), predicate);
+}
+
+#[test]
+fn given_two_symbolic_automata_their_intersection_should_accept_inputs_accepted_by_both() {
+    let sut = identifier().intersection(&ends_with_digit());
+
+    assert!(sut.accepts(&['x', '1']));
+    assert!(!sut.accepts(&['x']));
+    assert!(!sut.accepts(&['1']));
+}
+
+#[test]
+fn given_two_symbolic_automata_their_union_should_accept_inputs_accepted_by_either() {
+    let sut = identifier().union(&ends_with_digit());
+
+    assert!(sut.accepts(&['x']));
+    assert!(sut.accepts(&['1']));
+    assert!(sut.accepts(&['-', '1']));
+    assert!(!sut.accepts(&['-']));
+    assert!(!sut.accepts(&[]));
+}
+
+#[test]
+fn given_a_symbolic_automaton_its_complement_should_accept_the_inputs_it_rejects() {
+    let sut = identifier();
+    let complement = sut.complement();
+
+    for input in [&['x'][..], &['9', 'x'], &[], &['_', '0']] {
+        assert_ne!(sut.accepts(input), complement.accepts(input));
+    }
+}
+
+#[test]
+fn given_a_symbolic_automaton_its_dfa_over_minterms_should_step_on_the_minterm_of_each_symbol() {
+    let sut = identifier();
+    let minterms = sut.minterms();
+    let mut dfa = sut.to_dfa();
+
+    assert_eq!(3, minterms.len());
+    assert_eq!(minterms.len(), dfa.alphabet().len());
+
+    for input in [&['x', '1'][..], &['1', 'x']] {
+        let classes = input.iter()
+            .map(|symbol| minterms.iter().find(|minterm| minterm.holds(symbol)).expect("minterm").clone())
+            .collect::<Vec<_>>();
+
+        dfa.reset();
+        dfa.steps(&classes).expect(STEPS_NO_ERRORS);
+
+        assert_eq!(sut.accepts(input), dfa.matches());
+    }
+}
+
+// identifiers starting with a letter or underscore followed by letters, digits or underscores
+fn identifier() -> SymbolicAutomaton<Ranges<char>, char> {
+    let start = Ranges::new(['a'..='z', 'A'..='Z', '_'..='_', 'ß'..='ß']);
+    let rest = start.or(&Ranges::new(['0'..='9']));
+
+    let Q = Q::new(vec!['S', 'I', 'E']).expect(VALID_STATES);
+    let F = F::new(vec!['I']).expect(VALID_FINAL_STATES);
+    let δ = δ::new(vec![
+        ('S', vec![(start.clone(), 'I'), (start.not(), 'E')]),
+        ('I', vec![(rest.clone(), 'I'), (rest.not(), 'E')]),
+        ('E', vec![(Ranges::top(), 'E')]),
+    ]).expect(VALID_DELTA);
+
+    SymbolicAutomaton::new(Q, δ, 'S', &F).expect(VALID_SYMBOLIC)
+}
+
+// any input ending with a digit, guessing the last digit
+fn ends_with_digit() -> SymbolicAutomaton<Ranges<char>, char> {
+    let Q = Q::new(vec!['A', 'B']).expect(VALID_STATES);
+    let F = F::new(vec!['B']).expect(VALID_FINAL_STATES);
+    let δ = δ::new(vec![
+        ('A', vec![(Ranges::top(), 'A'), (Ranges::new(['0'..='9']), 'B')]),
+        ('B', vec![]),
+    ]).expect(VALID_DELTA);
+
+    SymbolicAutomaton::new(Q, δ, 'A', &F).expect(VALID_SYMBOLIC)
+}
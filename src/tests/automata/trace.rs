@@ -0,0 +1,88 @@
+#![allow(non_snake_case)]
+
+use crate::automata::{DFA, ERR_INVALID_INPUT};
+use crate::model::{F, Q, δ, Σ};
+use crate::tests::{assert_err, VALID_DELTA, VALID_FINAL_STATES, VALID_SIGMA, VALID_STATES};
+use crate::tests::automata::determinize::ends_with_zero_one;
+use crate::tests::automata::VALID_DFA;
+
+const VALID_TRACE: &str = "valid trace";
+
+#[test]
+fn given_a_dfa_rejecting_an_input_its_trace_should_have_the_states_visited() {
+    let mut sut = two_ones();
+
+    let actual = sut.trace(&[0, 1, 0]).expect(VALID_TRACE);
+
+    assert!(!actual.accepted());
+    assert_eq!(3, actual.len());
+    assert_eq!((2, 0), (actual[2].position, actual[2].symbol));
+    assert_eq!(">(A) -0-> >(A) -1-> (C) -0-> >(A)", actual.to_string());
+    assert_eq!(vec!['A', 'A', 'C', 'A'], actual.states().map(|state| state[0]).collect::<Vec<_>>());
+}
+
+#[test]
+fn given_a_dfa_its_trace_should_leave_it_in_the_end_state() {
+    let mut sut = two_ones();
+
+    let actual = sut.trace(&[1, 1]).expect(VALID_TRACE);
+
+    assert!(actual.accepted());
+    assert_eq!(actual.end(), sut.current());
+    assert!(sut.matches());
+}
+
+#[test]
+fn given_a_dfa_and_an_invalid_input_its_trace_should_fail() {
+    let mut sut = two_ones();
+
+    let actual = sut.trace(&[1, 2]);
+
+    assert_err(ERR_INVALID_INPUT, &actual);
+}
+
+#[test]
+fn given_an_nfa_its_trace_should_have_the_active_states_of_each_step() {
+    let mut sut = ends_with_zero_one();
+
+    let actual = sut.trace(&[1, 0, 1]).expect(VALID_TRACE);
+
+    assert!(actual.accepted());
+    assert_eq!("{>(A)} -1-> {>(A)} -0-> {>(A) (B)} -1-> {>(A) ((C))}", actual.to_string());
+}
+
+#[test]
+fn given_an_nfa_accepting_an_input_its_trace_should_have_a_witness() {
+    let mut sut = ends_with_zero_one();
+
+    let actual = sut.trace(&[0, 0, 1]).expect(VALID_TRACE).witness();
+
+    let expected = Some(vec!['A', 'A', 'B', 'C']);
+
+    assert_eq!(expected, actual.map(|path| path.iter().map(|state| state[0]).collect::<Vec<_>>()));
+}
+
+#[test]
+fn given_an_nfa_rejecting_an_input_its_trace_should_not_have_a_witness() {
+    let mut sut = ends_with_zero_one();
+
+    let actual = sut.trace(&[0, 1, 1]).expect(VALID_TRACE);
+
+    assert!(!actual.accepted());
+    assert!(actual.witness().is_none());
+}
+
+// ends with two 1s
+fn two_ones() -> DFA<u8, char> {
+    let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B', 'C']).expect(VALID_STATES);
+    let F = F::new(vec!['B']).expect(VALID_FINAL_STATES);
+
+    let δ = δ::new(vec![
+        ('A', vec![(0, 'A'), (1, 'C')]),
+        ('C', vec![(0, 'A'), (1, 'B')]),
+        ('B', vec![(0, 'A'), (1, 'B')]),
+    ]).expect(VALID_DELTA);
+
+    DFA::new(Q, &Σ, δ, 'A', &F).expect(VALID_DFA)
+}
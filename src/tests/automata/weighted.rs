@@ -0,0 +1,139 @@
+#![allow(non_snake_case)]
+
+use crate::automata::{Boolean, Counting, ERR_INVALID_INPUT, ERR_UNDEFINED_FINAL_STATE, ERR_UNDEFINED_INITIAL_STATE, Probability, Tropical, WeightedAutomaton};
+use crate::automata::dfa::ERR_UNDEFINED_SYMBOL;
+use crate::automata::mealy::ERR_UNDEFINED_STATE;
+use crate::automata::weighted::{ERR_DIVERGENT_DISTANCE, ERR_DUPLICATE_FINAL_WEIGHT, ERR_DUPLICATE_TRANSITION};
+use crate::model::{Q, Σ};
+use crate::tests::{assert_err, STEPS_NO_ERRORS, VALID_SIGMA, VALID_STATES};
+use crate::tests::automata::determinize::ends_with_zero_one;
+
+const VALID_WEIGHTED: &str = "valid weighted automaton";
+
+#[test]
+fn given_a_tropical_automaton_the_weight_of_an_input_should_be_its_cheapest_path() {
+    let sut = costs();
+
+    assert_eq!(Ok(Tropical(4.0)), sut.weight_of(&['a']));
+    assert_eq!(Ok(Tropical(3.0)), sut.weight_of(&['a', 'b']));
+    assert_eq!(Ok(Tropical(11.0)), sut.weight_of(&['b', 'b']));
+    assert_eq!(Ok(Tropical(f64::INFINITY)), sut.weight_of(&['b', 'a']));
+}
+
+#[test]
+fn given_a_tropical_automaton_its_shortest_distances_should_be_the_cheapest_costs_to_each_state() {
+    assert_eq!(Ok(vec![Tropical(0.0), Tropical(1.0), Tropical(3.0)]), costs().shortest_distance());
+}
+
+#[test]
+fn given_a_counting_automaton_the_weight_of_an_input_should_be_its_number_of_paths() {
+    let sut = diamond(vec![]);
+
+    assert_eq!(Ok(Counting(2)), sut.weight_of(&['a', 'a']));
+    assert_eq!(Ok(Counting(0)), sut.weight_of(&['a']));
+    assert_eq!(Ok(vec![Counting(1), Counting(1), Counting(1), Counting(2)]), sut.shortest_distance());
+}
+
+#[test]
+fn given_a_reachable_cycle_a_counting_automaton_should_have_divergent_distances() {
+    assert_err(ERR_DIVERGENT_DISTANCE, &diamond(vec![('D', 'a', 'A', Counting(1))]).shortest_distance());
+}
+
+#[test]
+fn given_a_probability_automaton_its_shortest_distances_should_sum_the_probabilities_of_all_paths() {
+    let Σ = Σ::new(vec!['a', 'b']).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B']).expect(VALID_STATES);
+
+    let sut = WeightedAutomaton::new(
+        Q,
+        Σ,
+        vec![('A', 'a', 'A', Probability(0.5)), ('A', 'b', 'B', Probability(0.5))],
+        'A',
+        vec![('B', Probability(1.0))],
+    ).expect(VALID_WEIGHTED);
+
+    assert_eq!(Ok(Probability(0.25)), sut.weight_of(&['a', 'b']));
+    assert_eq!(Ok(vec![Probability(2.0), Probability(1.0)]), sut.shortest_distance());
+}
+
+#[test]
+fn given_an_nfa_its_boolean_automaton_should_weigh_the_inputs_it_accepts_true() {
+    let mut nfa = ends_with_zero_one();
+    let sut = nfa.to_weighted();
+
+    for inputs in [vec![0, 1], vec![1, 0], vec![1, 1, 0, 1], vec![0], vec![]] {
+        nfa.reset();
+        nfa.steps(&inputs).expect(STEPS_NO_ERRORS);
+
+        assert_eq!(Ok(Boolean(nfa.matches())), sut.weight_of(&inputs), "{inputs:?}");
+    }
+
+    assert_eq!(Some(&Boolean(true)), sut.final_weight(sut.states().last().expect("final state")));
+}
+
+#[test]
+fn given_a_weighted_automaton_its_transitions_should_carry_their_weights() {
+    let sut = costs();
+
+    let actual = sut.transitions(sut.initial(), &'a').map(|(state, weight)| (format!("{state}"), *weight)).collect::<Vec<_>>();
+
+    assert_eq!(vec![("((B))".to_string(), Tropical(1.0)), ("((C))".to_string(), Tropical(4.0))], actual);
+}
+
+#[test]
+fn given_an_undefined_input_symbol_weight_of_should_return_an_err() {
+    assert_err(ERR_INVALID_INPUT, &costs().weight_of(&['a', 'c']));
+}
+
+#[test]
+fn given_invalid_weighted_automata_we_should_get_an_err() {
+    for (δ, q0, ρ, expected) in [
+        (vec![('A', 'a', 'X', Tropical(1.0))], 'A', vec![], ERR_UNDEFINED_STATE),
+        (vec![('A', 'c', 'B', Tropical(1.0))], 'A', vec![], ERR_UNDEFINED_SYMBOL),
+        (vec![('A', 'a', 'B', Tropical(1.0)), ('A', 'a', 'B', Tropical(2.0))], 'A', vec![], ERR_DUPLICATE_TRANSITION),
+        (vec![], 'X', vec![], ERR_UNDEFINED_INITIAL_STATE),
+        (vec![], 'A', vec![('X', Tropical(0.0))], ERR_UNDEFINED_FINAL_STATE),
+        (vec![], 'A', vec![('B', Tropical(0.0)), ('B', Tropical(1.0))], ERR_DUPLICATE_FINAL_WEIGHT),
+    ] {
+        let Σ = Σ::new(vec!['a', 'b']).expect(VALID_SIGMA);
+        let Q = Q::new(vec!['A', 'B']).expect(VALID_STATES);
+
+        assert_err(expected, &WeightedAutomaton::new(Q, Σ, δ, q0, ρ));
+    }
+}
+
+// costs of reaching B and C, leaving at B costing 3
+fn costs() -> WeightedAutomaton<char, char, Tropical> {
+    let Σ = Σ::new(vec!['a', 'b']).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B', 'C']).expect(VALID_STATES);
+
+    WeightedAutomaton::new(
+        Q,
+        Σ,
+        vec![
+            ('A', 'a', 'B', Tropical(1.0)),
+            ('A', 'a', 'C', Tropical(4.0)),
+            ('A', 'b', 'C', Tropical(10.0)),
+            ('B', 'b', 'C', Tropical(2.0)),
+            ('C', 'b', 'C', Tropical(1.0)),
+        ],
+        'A',
+        vec![('B', Tropical(3.0)), ('C', Tropical(0.0))],
+    ).expect(VALID_WEIGHTED)
+}
+
+// two paths from A to D, through B and C
+fn diamond(extra: Vec<(char, char, char, Counting)>) -> WeightedAutomaton<char, char, Counting> {
+    let Σ = Σ::new(vec!['a']).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B', 'C', 'D']).expect(VALID_STATES);
+    let mut δ = vec![
+        ('A', 'a', 'B', Counting(1)),
+        ('A', 'a', 'C', Counting(1)),
+        ('B', 'a', 'D', Counting(1)),
+        ('C', 'a', 'D', Counting(1)),
+    ];
+
+    δ.extend(extra);
+
+    WeightedAutomaton::new(Q, Σ, δ, 'A', vec![('D', Counting(1))]).expect(VALID_WEIGHTED)
+}
@@ -0,0 +1,76 @@
+#![allow(non_snake_case)]
+
+use crate::automata::{Dawg, Levenshtein};
+use crate::model::Σ;
+use crate::tests::VALID_SIGMA;
+use crate::tests::automata::determinize::ends_with_zero_one;
+use crate::tests::automata::intervals::lowercase;
+use crate::tests::automata::{VALID_DFA, words};
+
+const VALID_DAWG: &str = "valid dawg";
+
+#[test]
+fn given_a_finite_language_the_words_should_be_yielded_in_shortlex_order_and_end() {
+    let sut = Dawg::new(&words(&["", "ab", "abc", "b", "ba", "c"])).expect(VALID_DAWG).to_dfa().expect(VALID_DFA);
+
+    assert_eq!(words(&["", "b", "c", "ab", "ba", "abc"]), sut.words().collect::<Vec<_>>());
+}
+
+#[test]
+fn given_an_alphabet_the_words_should_follow_its_declared_order() {
+    let Σ = Σ::new(vec!['b', 'a']).expect(VALID_SIGMA);
+    let sut = Levenshtein::new(&['a'], 1).to_dfa(&Σ);
+
+    assert_eq!(words(&["", "b", "a", "ba", "ab", "aa"]), sut.words().collect::<Vec<_>>());
+}
+
+#[test]
+fn given_an_empty_language_the_words_should_end_at_once() {
+    let Σ = Σ::new(vec!['c']).expect(VALID_SIGMA);
+    let sut = Levenshtein::new(&['a', 'b'], 0).to_dfa(&Σ);
+
+    assert_eq!(None, sut.words().next());
+    assert_eq!(None, sut.words_up_to(10).next());
+}
+
+#[test]
+fn given_an_infinite_language_the_words_should_keep_coming_in_shortlex_order() {
+    let sut = ends_with_zero_one().determinize();
+
+    let expected = vec![vec![0, 1], vec![0, 0, 1], vec![1, 0, 1], vec![0, 0, 0, 1], vec![0, 1, 0, 1]];
+
+    assert_eq!(expected, sut.words().take(5).collect::<Vec<_>>());
+    assert_eq!(Some(vec![0, 0, 0, 0, 1]), sut.words().nth(7));
+}
+
+#[test]
+fn given_a_bound_the_words_should_stop_at_that_length() {
+    let sut = ends_with_zero_one().determinize();
+
+    assert_eq!(1 + 2 + 4, sut.words_up_to(4).count());
+    assert!(sut.words_up_to(4).all(|word| word.len() <= 4));
+    assert_eq!(0, sut.words_up_to(1).count());
+}
+
+#[test]
+fn given_a_dfa_over_intervals_its_words_should_have_every_symbol_of_a_class() {
+    let sut = lowercase(vec!['a'..='m', 'n'..='z']);
+
+    let mut expected = vec![Vec::new()];
+
+    expected.extend(('a'..='z').map(|sym| vec![sym]));
+
+    assert_eq!(expected, sut.words_up_to(1).collect::<Vec<_>>());
+    assert_eq!(1 + 26 + 26 * 26, sut.words_up_to(2).count());
+}
+
+#[test]
+fn given_an_nfa_its_words_should_be_yielded_once_each() {
+    let sut = ends_with_zero_one();
+
+    assert_eq!(
+        ends_with_zero_one().determinize().words_up_to(5).collect::<Vec<_>>(),
+        sut.words_up_to(5).collect::<Vec<_>>(),
+    );
+    assert_eq!(vec![vec![0, 1], vec![0, 0, 1]], sut.words().take(2).collect::<Vec<_>>());
+}
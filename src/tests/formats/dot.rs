@@ -0,0 +1,21 @@
+use crate::formats::Definition;
+use crate::tests::automata::determinize::ends_with_zero_one;
+
+#[test]
+fn given_a_definition_we_should_get_its_dot_representation() {
+    let sut = Definition::from_nfa(&ends_with_zero_one());
+
+    let expected = "digraph NFA {
+    rankdir = LR;
+    node [shape = circle];
+    \"\" [shape = none, label = \"\"];
+    \"C\" [shape = doublecircle];
+    \"\" -> \"A\";
+    \"A\" -> \"A\" [label = \"0, 1\"];
+    \"A\" -> \"B\" [label = \"0\"];
+    \"B\" -> \"C\" [label = \"1\"];
+}
+";
+
+    assert_eq!(expected, sut.to_dot());
+}
@@ -0,0 +1,117 @@
+use crate::formats::{Definition, Kind, ParseError};
+use crate::formats::hoa::{
+    ERR_EXPECTED_HOA,
+    ERR_NOT_BUCHI,
+    ERR_TOO_MANY_PROPOSITIONS,
+    ERR_TOO_MANY_STATES,
+    ERR_TRANSITION_ACCEPTANCE,
+    ERR_UNDEFINED_STATE,
+};
+use crate::tests::automata::buchi::infinitely_many_a;
+
+const VALID_DEFINITION: &str = "valid definition";
+
+// infinitely often a, with state labels omitted
+const INFINITELY_OFTEN: &str = r#"HOA: v1
+name: "GF a"
+States: 2
+Start: 0
+AP: 1 "a"
+acc-name: Buchi
+Acceptance: 1 Inf(0)
+properties: trans-labels explicit-labels state-acc
+--BODY--
+State: 0
+[!0] 0
+[0] 1
+State: 1 {0}  /* accepting */
+[!0] 0
+[0] 1
+--END--
+"#;
+
+#[test]
+fn given_a_hoa_büchi_automaton_we_should_get_a_buchi_definition() {
+    let definition = Definition::from_hoa(INFINITELY_OFTEN).expect(VALID_DEFINITION);
+
+    assert_eq!(Kind::Buchi, definition.kind);
+    assert_eq!(vec!["{}", "a"], definition.alphabet);
+    assert_eq!(vec!["0", "1"], definition.states);
+    assert_eq!(vec!["1"], definition.finals);
+
+    let sut = definition.to_buchi().expect("valid büchi automaton");
+
+    assert_eq!(Ok(true), sut.accepts(&["{}".to_string()], &["{}", "a"].map(String::from)));
+    assert_eq!(Ok(false), sut.accepts(&["a".to_string()], &["{}".to_string()]));
+}
+
+#[test]
+fn given_a_hoa_automaton_with_state_labels_they_should_apply_to_each_edge() {
+    let source = "HOA: v1 States: 1 Start: 0 AP: 2 \"a\" \"b\" Acceptance: 0 t \
+        --BODY-- State: [0 | 1] 0 0 --END--";
+
+    let definition = Definition::from_hoa(source).expect(VALID_DEFINITION);
+
+    assert_eq!(vec!["a", "b", "a&b"], definition.alphabet);
+    assert_eq!(vec!["0"], definition.finals);
+}
+
+#[test]
+fn given_a_definition_its_hoa_should_round_trip() {
+    let sut = Definition::from_buchi(&infinitely_many_a());
+
+    let actual = Definition::from_hoa(&sut.to_hoa().expect("valid hoa")).expect(VALID_DEFINITION);
+
+    assert_eq!(sut, actual);
+}
+
+#[test]
+fn given_a_definition_with_more_symbols_than_propositions_to_hoa_should_return_an_err() {
+    let mut sut = Definition::from_buchi(&infinitely_many_a());
+
+    sut.alphabet = ('a'..='m').map(String::from).collect();
+
+    assert_eq!(Err(ERR_TOO_MANY_PROPOSITIONS), sut.to_hoa());
+}
+
+#[test]
+fn given_a_hoa_büchi_automaton_without_accepting_states_it_should_be_empty() {
+    let source = "HOA: v1 States: 1 Start: 0 AP: 1 \"a\" Acceptance: 1 Inf(0) --BODY-- State: 0 [t] 0 --END--";
+
+    let definition = Definition::from_hoa(source).expect(VALID_DEFINITION);
+
+    assert!(definition.finals.is_empty());
+    assert!(definition.to_buchi().expect("valid büchi automaton").is_empty());
+}
+
+#[test]
+fn given_invalid_hoa_automata_we_should_get_the_position_of_the_error() {
+    let header = "HOA: v1\nStates: 1\nStart: 0\nAP: 0\n";
+
+    for (source, line, column, message) in [
+        ("HOA: v2\n".to_string(), 1, 1, ERR_EXPECTED_HOA),
+        (format!("{header}Acceptance: 2 Inf(0)&Inf(1)\n--BODY--\n--END--"), 5, 1, ERR_NOT_BUCHI),
+        (format!("{header}Acceptance: 1 Inf(0)\n--BODY--\nState: 0\n[t] 0 {{0}}\n--END--"), 8, 1, ERR_TRANSITION_ACCEPTANCE),
+        (format!("{header}Acceptance: 1 Inf(0)\n--BODY--\nState: 0\n[t] 3\n--END--"), 8, 5, ERR_UNDEFINED_STATE),
+    ] {
+        let actual = Definition::from_hoa(&source);
+
+        assert_eq!(Err(ParseError { line, column, message }), actual);
+    }
+}
+
+#[test]
+fn given_hoa_state_numbers_beyond_the_states_we_should_get_an_err() {
+    let acceptance = "AP: 0\nAcceptance: 0 t\n--BODY--\n";
+
+    for (source, line, column, message) in [
+        (format!("HOA: v1\nStates: 2\nStart: 0\n{acceptance}State: 99999999999\n--END--"), 7, 1, ERR_UNDEFINED_STATE),
+        (format!("HOA: v1\nStates: 99999999999\nStart: 0\n{acceptance}State: 0\n--END--"), 2, 1, ERR_TOO_MANY_STATES),
+        (format!("HOA: v1\nStart: 0\n{acceptance}State: 99999999999\n--END--"), 6, 1, ERR_TOO_MANY_STATES),
+        (format!("HOA: v1\nStart: 99999999999\n{acceptance}State: 0\n--END--"), 2, 1, ERR_TOO_MANY_STATES),
+    ] {
+        let actual = Definition::from_hoa(&source);
+
+        assert_eq!(Err(ParseError { line, column, message }), actual);
+    }
+}
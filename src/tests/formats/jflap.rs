@@ -0,0 +1,137 @@
+use crate::formats::{Definition, Kind, ParseError};
+use crate::formats::jflap::{ERR_NOT_FINITE_AUTOMATON, ERR_UNDEFINED_STATE};
+use crate::tests::automata::determinize::ends_with_zero_one;
+use crate::tests::STEPS_NO_ERRORS;
+
+const VALID_DEFINITION: &str = "valid definition";
+
+// JFLAP 6 layout, without an automaton element
+const ODD_ONES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?><!--Created with JFLAP 6.4.--><structure>&#13;
+	<type>fa</type>&#13;
+	<!--The list of states.-->&#13;
+	<state id="0" name="even">&#13;
+		<x>70.0</x>&#13;
+		<y>90.0</y>&#13;
+		<initial/>&#13;
+	</state>&#13;
+	<state id="1" name="odd">&#13;
+		<x>200.0</x>&#13;
+		<y>90.0</y>&#13;
+		<final/>&#13;
+	</state>&#13;
+	<transition><from>0</from><to>0</to><read>0</read></transition>
+	<transition><from>0</from><to>1</to><read>1</read></transition>
+	<transition><from>1</from><to>1</to><read>0</read></transition>
+	<transition><from>1</from><to>0</to><read>1</read></transition>
+</structure>"#;
+
+#[test]
+fn given_a_deterministic_jflap_automaton_we_should_get_a_dfa() {
+    let definition = Definition::from_jflap(ODD_ONES).expect(VALID_DEFINITION);
+
+    assert_eq!(Kind::Dfa, definition.kind);
+    assert_eq!(vec!["even", "odd"], definition.states);
+
+    let mut sut = definition.to_dfa().expect("valid dfa");
+
+    sut.steps(&["1", "0", "1", "1"].map(String::from)).expect(STEPS_NO_ERRORS);
+
+    assert!(sut.matches());
+}
+
+#[test]
+fn given_a_jflap_automaton_with_empty_transitions_they_should_be_eliminated() {
+    let source = r#"<structure><type>fa</type><automaton>
+        <state id="0" name="q0"><initial/></state>
+        <state id="1" name="q1"></state>
+        <state id="2" name="q2"><final/></state>
+        <transition><from>0</from><to>0</to><read>a</read></transition>
+        <transition><from>0</from><to>1</to><read/></transition>
+        <transition><from>1</from><to>1</to><read>b</read></transition>
+        <transition><from>1</from><to>2</to><read></read></transition>
+    </automaton></structure>"#;
+
+    let definition = Definition::from_jflap(source).expect(VALID_DEFINITION);
+
+    assert_eq!(Kind::Nfa, definition.kind);
+    assert_eq!(vec!["q0", "q1"], definition.states);
+
+    let mut sut = definition.to_nfa().expect("valid nfa");
+
+    for (inputs, expected) in [("", true), ("aab", true), ("abb", true), ("ba", false)] {
+        sut.reset();
+        sut.steps(&inputs.chars().map(String::from).collect::<Vec<_>>()).expect(STEPS_NO_ERRORS);
+
+        assert_eq!(expected, sut.matches(), "{inputs}");
+    }
+}
+
+#[test]
+fn given_an_unreachable_state_leading_to_a_dropped_state_both_should_be_dropped() {
+    let source = r#"<structure><type>fa</type><automaton>
+        <state id="0" name="q0"><initial/></state>
+        <state id="1" name="q1"><final/></state>
+        <state id="2" name="q2"></state>
+        <transition><from>0</from><to>1</to><read/></transition>
+        <transition><from>0</from><to>0</to><read>a</read></transition>
+        <transition><from>2</from><to>1</to><read>a</read></transition>
+        <transition><from>2</from><to>2</to><read>a</read></transition>
+    </automaton></structure>"#;
+
+    let definition = Definition::from_jflap(source).expect(VALID_DEFINITION);
+
+    let transitions = [("q0", "a", "q0")].map(|(from, sym, to)| (from.to_string(), sym.to_string(), to.to_string()));
+
+    assert_eq!(vec!["q0"], definition.states);
+    assert_eq!(transitions.to_vec(), definition.transitions);
+}
+
+#[test]
+fn given_a_jflap_automaton_with_unreachable_states_they_should_be_dropped() {
+    let source = r#"<structure><type>fa</type><automaton>
+        <state id="0" name="even"><initial/></state>
+        <state id="1" name="odd"><final/></state>
+        <state id="2" name="unused"><final/></state>
+        <transition><from>0</from><to>0</to><read>0</read></transition>
+        <transition><from>0</from><to>1</to><read>1</read></transition>
+        <transition><from>1</from><to>1</to><read>0</read></transition>
+        <transition><from>1</from><to>0</to><read>1</read></transition>
+        <transition><from>2</from><to>1</to><read>0</read></transition>
+    </automaton></structure>"#;
+
+    let definition = Definition::from_jflap(source).expect(VALID_DEFINITION);
+
+    assert_eq!(Kind::Dfa, definition.kind);
+    assert_eq!(vec!["even", "odd"], definition.states);
+    assert_eq!(vec!["odd"], definition.finals);
+
+    let mut sut = definition.to_dfa().expect("valid dfa");
+
+    sut.steps(&["1", "0"].map(String::from)).expect(STEPS_NO_ERRORS);
+
+    assert!(sut.matches());
+}
+
+#[test]
+fn given_a_definition_its_jflap_should_round_trip() {
+    let sut = Definition::from_nfa(&ends_with_zero_one());
+
+    let actual = Definition::from_jflap(&sut.to_jflap()).expect(VALID_DEFINITION);
+
+    assert_eq!(sut, actual);
+}
+
+#[test]
+fn given_invalid_jflap_automata_we_should_get_the_position_of_the_error() {
+    let undefined = "<structure>\n  <type>fa</type>\n  <state id=\"0\"><initial/></state>\n  \
+        <transition><from>0</from><to>7</to><read>a</read></transition>\n</structure>";
+
+    for (source, line, column, message) in [
+        ("<structure><type>turing</type></structure>", 1, 1, ERR_NOT_FINITE_AUTOMATON),
+        (undefined, 4, 29, ERR_UNDEFINED_STATE),
+    ] {
+        let actual = Definition::from_jflap(source);
+
+        assert_eq!(Err(ParseError { line, column, message }), actual);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::formats::{Definition, Kind, ParseError};
+use crate::formats::json::{ERR_EXPECTED_TRANSITION, ERR_MISSING_FIELD, ERR_UNEXPECTED_CHARACTER};
+use crate::tests::automata::determinize::ends_with_zero_one;
+
+const VALID_DEFINITION: &str = "valid definition";
+
+#[test]
+fn given_a_json_definition_we_should_get_a_definition() {
+    let source = r#"{
+        "kind": "dfa",
+        "alphabet": [0, "1"],
+        "states": ["A", "B"],
+        "initial": "A",
+        "final": ["B"],
+        "transitions": [["A", "0", "B"], ["A", "1", "A"], ["B", "0", "B"], ["B", "1", "A"]]
+    }"#;
+
+    let actual = Definition::from_json(source).expect(VALID_DEFINITION);
+
+    assert_eq!(Kind::Dfa, actual.kind);
+    assert_eq!(vec!["0", "1"], actual.alphabet);
+    assert_eq!(("B".to_string(), "1".to_string(), "A".to_string()), actual.transitions[3]);
+
+    actual.to_dfa().expect("valid dfa");
+}
+
+#[test]
+fn given_a_definition_its_json_should_round_trip() {
+    let sut = Definition::from_nfa(&ends_with_zero_one());
+
+    let actual = Definition::from_json(&sut.to_json()).expect(VALID_DEFINITION);
+
+    assert_eq!(sut, actual);
+}
+
+#[test]
+fn given_malformed_json_we_should_get_the_position_of_the_error() {
+    let actual = Definition::from_json("{\n  \"kind\": \"dfa\",\n  \"states\" [\"A\"]\n}");
+
+    assert_eq!(Err(ParseError { line: 3, column: 12, message: ERR_UNEXPECTED_CHARACTER }), actual);
+}
+
+#[test]
+fn given_a_json_definition_with_missing_fields_we_should_get_an_err() {
+    let actual = Definition::from_json(r#"{"kind": "nfa"}"#);
+
+    assert_eq!(Err(ParseError { line: 1, column: 1, message: ERR_MISSING_FIELD }), actual);
+}
+
+#[test]
+fn given_a_json_definition_with_an_invalid_transition_we_should_get_an_err() {
+    let source = r#"{"kind": "nfa", "alphabet": [0], "states": ["A"], "initial": "A", "final": ["A"],
+        "transitions": [["A", "0"]]}"#;
+
+    let actual = Definition::from_json(source);
+
+    assert_eq!(Err(ParseError { line: 2, column: 25, message: ERR_EXPECTED_TRANSITION }), actual);
+}
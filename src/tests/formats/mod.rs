@@ -0,0 +1,5 @@
+mod dot;
+mod hoa;
+mod jflap;
+mod json;
+mod text;
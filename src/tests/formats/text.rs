@@ -0,0 +1,82 @@
+use crate::formats::{Definition, Kind, ParseError};
+use crate::formats::text::{
+    ERR_EXPECTED_KIND, ERR_EXPECTED_STATE, ERR_EXPECTED_TRANSITION,
+    ERR_MISSING_INITIAL_STATE, ERR_UNDECLARED_STATE, ERR_UNDECLARED_SYMBOL,
+};
+use crate::tests::automata::determinize::ends_with_zero_one;
+use crate::tests::automata::intervals::lowercase;
+use crate::tests::STEPS_NO_ERRORS;
+
+const VALID_DEFINITION: &str = "valid definition";
+
+#[test]
+fn given_a_text_definition_we_should_get_a_dfa() {
+    let source = "
+        # odd number of 1s
+        dfa
+        Σ: 0 1
+        >(A) ((B))
+        A -0-> A
+        A -1-> B
+        B -0-> B
+        B -1-> A
+    ";
+
+    let definition = Definition::from_text(source).expect(VALID_DEFINITION);
+    let mut sut = definition.to_dfa().expect("valid dfa");
+
+    assert_eq!(Kind::Dfa, definition.kind);
+
+    sut.steps(&["1".to_string(), "0".to_string(), "1".to_string(), "1".to_string()]).expect(STEPS_NO_ERRORS);
+
+    assert!(sut.matches());
+}
+
+#[test]
+fn given_a_definition_its_text_should_round_trip() {
+    let sut = Definition::from_dfa(&ends_with_zero_one().determinize());
+
+    let actual = sut.to_text().parse::<Definition>().expect(VALID_DEFINITION);
+
+    assert_eq!(sut, actual);
+}
+
+#[test]
+fn given_a_dfa_over_intervals_its_text_should_round_trip_every_symbol_of_its_classes() {
+    let sut = Definition::from_dfa(&lowercase(vec!['a'..='m', 'n'..='z']));
+
+    let actual = sut.to_text().parse::<Definition>().expect(VALID_DEFINITION);
+    let mut dfa = actual.to_dfa().expect("valid dfa");
+
+    assert_eq!(sut, actual);
+    assert_eq!(26, actual.alphabet.len());
+
+    dfa.steps(&["q", "u", "e", "r", "y"].map(String::from)).expect(STEPS_NO_ERRORS);
+
+    assert!(dfa.matches());
+}
+
+#[test]
+fn given_a_definition_its_text_should_use_state_notation() {
+    let sut = Definition::from_nfa(&ends_with_zero_one());
+
+    let expected = "nfa\nΣ: 0 1\n>(A) (B) ((C))\nA -0-> A\nA -0-> B\nA -1-> A\nB -1-> C\n";
+
+    assert_eq!(expected, sut.to_text());
+}
+
+#[test]
+fn given_malformed_text_definitions_we_should_get_the_position_of_the_error() {
+    for (source, line, column, message) in [
+        ("Σ: 0 1", 1, 1, ERR_EXPECTED_KIND),
+        ("nfa\nΣ: 0 1\n>(A) ((B)\n", 3, 6, ERR_EXPECTED_STATE),
+        ("nfa\nΣ: 0 1\n>(A) ((B))\n\nA -0- B", 5, 3, ERR_EXPECTED_TRANSITION),
+        ("nfa\nΣ: 0 1\n>(A) ((B))\nA -0-> C", 4, 8, ERR_UNDECLARED_STATE),
+        ("nfa\nΣ: 0 1\n>(A) ((B))\n  A -2-> B", 4, 6, ERR_UNDECLARED_SYMBOL),
+        ("nfa\nΣ: 0 1\n(A) ((B))\n", 4, 1, ERR_MISSING_INITIAL_STATE),
+    ] {
+        let actual = Definition::from_text(source);
+
+        assert_eq!(Err(ParseError { line, column, message }), actual, "{source}");
+    }
+}
@@ -0,0 +1,67 @@
+use std::hash::Hash;
+
+use crate::automata::DFA;
+use crate::learning::{EquivalenceOracle, LStar, WMethodOracle};
+use crate::learning::lstar::ERR_INVALID_COUNTEREXAMPLE;
+use crate::tests::assert_err;
+use crate::tests::automata::determinize::ends_with_zero_one;
+use crate::tests::learning::{contains_ab, membership, ones_divisible_by_three, VALID_LEARNING};
+
+// equivalence oracle answering with the same word every time
+struct Fixed(Vec<char>);
+
+impl EquivalenceOracle<char> for Fixed {
+    fn counterexample<S: Eq + Hash>(&mut self, _: &DFA<char, S>) -> Option<Vec<char>> {
+        Some(self.0.clone())
+    }
+}
+
+#[test]
+fn given_an_exact_equivalence_oracle_lstar_should_learn_the_minimal_dfa() {
+    let target = ends_with_zero_one().determinize();
+    let mut sut = LStar::new(target.alphabet(), membership(ends_with_zero_one().determinize()));
+
+    let actual = sut.learn(&mut ends_with_zero_one().determinize()).expect(VALID_LEARNING);
+
+    assert!(actual.equivalent(&target));
+    assert_eq!(target.minimize().states().count(), actual.states().count());
+    assert_eq!(&vec![0], &**actual.initial());
+}
+
+#[test]
+fn given_a_redundant_machine_lstar_should_learn_it_without_the_redundant_state() {
+    let target = ones_divisible_by_three();
+    let mut sut = LStar::new(target.alphabet(), membership(ones_divisible_by_three()));
+
+    let actual = sut.learn(&mut WMethodOracle::new(membership(ones_divisible_by_three()), 1)).expect(VALID_LEARNING);
+
+    assert!(actual.equivalent(&target));
+    assert_eq!(3, actual.states().count());
+}
+
+#[test]
+fn given_a_learned_language_learning_again_should_ask_no_new_membership_queries() {
+    let mut sut = LStar::new(contains_ab().alphabet(), membership(contains_ab()));
+
+    sut.learn(&mut contains_ab()).expect(VALID_LEARNING);
+
+    let queries = sut.queries();
+    let actual = sut.learn(&mut contains_ab()).expect(VALID_LEARNING);
+
+    assert!(actual.equivalent(&contains_ab()));
+    assert_eq!(queries, sut.queries());
+}
+
+#[test]
+fn given_a_word_the_hypothesis_classifies_correctly_learn_should_fail() {
+    let mut sut = LStar::new(contains_ab().alphabet(), membership(contains_ab()));
+
+    assert_err(ERR_INVALID_COUNTEREXAMPLE, &sut.learn(&mut Fixed(vec!['b', 'a'])));
+}
+
+#[test]
+fn given_a_counterexample_outside_the_alphabet_learn_should_fail() {
+    let mut sut = LStar::new(contains_ab().alphabet(), membership(contains_ab()));
+
+    assert_err(ERR_INVALID_COUNTEREXAMPLE, &sut.learn(&mut Fixed(vec!['a', 'c'])));
+}
@@ -0,0 +1,53 @@
+#![allow(non_snake_case)]
+
+use std::hash::Hash;
+
+use crate::automata::DFA;
+use crate::model::{F, Q, δ, Σ};
+use crate::tests::{VALID_DELTA, VALID_FINAL_STATES, VALID_SIGMA, VALID_STATES};
+
+mod lstar;
+mod oracle;
+mod rpni;
+
+const VALID_DFA: &str = "valid dfa";
+const VALID_LEARNING: &str = "valid learning";
+
+// black box membership oracle, running the automaton on each word
+fn membership<A: Clone + Eq + Hash, S: Eq + Hash>(mut dfa: DFA<A, S>) -> impl FnMut(&[A]) -> bool {
+    move |word| {
+        dfa.reset();
+        dfa.steps(word).is_ok() && dfa.matches()
+    }
+}
+
+// words over {a, b} containing "ab"
+fn contains_ab() -> DFA<char, char> {
+    let Σ = Σ::new(vec!['a', 'b']).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B', 'C']).expect(VALID_STATES);
+    let F = F::new(vec!['C']).expect(VALID_FINAL_STATES);
+
+    let δ = δ::new(vec![
+        ('A', vec![('a', 'B'), ('b', 'A')]),
+        ('B', vec![('a', 'B'), ('b', 'C')]),
+        ('C', vec![('a', 'C'), ('b', 'C')]),
+    ]).expect(VALID_DELTA);
+
+    DFA::new(Q, &Σ, δ, 'A', &F).expect(VALID_DFA)
+}
+
+// words over {0, 1} whose number of 1s is divisible by 3, with a redundant state
+fn ones_divisible_by_three() -> DFA<u8, char> {
+    let Σ = Σ::new(vec![0, 1]).expect(VALID_SIGMA);
+    let Q = Q::new(vec!['A', 'B', 'C', 'D']).expect(VALID_STATES);
+    let F = F::new(vec!['A', 'D']).expect(VALID_FINAL_STATES);
+
+    let δ = δ::new(vec![
+        ('A', vec![(0, 'D'), (1, 'B')]),
+        ('B', vec![(0, 'B'), (1, 'C')]),
+        ('C', vec![(0, 'C'), (1, 'A')]),
+        ('D', vec![(0, 'A'), (1, 'B')]),
+    ]).expect(VALID_DELTA);
+
+    DFA::new(Q, &Σ, δ, 'A', &F).expect(VALID_DFA)
+}
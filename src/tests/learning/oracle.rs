@@ -0,0 +1,77 @@
+use crate::learning::{EquivalenceOracle, LStar, RandomWordOracle, WMethodOracle};
+use crate::random::{Random, Rng};
+use crate::tests::STEPS_NO_ERRORS;
+use crate::tests::learning::{contains_ab, membership, ones_divisible_by_three, VALID_LEARNING};
+
+const COUNTEREXAMPLE: &str = "expect a counterexample";
+
+#[test]
+fn given_random_tests_lstar_should_learn_the_language() {
+    let mut sut = LStar::new(contains_ab().alphabet(), membership(contains_ab()));
+    let mut oracle = RandomWordOracle::new(membership(contains_ab()), Random::new(42), 200, 8);
+
+    let actual = sut.learn(&mut oracle).expect(VALID_LEARNING);
+
+    assert!(actual.equivalent(&contains_ab()));
+    assert_eq!(3, actual.states().count());
+}
+
+#[test]
+fn given_another_generator_random_tests_should_draw_from_it() {
+    let mut sut = LStar::new(contains_ab().alphabet(), membership(contains_ab()));
+    let mut oracle = RandomWordOracle::new(membership(contains_ab()), Weyl::from_seed(3), 200, 8);
+
+    let actual = sut.learn(&mut oracle).expect(VALID_LEARNING);
+
+    assert!(actual.equivalent(&contains_ab()));
+}
+
+#[test]
+fn given_an_equivalent_hypothesis_oracles_should_find_no_counterexample() {
+    let hypothesis = ones_divisible_by_three().minimize();
+
+    let mut w_method = WMethodOracle::new(membership(ones_divisible_by_three()), 2);
+    let mut random = RandomWordOracle::new(membership(ones_divisible_by_three()), Random::new(7), 100, 10);
+
+    assert_eq!(None, w_method.counterexample(&hypothesis));
+    assert_eq!(None, random.counterexample(&hypothesis));
+}
+
+#[test]
+fn given_a_wrong_hypothesis_the_w_method_should_find_a_word_it_misclassifies() {
+    let mut hypothesis = contains_ab();
+    let target = |word: &[char]| word.last() == Some(&'b');
+    let mut sut = WMethodOracle::new(target, 1);
+
+    let word = sut.counterexample(&hypothesis).expect(COUNTEREXAMPLE);
+
+    hypothesis.steps(&word).expect(STEPS_NO_ERRORS);
+
+    assert_ne!(hypothesis.matches(), target(&word));
+}
+
+#[test]
+fn given_more_extra_states_the_w_method_should_find_longer_counterexamples() {
+    let hypothesis = contains_ab();
+    let target = |word: &[char]| word.windows(2).any(|pair| pair == ['a', 'b']) && word.len() != 7;
+
+    assert_eq!(None, WMethodOracle::new(target, 0).counterexample(&hypothesis));
+
+    let actual = WMethodOracle::new(target, 4).counterexample(&hypothesis).expect(COUNTEREXAMPLE);
+
+    assert_eq!(7, actual.len());
+}
+
+// Weyl sequence, multiples of the golden ratio modulo 2⁶⁴, equidistributed
+struct Weyl(u64);
+
+impl Rng for Weyl {
+    fn from_seed(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        self.0
+    }
+}
@@ -0,0 +1,102 @@
+#![allow(non_snake_case)]
+
+use std::hash::Hash;
+
+use crate::automata::DFA;
+use crate::automata::dfa::ERR_UNDEFINED_SYMBOL;
+use crate::learning::rpni::ERR_INCONSISTENT_SAMPLES;
+use crate::model::Σ;
+use crate::tests::{assert_err, VALID_SIGMA};
+use crate::tests::learning::{contains_ab, membership, ones_divisible_by_three, VALID_LEARNING};
+
+#[test]
+fn given_positive_samples_the_prefix_tree_acceptor_should_accept_exactly_them() {
+    let Σ = Σ::new(vec!['a', 'b']).expect(VALID_SIGMA);
+
+    let sut = DFA::prefix_tree_acceptor(&Σ, &[vec!['a', 'b'], vec!['a'], vec!['b', 'b']]).expect(VALID_LEARNING);
+
+    assert_eq!(6, sut.states().count());
+
+    let mut accepts = membership(sut);
+
+    assert!(accepts(&['a']) && accepts(&['a', 'b']) && accepts(&['b', 'b']));
+    assert!(!accepts(&[]) && !accepts(&['b']) && !accepts(&['a', 'b', 'b']) && !accepts(&['b', 'a']));
+}
+
+#[test]
+fn given_all_labelled_words_up_to_a_length_rpni_should_learn_the_language() {
+    let target = contains_ab();
+    let (positive, negative) = samples(contains_ab(), 4);
+
+    let actual = DFA::rpni(target.alphabet(), &positive, &negative).expect(VALID_LEARNING);
+
+    assert!(actual.equivalent(&target));
+}
+
+#[test]
+fn given_a_characteristic_sample_rpni_should_learn_the_minimal_dfa() {
+    let target = ones_divisible_by_three();
+    let (positive, negative) = samples(ones_divisible_by_three(), 5);
+
+    let actual = DFA::rpni(target.alphabet(), &positive, &negative).expect(VALID_LEARNING);
+
+    assert!(actual.equivalent(&target));
+    assert_eq!(3, actual.states().count());
+}
+
+#[test]
+fn given_few_samples_rpni_should_be_consistent_with_all_of_them() {
+    let Σ = Σ::new(vec!['a', 'b']).expect(VALID_SIGMA);
+    let positive = [vec!['a', 'b'], vec!['a', 'a', 'b'], vec!['b']];
+    let negative = [vec!['a'], vec!['b', 'a'], vec![]];
+
+    let mut accepts = membership(DFA::rpni(&Σ, &positive, &negative).expect(VALID_LEARNING));
+
+    assert!(positive.iter().all(|word| accepts(word)));
+    assert!(negative.iter().all(|word| !accepts(word)));
+}
+
+#[test]
+fn given_no_negative_samples_rpni_should_merge_every_state() {
+    let Σ = Σ::new(vec!['a', 'b']).expect(VALID_SIGMA);
+
+    let sut = DFA::rpni(&Σ, &[vec!['a', 'a']], &[]).expect(VALID_LEARNING);
+
+    assert_eq!(2, sut.states().count());
+
+    let mut accepts = membership(sut);
+
+    assert!(accepts(&[]) && accepts(&['a']) && !accepts(&['a', 'b']));
+}
+
+#[test]
+fn given_a_word_in_both_samples_rpni_should_fail() {
+    let Σ = Σ::new(vec!['a', 'b']).expect(VALID_SIGMA);
+
+    assert_err(ERR_INCONSISTENT_SAMPLES, &DFA::rpni(&Σ, &[vec!['a']], &[vec!['b'], vec!['a']]));
+}
+
+#[test]
+fn given_a_sample_outside_the_alphabet_learning_should_fail() {
+    let Σ = Σ::new(vec!['a', 'b']).expect(VALID_SIGMA);
+
+    assert_err(ERR_UNDEFINED_SYMBOL, &DFA::rpni(&Σ, &[vec!['a']], &[vec!['c']]));
+    assert_err(ERR_UNDEFINED_SYMBOL, &DFA::prefix_tree_acceptor(&Σ, &[vec!['a', 'c']]));
+}
+
+// all words up to the length, split into those the automaton accepts and rejects
+fn samples<A: Clone + Eq + Hash, S: Eq + Hash>(dfa: DFA<A, S>, length: usize) -> (Vec<Vec<A>>, Vec<Vec<A>>) {
+    let symbols = dfa.alphabet().to_vec();
+    let mut accepts = membership(dfa);
+    let mut words = vec![Vec::new()];
+    let mut layer = vec![Vec::new()];
+
+    for _ in 0..length {
+        layer = layer.iter()
+            .flat_map(|word| symbols.iter().map(move |sym| [&word[..], std::slice::from_ref(sym)].concat()))
+            .collect();
+        words.extend(layer.iter().cloned());
+    }
+
+    words.into_iter().partition(|word| accepts(word))
+}
@@ -0,0 +1,19 @@
+use std::hash::Hash;
+
+use crate::automata::NFA;
+use crate::lexer::Regex;
+
+mod regex;
+mod tokenize;
+
+const VALID_LEXER: &str = "valid lexer";
+const VALID_REGEX: &str = "valid regex";
+
+fn nfa(source: &str) -> NFA<char, usize> {
+    source.parse::<Regex>().expect(VALID_REGEX).to_nfa()
+}
+
+fn accepts<A: Eq + Hash, S: Clone + Eq + Hash>(nfa: &mut NFA<A, S>, word: &[A]) -> bool {
+    nfa.reset();
+    nfa.steps(word).is_ok() && nfa.matches()
+}
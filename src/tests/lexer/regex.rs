@@ -0,0 +1,65 @@
+use crate::formats::ParseError;
+use crate::lexer::Regex;
+use crate::lexer::regex::{
+    ERR_EXPECTED_CLOSING_BRACKET, ERR_EXPECTED_CLOSING_PARENTHESIS, ERR_EXPECTED_EXPRESSION, ERR_INVALID_RANGE,
+    ERR_TRAILING_ESCAPE, ERR_UNEXPECTED_CLOSING_PARENTHESIS,
+};
+use crate::tests::lexer::{accepts, nfa};
+
+#[test]
+fn given_alternation_and_repetition_the_nfa_should_accept_the_language() {
+    let mut sut = nfa("ab*|c");
+
+    assert!(accepts(&mut sut, &['a']));
+    assert!(accepts(&mut sut, &['a', 'b', 'b', 'b']));
+    assert!(accepts(&mut sut, &['c']));
+    assert!(!accepts(&mut sut, &[]));
+    assert!(!accepts(&mut sut, &['a', 'c']));
+    assert!(!accepts(&mut sut, &['c', 'b']));
+}
+
+#[test]
+fn given_groups_the_nfa_should_repeat_them_as_a_whole() {
+    let mut sut = nfa("(a|b)*abb");
+
+    assert!(accepts(&mut sut, &['a', 'b', 'b']));
+    assert!(accepts(&mut sut, &['b', 'a', 'a', 'b', 'b']));
+    assert!(!accepts(&mut sut, &['a', 'b']));
+    assert!(!accepts(&mut sut, &['a', 'b', 'b', 'a']));
+    assert_eq!(4, sut.determinize().minimize().states().count());
+}
+
+#[test]
+fn given_classes_and_escapes_the_nfa_should_accept_their_chars() {
+    let mut sut = nfa(r"[a-c_]+\d?\*");
+
+    assert!(accepts(&mut sut, &['a', '_', 'c', '*']));
+    assert!(accepts(&mut sut, &['b', '7', '*']));
+    assert!(!accepts(&mut sut, &['d', '*']));
+    assert!(!accepts(&mut sut, &['a', '1', '2', '*']));
+    assert_eq!(15, sut.alphabet().len());
+}
+
+#[test]
+fn given_a_nullable_expression_the_initial_state_should_be_final() {
+    let mut sut = nfa("a*(b?)");
+
+    assert!(sut.initial().is_final());
+    assert!(accepts(&mut sut, &['a', 'a', 'b']));
+    assert!(!accepts(&mut sut, &['b', 'a']));
+}
+
+#[test]
+fn given_an_invalid_expression_parsing_should_report_where() {
+    let error = |message, column| Err(ParseError { line: 1, column, message });
+
+    assert_eq!(error(ERR_EXPECTED_EXPRESSION, 1), "".parse::<Regex>());
+    assert_eq!(error(ERR_EXPECTED_EXPRESSION, 1), "*a".parse::<Regex>());
+    assert_eq!(error(ERR_EXPECTED_EXPRESSION, 3), "a|".parse::<Regex>());
+    assert_eq!(error(ERR_EXPECTED_EXPRESSION, 2), "[]".parse::<Regex>());
+    assert_eq!(error(ERR_EXPECTED_CLOSING_PARENTHESIS, 4), "(ab".parse::<Regex>());
+    assert_eq!(error(ERR_UNEXPECTED_CLOSING_PARENTHESIS, 3), "ab)".parse::<Regex>());
+    assert_eq!(error(ERR_EXPECTED_CLOSING_BRACKET, 4), "[ab".parse::<Regex>());
+    assert_eq!(error(ERR_INVALID_RANGE, 2), "[z-a]".parse::<Regex>());
+    assert_eq!(error(ERR_TRAILING_ESCAPE, 3), r"a\".parse::<Regex>());
+}
@@ -0,0 +1,116 @@
+use crate::lexer::{ERR_NO_RULES, Lexer, Token};
+use crate::model::State;
+use crate::tests::assert_err;
+use crate::tests::automata::determinize::ends_with_zero_one;
+use crate::tests::lexer::{nfa, VALID_LEXER};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Kind { Keyword, Identifier, Number, Space, Operator }
+
+fn lexer() -> Lexer<char, Kind> {
+    Lexer::new(vec![
+        (Kind::Keyword, nfa("if|else")),
+        (Kind::Identifier, nfa(r"[a-z_]\w*")),
+        (Kind::Number, nfa(r"\d+")),
+        (Kind::Space, nfa(r"\s+")),
+        (Kind::Operator, nfa("[+=<]|==")),
+    ]).expect(VALID_LEXER)
+}
+
+fn kinds(tokens: &[Token<Kind>]) -> Vec<Option<Kind>> {
+    tokens.iter().map(|token| token.kind().copied()).collect()
+}
+
+#[test]
+fn given_text_the_lexer_should_read_the_longest_matches() {
+    let sut = lexer();
+
+    let actual = sut.tokenize_str("if x1 == 42");
+
+    assert_eq!(vec![
+        Token::Match { kind: Kind::Keyword, span: 0..2 },
+        Token::Match { kind: Kind::Space, span: 2..3 },
+        Token::Match { kind: Kind::Identifier, span: 3..5 },
+        Token::Match { kind: Kind::Space, span: 5..6 },
+        Token::Match { kind: Kind::Operator, span: 6..8 },
+        Token::Match { kind: Kind::Space, span: 8..9 },
+        Token::Match { kind: Kind::Number, span: 9..11 },
+    ], actual);
+}
+
+#[test]
+fn given_matches_of_equal_length_the_first_rule_should_win() {
+    let sut = lexer();
+
+    assert_eq!(vec![Some(Kind::Keyword)], kinds(&sut.tokenize_str("else")));
+    assert_eq!(vec![Some(Kind::Identifier)], kinds(&sut.tokenize_str("elsewhere")));
+    assert_eq!(Some((&Kind::Identifier, 3)), sut.longest_match(&['i', 'f', '_', ' ']));
+}
+
+#[test]
+fn given_unrecognized_input_the_lexer_should_report_it_and_recover() {
+    let sut = lexer();
+
+    let actual = sut.tokenize_str("a $$ b");
+
+    assert_eq!(vec![
+        Token::Match { kind: Kind::Identifier, span: 0..1 },
+        Token::Match { kind: Kind::Space, span: 1..2 },
+        Token::Unrecognized { span: 2..4 },
+        Token::Match { kind: Kind::Space, span: 4..5 },
+        Token::Match { kind: Kind::Identifier, span: 5..6 },
+    ], actual);
+}
+
+#[test]
+fn given_multibyte_chars_string_spans_should_be_byte_offsets() {
+    let sut = lexer();
+    let text = "xé1";
+
+    let actual = sut.tokenize_str(text);
+
+    assert_eq!(&(1..3), actual[1].span());
+    assert_eq!("é", &text[actual[1].span().clone()]);
+    assert_eq!("1", &text[actual[2].span().clone()]);
+}
+
+#[test]
+fn given_a_rule_accepting_the_empty_word_it_should_never_match_it() {
+    let sut = Lexer::new(vec![("a", nfa("a*"))]).expect(VALID_LEXER);
+
+    assert_eq!(vec![Token::Unrecognized { span: 0..1 }, Token::Match { kind: "a", span: 1..3 }], sut.tokenize(&['b', 'a', 'a']));
+    assert_eq!(None, sut.longest_match(&[]));
+}
+
+#[test]
+fn given_nfa_rules_over_slices_the_lexer_should_tokenize_them() {
+    let sut = Lexer::new(vec![("zero one", ends_with_zero_one())]).expect(VALID_LEXER);
+
+    let actual = sut.tokenize(&[0, 1, 1, 2, 1, 0, 1]);
+
+    assert_eq!(vec![
+        Token::Match { kind: "zero one", span: 0..2 },
+        Token::Unrecognized { span: 2..4 },
+        Token::Match { kind: "zero one", span: 4..7 },
+    ], actual);
+}
+
+#[test]
+fn given_the_compiled_dfa_its_final_states_should_be_tagged_with_the_winning_rule() {
+    let sut = lexer();
+    let dfa = sut.dfa();
+
+    let state = dfa.transition(dfa.initial(), &'i')
+        .and_then(|state| dfa.transition(state, &'f'))
+        .expect("defined transitions");
+
+    assert!(state.is_final());
+    assert_eq!(Some(&Kind::Keyword), sut.rule(state));
+    assert_eq!(None, sut.rule(dfa.initial()));
+    assert!(dfa.states().all(|state: &State<usize>| state.is_final() == sut.rule(state).is_some()));
+}
+
+#[test]
+fn given_no_rules_the_lexer_should_fail() {
+    assert_err(ERR_NO_RULES, &Lexer::<char, Kind>::new::<usize>(vec![]));
+}
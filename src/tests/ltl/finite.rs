@@ -0,0 +1,53 @@
+use crate::tests::STEPS_NO_ERRORS;
+use crate::tests::ltl::{formula, trace};
+
+const VALID_DFA: &str = "valid dfa";
+const VALID_NFA: &str = "valid nfa";
+
+#[test]
+fn given_an_ltlf_formula_its_nfa_should_accept_the_finite_traces_satisfying_it() {
+    for (source, accepted, rejected) in [
+        ("F a", trace(&[&[], &["a"]]), trace(&[&[], &[]])),
+        ("G a", trace(&[&["a"], &["a"]]), trace(&[&["a"], &[]])),
+        ("a U b", trace(&[&["a"], &["b"]]), trace(&[&["a"], &["a"]])),
+        ("X a", trace(&[&[], &["a"]]), trace(&[&["a"]])),
+        ("!X !a", trace(&[&["a"]]), trace(&[&[], &[]])),
+        ("G (!request || X grant)", trace(&[&["request"], &["grant"]]), trace(&[&[], &["request"]])),
+    ] {
+        let mut sut = formula(source).to_ltlf_nfa().expect(VALID_NFA);
+
+        sut.steps(&accepted).expect(STEPS_NO_ERRORS);
+
+        assert!(sut.matches(), "{source}");
+
+        sut.reset();
+        sut.steps(&rejected).expect(STEPS_NO_ERRORS);
+
+        assert!(!sut.matches(), "{source}");
+    }
+}
+
+#[test]
+fn given_an_ltlf_formula_its_nfa_should_reject_the_empty_trace() {
+    let sut = formula("G a").to_ltlf_nfa().expect(VALID_NFA);
+
+    assert!(!sut.matches());
+    assert_eq!(">({X G a})", format!("{}", sut.initial()));
+}
+
+#[test]
+fn given_an_ltlf_formula_its_dfa_should_agree_with_its_nfa() {
+    let source = formula("(a U b) || G !b");
+    let mut nfa = source.to_ltlf_nfa().expect(VALID_NFA);
+    let mut dfa = source.to_ltlf_dfa().expect(VALID_DFA);
+
+    for inputs in [trace(&[&["a"], &["a"], &["b"]]), trace(&[&["a"], &[], &["b"]]), trace(&[&[], &["a"]])] {
+        nfa.reset();
+        dfa.reset();
+
+        nfa.steps(&inputs).expect(STEPS_NO_ERRORS);
+        dfa.steps(&inputs).expect(STEPS_NO_ERRORS);
+
+        assert_eq!(nfa.matches(), dfa.matches(), "{inputs:?}");
+    }
+}
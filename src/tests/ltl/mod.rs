@@ -0,0 +1,16 @@
+use crate::ltl::{Formula, Valuation};
+
+mod finite;
+mod parser;
+mod tableau;
+
+const VALID_FORMULA: &str = "valid formula";
+
+fn formula(source: &str) -> Formula {
+    source.parse().expect(VALID_FORMULA)
+}
+
+// trace of valuations, each given by its holding propositions
+fn trace(valuations: &[&[&str]]) -> Vec<Valuation> {
+    valuations.iter().map(|propositions| Valuation::new(propositions.iter().copied())).collect()
+}
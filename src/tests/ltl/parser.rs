@@ -0,0 +1,55 @@
+use crate::formats::ParseError;
+use crate::ltl::Formula;
+use crate::ltl::parser::{ERR_EXPECTED_CLOSING_PARENTHESIS, ERR_EXPECTED_FORMULA, ERR_UNEXPECTED_CHARACTER, ERR_UNEXPECTED_TOKEN};
+use crate::tests::ltl::formula;
+
+#[test]
+fn given_a_formula_binary_operators_should_bind_weaker_than_unary_ones() {
+    let atom = |name: &str| Box::new(Formula::Atom(name.to_string()));
+
+    let expected = Formula::Or(
+        Box::new(Formula::And(
+            Box::new(Formula::Until(Box::new(Formula::Not(atom("a"))), atom("b"))),
+            Box::new(Formula::Next(atom("c"))),
+        )),
+        Box::new(Formula::Always(Box::new(Formula::Eventually(atom("d"))))),
+    );
+
+    assert_eq!(expected, formula("!a U b && X c || G F d"));
+}
+
+#[test]
+fn given_a_formula_until_and_release_should_associate_to_the_right() {
+    assert_eq!(formula("a U (b R c)"), formula("a U b R c"));
+    assert_eq!(formula("(a && b) && c"), formula("a && b && c"));
+}
+
+#[test]
+fn given_a_formula_its_display_should_read_back_as_the_same_formula() {
+    for source in ["!a U b && X c || G F d", "(a U b) U c", "a && (b || c)", "G (!request || X grant)", "!(a && true) R false"] {
+        let sut = formula(source);
+
+        assert_eq!(sut, formula(&sut.to_string()), "{source}");
+    }
+
+    assert_eq!("(a U b) U c", formula("(a U b) U c").to_string());
+    assert_eq!("G (a || X b)", formula("G(a||X b)").to_string());
+}
+
+#[test]
+fn given_a_formula_its_propositions_should_be_sorted_by_name() {
+    assert_eq!(vec!["a", "grant", "request"], formula("G (request || F grant) U a && request").propositions());
+}
+
+#[test]
+fn given_invalid_formulas_we_should_get_the_position_of_the_error() {
+    for (source, line, column, message) in [
+        ("a && ", 1, 6, ERR_EXPECTED_FORMULA),
+        ("U a", 1, 1, ERR_EXPECTED_FORMULA),
+        ("G (a\n|| b", 2, 5, ERR_EXPECTED_CLOSING_PARENTHESIS),
+        ("a & b", 1, 3, ERR_UNEXPECTED_CHARACTER),
+        ("a b", 1, 3, ERR_UNEXPECTED_TOKEN),
+    ] {
+        assert_eq!(Err(ParseError { line, column, message }), source.parse::<Formula>(), "{source}");
+    }
+}
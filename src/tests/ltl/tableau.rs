@@ -0,0 +1,102 @@
+use crate::automata::Buchi;
+use crate::ltl::{Formula, Valuation};
+use crate::ltl::ERR_TOO_MANY_PROPOSITIONS;
+use crate::tests::assert_err;
+use crate::tests::ltl::{formula, trace};
+
+const VALID_BUCHI: &str = "valid büchi automaton";
+
+#[test]
+fn given_infinitely_often_its_büchi_automaton_should_accept_lassos_visiting_it_infinitely_often() {
+    let sut = buchi("G F a");
+
+    assert_eq!(Ok(true), sut.accepts(&trace(&[&[]]), &trace(&[&[], &["a"]])));
+    assert_eq!(Ok(false), sut.accepts(&trace(&[&["a"], &["a"]]), &trace(&[&[]])));
+}
+
+#[test]
+fn given_until_its_büchi_automaton_should_require_the_right_operand_to_eventually_hold() {
+    let sut = buchi("a U b");
+
+    assert_eq!(Ok(true), sut.accepts(&trace(&[&["a"], &["a"], &["b"]]), &trace(&[&[]])));
+    assert_eq!(Ok(false), sut.accepts(&trace(&[&["a"], &[]]), &trace(&[&["b"]])));
+    assert_eq!(Ok(false), sut.accepts(&[], &trace(&[&["a"]])));
+}
+
+#[test]
+fn given_several_eventualities_a_büchi_automaton_should_require_each_to_be_fulfilled() {
+    let sut = buchi("G F a && G F b");
+
+    assert_eq!(Ok(true), sut.accepts(&[], &trace(&[&["a"], &["b"]])));
+    assert_eq!(Ok(true), sut.accepts(&[], &trace(&[&["a", "b"]])));
+    assert_eq!(Ok(false), sut.accepts(&trace(&[&["b"]]), &trace(&[&["a"]])));
+}
+
+#[test]
+fn given_a_response_property_its_büchi_automaton_should_reject_unanswered_requests() {
+    let sut = buchi("G (!request || F grant)");
+
+    assert_eq!(Ok(true), sut.accepts(&trace(&[&["request"], &[], &["grant"]]), &trace(&[&["request", "grant"]])));
+    assert_eq!(Ok(true), sut.accepts(&[], &trace(&[&[]])));
+    assert_eq!(Ok(false), sut.accepts(&trace(&[&["grant"], &["request"]]), &trace(&[&[]])));
+}
+
+#[test]
+fn given_release_its_büchi_automaton_should_require_the_right_operand_until_released() {
+    let sut = buchi("a R b");
+
+    assert_eq!(Ok(true), sut.accepts(&[], &trace(&[&["b"]])));
+    assert_eq!(Ok(true), sut.accepts(&trace(&[&["b"], &["a", "b"]]), &trace(&[&[]])));
+    assert_eq!(Ok(false), sut.accepts(&trace(&[&["b"], &["a"]]), &trace(&[&[]])));
+}
+
+#[test]
+fn given_unsatisfiable_formulas_their_büchi_automata_should_be_empty() {
+    for source in ["a && !a", "G a && F !a", "X false", "G F a && F G !a"] {
+        assert!(buchi(source).is_empty(), "{source}");
+    }
+
+    assert!(!buchi("F G !a || G F a").is_empty());
+}
+
+#[test]
+fn given_a_formula_and_its_negation_their_büchi_automata_should_not_intersect() {
+    for source in ["a U b", "G (!a || F b)", "F G a", "(a R b) || X !a", "G F a && F (b U a)"] {
+        let sut = buchi(source);
+        let negation = buchi(&format!("!({source})"));
+
+        assert!(sut.intersection(&negation).is_empty(), "{source}");
+        assert!(!sut.is_empty() && !negation.is_empty(), "{source}");
+    }
+}
+
+#[test]
+fn given_a_formula_its_büchi_automaton_should_read_every_valuation_of_its_propositions() {
+    let sut = buchi("b U a");
+
+    let expected = [Valuation::default(), Valuation::new(["a"]), Valuation::new(["b"]), Valuation::new(["a", "b"])];
+
+    assert_eq!(expected.as_slice(), sut.alphabet().as_ref());
+    assert_eq!(vec!["{}", "a", "b", "a&b"], sut.alphabet().iter().map(ToString::to_string).collect::<Vec<_>>());
+}
+
+#[test]
+fn given_a_formula_its_büchi_automaton_states_should_be_tagged_with_their_obligations() {
+    let sut = buchi("F a");
+
+    let actual = sut.states().map(|state| format!("{state}")).collect::<Vec<_>>();
+
+    assert_eq!(vec![">({F a} 0)", "(({} 1))"], actual);
+}
+
+#[test]
+fn given_too_many_propositions_a_translation_should_return_an_err() {
+    let source = (0..13).map(|idx| format!("p{idx}")).collect::<Vec<_>>().join(" && ");
+
+    assert_err(ERR_TOO_MANY_PROPOSITIONS, &formula(&source).to_buchi());
+    assert_err(ERR_TOO_MANY_PROPOSITIONS, &formula(&source).to_ltlf_nfa());
+}
+
+fn buchi(source: &str) -> Buchi<Valuation, String> {
+    Formula::to_buchi(&formula(source)).expect(VALID_BUCHI)
+}
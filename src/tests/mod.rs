@@ -1,14 +1,20 @@
 use std::fmt::{Debug, Display, Formatter, Write};
 use std::fmt;
 
-mod automata;
+pub mod automata;
+mod formats;
+mod learning;
+mod lexer;
+mod ltl;
 mod model;
+mod random;
 // mod nfa;
 
 const STEPS_NO_ERRORS: &str = "expect no errors in steps";
 
 const VALID_DELTA: &str = "valid δ";
 const VALID_FINAL_STATES: &str = "valid final states";
+const VALID_LAMBDA: &str = "valid λ";
 const VALID_SIGMA: &str = "valid Σ";
 const VALID_STATES: &str = "valid Q";
 
@@ -52,7 +58,7 @@ pub fn assert_err<T>(expected: &str, actual: &Result<T, &str>) {
 }
 
 #[test]
-#[should_panic]
+#[should_panic(expected = "Expected Err: this is foolish")]
 fn given_no_way_to_supress_code_coverage_for_untestable_test_logic_we_should_devise_a_useless_test_to_accommplish_code_coverage() {
     assert_err("this is foolish", &Ok(()));
 }
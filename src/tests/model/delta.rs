@@ -1,6 +1,7 @@
 #![allow(non_snake_case)]
 
 use crate::model::delta::{
+    ERR_DUPLICATE_DEFAULT_TRANSITIONS,
     ERR_DUPLICATE_DELTA_STATES,
     ERR_DUPLICATE_INPUT_TRANSITIONS,
     ERR_UNDEFINED_STATE_TRANSITION,
@@ -62,3 +63,34 @@ fn given_a_collection_of_valid_transitions_should_get_you_transition_functions_
         ('B', vec![(0, 'B'), (1, 'B')]),
     ]).expect(VALID_DELTA);
 }
+
+#[test]
+fn given_a_state_with_more_than_one_default_transition_we_should_get_an_err() {
+    let δ = δ::with_defaults(vec![
+        ('A', vec![(0, 'B')]),
+        ('B', vec![(0, 'A')]),
+    ], vec![('A', 'A'), ('A', 'B')]);
+
+    assert_err(ERR_DUPLICATE_DEFAULT_TRANSITIONS, &δ);
+}
+
+#[test]
+fn given_a_default_transition_to_an_undefined_state_we_should_get_an_err() {
+    let δ = δ::with_defaults(vec![
+        ('A', vec![(0, 'B')]),
+        ('B', vec![(0, 'A')]),
+    ], vec![('A', 'C')]);
+
+    assert_err(ERR_UNDEFINED_STATE_TRANSITION, &δ);
+}
+
+#[test]
+fn given_default_transitions_we_should_get_the_default_of_each_state() {
+    let δ = δ::with_defaults(vec![
+        ('A', vec![(0, 'B')]),
+        ('B', vec![]),
+    ], vec![('B', 'A')]).expect(VALID_DELTA);
+
+    assert_eq!(Some(&'A'), δ.default_of(&'B'));
+    assert_eq!(None, δ.default_of(&'A'));
+}
@@ -0,0 +1,53 @@
+use crate::model::Intervals;
+use crate::model::intervals::ERR_EMPTY_RANGE;
+use crate::model::sigma::ERR_EMPTY_ALPHABET;
+use crate::tests::{assert_err, VALID_SIGMA};
+
+#[test]
+fn given_overlapping_ranges_they_should_be_split_into_disjoint_classes() {
+    let sut = Intervals::new(vec!['f'..='z', 'a'..='m']).expect(VALID_SIGMA);
+
+    assert_eq!(&*sut, &['a'..='e', 'f'..='m', 'n'..='z']);
+}
+
+#[test]
+fn given_disjoint_ranges_they_should_be_kept_in_order() {
+    let sut = Intervals::new(vec![10_u8..=20, 0..=5, 6..=9]).expect(VALID_SIGMA);
+
+    assert_eq!(&*sut, &[0..=5, 6..=9, 10..=20]);
+    assert_eq!("[0..=5, 6..=9, 10..=20]", format!("{sut:?}"));
+}
+
+#[test]
+fn given_a_symbol_its_class_should_be_found_by_binary_search() {
+    let sut = Intervals::new(vec!['0'..='9', 'a'..='z', 'A'..='Z']).expect(VALID_SIGMA);
+
+    assert_eq!(Some(0), sut.class(&'5'));
+    assert_eq!(Some(1), sut.class(&'Q'));
+    assert_eq!(Some(2), sut.class(&'a'));
+    assert_eq!(None, sut.class(&'_'));
+}
+
+#[test]
+fn given_ranges_within_the_alphabet_refine_should_split_the_classes_at_their_boundaries() {
+    let sut = Intervals::new(vec!['a'..='z']).expect(VALID_SIGMA);
+
+    assert_eq!(&*sut.refine(&['c'..='e', 'e'..='g']), &['a'..='b', 'c'..='d', 'e'..='e', 'f'..='g', 'h'..='z']);
+}
+
+#[test]
+fn given_ranges_they_should_cover_the_alphabet_only_if_they_have_all_its_symbols() {
+    let sut = Intervals::new(vec!['a'..='z', '0'..='9']).expect(VALID_SIGMA);
+
+    assert!(sut.is_covered_by(&['0'..='9', 'a'..='m', 'n'..='z']));
+    assert!(!sut.is_covered_by(&['0'..='9', 'a'..='y']));
+    assert!(!sut.is_covered_by(&['0'..='z']));
+    assert!(sut.contains(&('b'..='d')));
+    assert!(!sut.contains(&('9'..='a')));
+}
+
+#[test]
+fn given_no_ranges_or_an_empty_range_new_should_return_an_err() {
+    assert_err(ERR_EMPTY_ALPHABET, &Intervals::<char>::new(vec![]));
+    assert_err(ERR_EMPTY_RANGE, &Intervals::new(vec!['a'..='z', 'z'..='a']));
+}
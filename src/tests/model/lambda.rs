@@ -0,0 +1,47 @@
+use crate::model::delta::{
+    ERR_DUPLICATE_DELTA_STATES,
+    ERR_DUPLICATE_INPUT_TRANSITIONS,
+    ERR_UNDEFINED_STATE_TRANSITION,
+};
+use crate::model::λ;
+use crate::tests::assert_err;
+use crate::tests::VALID_LAMBDA;
+
+#[test]
+fn given_a_collection_of_output_transitions_with_duplicate_input_transitions_we_should_get_an_err() {
+    let λ = λ::new(vec![
+        ('A', vec![(0, 'A', 'x'), (1, 'B', 'y')]),
+        ('B', vec![(0, 'B', 'x'), (0, 'A', 'y')]), // (B) 0 is defined twice
+    ]);
+
+    assert_err(ERR_DUPLICATE_INPUT_TRANSITIONS, &λ);
+}
+
+#[test]
+fn given_a_collection_of_output_transitions_with_duplicate_states_we_should_get_an_err() {
+    let λ = λ::new(vec![
+        ('A', vec![(0, 'A', 'x'), (1, 'B', 'y')]),
+        ('B', vec![(0, 'B', 'x'), (1, 'A', 'y')]), // \___ (B) is defined twice
+        ('B', vec![(0, 'A', 'x'), (1, 'B', 'y')]), // /
+    ]);
+
+    assert_err(ERR_DUPLICATE_DELTA_STATES, &λ);
+}
+
+#[test]
+fn given_a_collection_of_output_transitions_with_undefined_state_transitions_we_should_get_an_err() {
+    let λ = λ::new(vec![
+        ('A', vec![(0, 'A', 'x'), (1, 'B', 'y')]),
+        ('B', vec![(0, 'C', 'x'), (1, 'A', 'y')]), // (C) is not defined, only (A) & (B) are defined
+    ]);
+
+    assert_err(ERR_UNDEFINED_STATE_TRANSITION, &λ);
+}
+
+#[test]
+fn given_a_collection_of_valid_output_transitions_should_get_you_transition_and_output_functions_λ() {
+    λ::new(vec![
+        ('A', vec![(0, 'A', 'x'), (1, 'B', 'y')]),
+        ('B', vec![(0, 'B', 'x'), (1, 'A', 'y')]),
+    ]).expect(VALID_LAMBDA);
+}
@@ -1,4 +1,6 @@
 mod delta;
 mod final_states;
+mod intervals;
+mod lambda;
 mod sigma;
 mod state;
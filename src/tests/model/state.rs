@@ -111,6 +111,6 @@ fn assert_output_of_all_phases<S, E>(source: &[S], expected: E, actual: fn(&Stat
         let expected = expected(prefix, postfix);
         let actual = actual(&sut);
 
-        assert_eq!(expected, actual, "{:?} State Phase", phase);
+        assert_eq!(expected, actual, "{phase:?} State Phase");
     }
 }
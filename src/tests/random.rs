@@ -0,0 +1,41 @@
+use crate::random::Random;
+
+#[test]
+fn given_the_same_seed_random_should_produce_the_same_sequence() {
+    let mut sut = Random::new(42);
+    let mut expected = Random::new(42);
+
+    for _ in 0..100 {
+        assert_eq!(expected.next_u64(), sut.next_u64());
+    }
+}
+
+#[test]
+fn given_different_seeds_random_should_produce_different_sequences() {
+    let mut sut = Random::new(1);
+    let mut other = Random::new(2);
+
+    assert!((0..10).any(|_| sut.next_u64() != other.next_u64()));
+}
+
+#[test]
+fn given_a_random_generator_next_f64_should_be_uniform_in_the_unit_interval() {
+    let mut sut = Random::new(7);
+    let draws = (0..10_000).map(|_| sut.next_f64()).collect::<Vec<_>>();
+
+    assert!(draws.iter().all(|draw| (0.0..1.0).contains(draw)));
+    assert!((draws.iter().sum::<f64>() / 10_000.0 - 0.5).abs() < 0.02);
+}
+
+#[test]
+fn given_a_bound_next_below_should_cover_every_index_below_it() {
+    let mut sut = Random::new(11);
+    let mut seen = [false; 5];
+
+    for _ in 0..1000 {
+        seen[sut.next_below(5)] = true;
+    }
+
+    assert!(seen.iter().all(|seen| *seen));
+    assert_eq!(0, sut.next_below(0));
+}
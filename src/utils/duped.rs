@@ -1,8 +1,8 @@
 use crate::UNREACHABLE_ERR;
 
-///
+/// Detects duplicate items in a collection
 pub trait Duped {
-    ///
+    /// Does the collection contain any item more than once
     fn has_dupes(&self) -> bool;
 }
 